@@ -0,0 +1,1297 @@
+//! 代理
+//! 对应《民法典》第一百六十一条至第一百七十五条：代理人在代理权限内，以被代理人名义实施的民事法律行为，对被代理人发生效力。
+//! 代理包括委托代理和法定代理（第一百六十三条）。代理人不得以被代理人的名义与自己实施民事法律行为，也不得与自己同时代理的
+//! 其他人实施民事法律行为，但是被代理人同意或者追认的除外（第一百六十八条）。没有代理权、超越代理权或者代理权终止后实施的
+//! 代理行为，未经被代理人追认的，对被代理人不发生效力（第一百七十一条）；相对人有理由相信行为人有代理权的，代理行为有效
+//! （第一百七十二条），其理由限于被代理人未收回的授权委托书、交付的盖章空白合同书等可以核对的外观，且相对人未被告知。
+//! 法定代理由监护人经监护程序实施，不经委托代理的途径。法人、非法人组织由其法定代表人、负责人或者执行工作任务的人员以其名义实施民事法律行为，
+//! 章程或者内部对其权限的限制，不得对抗善意相对人（第六十一条、第一百零五条、第一百七十条）。
+
+use crate::contract::intent::content::{IntentContent, SubjectMatterType};
+use crate::contract::intent::declaration::IntentDeclaration;
+use crate::core::entity::{Entity, EntityType};
+use crate::core::money::Money;
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 代理权限
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgencyScope {
+    pub matters: Option<Vec<SubjectMatterType>>, // 授权的事项，空为概括授权
+    pub price_limit: Option<Money>,              // 单项交易的价款上限
+}
+
+impl AgencyScope {
+    /// 概括授权
+    pub fn general() -> Self {
+        Self {
+            matters: None,
+            price_limit: None,
+        }
+    }
+
+    /// 是否涵盖某一意思表示的内容
+    pub fn covers(&self, content: &IntentContent) -> FanResult<bool> {
+        let matter_ok = self
+            .matters
+            .as_ref()
+            .is_none_or(|m| m.contains(content.subject_matter.subject_type()));
+        let price_ok = match (&self.price_limit, &content.price) {
+            (Some(limit), Some(price)) => price.money().checked_cmp(limit)? != Ordering::Greater,
+            _ => true,
+        };
+        Ok(matter_ok && price_ok)
+    }
+
+    /// 是否在另一代理权限之内，转委托的权限不得超出原代理权限
+    pub fn within(&self, other: &AgencyScope) -> FanResult<bool> {
+        let matter_ok = match (&self.matters, &other.matters) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(mine), Some(theirs)) => mine.iter().all(|m| theirs.contains(m)),
+        };
+        let price_ok = match (&self.price_limit, &other.price_limit) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(mine), Some(theirs)) => mine.checked_cmp(theirs)? != Ordering::Greater,
+        };
+        Ok(matter_ok && price_ok)
+    }
+}
+
+/// 代理权终止的事由（第一百七十三条、第一百七十五条）
+///
+/// 代理人丧失民事行为能力、代理人或者被代理人死亡、被代理人取得民事行为能力等事由在实施代理行为时判断。
+#[derive(Debug, Clone, PartialEq)]
+pub enum AgencyTermination {
+    /// 代理事务完成
+    Completed,
+    /// 被代理人取消委托
+    RevokedByPrincipal,
+    /// 代理人辞去委托
+    RenouncedByAgent,
+    /// 作为代理人或者被代理人的法人、非法人组织终止
+    OrganisationTerminated,
+}
+
+/// 委托代理权
+///
+/// 法定代理由监护关系体现，监护人经 [`IntentDeclaration::through_guardian`] 代理被监护人。
+#[derive(Debug, Clone)]
+pub struct AgencyAuthority {
+    id: Uuid,
+    principal: Uuid,                                         // 被代理人
+    agent: Uuid,                                             // 代理人
+    scope: AgencyScope,                                      // 代理权限
+    valid_from: DateTime<Utc>,                               // 代理期限起始
+    valid_until: Option<DateTime<Utc>>,                      // 代理期限届满，空为未约定期限
+    sub_agency_permitted: bool,                              // 被代理人是否同意转委托
+    consents: Vec<Uuid>, // 被代理人同意代理人与之实施民事法律行为的相对人
+    parent: Option<Box<AgencyAuthority>>, // 转委托所依据的原代理权
+    termination: Option<(AgencyTermination, DateTime<Utc>)>, // 代理权终止的事由和时间
+    withdrawn_at: Option<DateTime<Utc>>, // 被代理人收回授权委托书之日
+    notified: Vec<Uuid>, // 被代理人已告知代理权终止的相对人
+}
+
+impl AgencyAuthority {
+    /// 被代理人出具授权委托书，载明代理事项、权限和期限（第一百六十五条）
+    pub fn entrusted(
+        principal: &dyn Entity,
+        agent: &dyn Entity,
+        scope: AgencyScope,
+        valid_from: DateTime<Utc>,
+        valid_until: Option<DateTime<Utc>>,
+    ) -> FanResult<Self> {
+        if !principal.has_capacity_at(valid_from) || principal.id() == agent.id() {
+            return Err(FanError::validation_with_ref(
+                "委托代理须由具有完全民事行为能力的被代理人授予他人",
+                ValidationErrorType::EntityCapacityLacking,
+                "《民法典》第一百六十五条",
+                "entrusted",
+                "AgencyAuthority",
+            ));
+        }
+        Self::build(
+            principal.id(),
+            agent.id(),
+            scope,
+            valid_from,
+            valid_until,
+            None,
+        )
+    }
+
+    fn build(
+        principal: Uuid,
+        agent: Uuid,
+        scope: AgencyScope,
+        valid_from: DateTime<Utc>,
+        valid_until: Option<DateTime<Utc>>,
+        parent: Option<Box<AgencyAuthority>>,
+    ) -> FanResult<Self> {
+        if valid_until.is_some_and(|until| until <= valid_from) {
+            return Err(FanError::validation(
+                "代理期限届满之日须晚于起始之日",
+                ValidationErrorType::EntityError,
+                "new",
+                "AgencyAuthority",
+            ));
+        }
+        Ok(Self {
+            id: Uuid::new_v4(),
+            principal,
+            agent,
+            scope,
+            valid_from,
+            valid_until,
+            sub_agency_permitted: false,
+            consents: Vec::new(),
+            parent,
+            termination: None,
+            withdrawn_at: None,
+            notified: Vec::new(),
+        })
+    }
+
+    /// 被代理人同意转委托
+    pub fn permit_sub_agency(&mut self) {
+        self.sub_agency_permitted = true;
+    }
+
+    /// 被代理人同意代理人以其名义与某一相对人实施民事法律行为
+    ///
+    /// 相对人为代理人本人的，为自己代理；为代理人同时代理的其他人的，为双方代理，须经被代理的双方同意。
+    pub fn consent_to(&mut self, counterparty: Uuid) {
+        if !self.consents.contains(&counterparty) {
+            self.consents.push(counterparty);
+        }
+    }
+
+    /// 代理人转委托第三人代理
+    ///
+    /// 转委托的权限不得超出原代理权限，期限不得晚于原代理期限。未经被代理人同意的转委托，第三人的代理行为
+    /// 须经被代理人追认，但是在紧急情况下为了维护被代理人的利益需要转委托的除外（第一百六十九条）。
+    pub fn sub_delegate(
+        &self,
+        sub_agent: &dyn Entity,
+        scope: AgencyScope,
+        at: DateTime<Utc>,
+        emergency: bool,
+    ) -> FanResult<Self> {
+        if !self.is_in_force_at(at) || !scope.within(&self.scope)? || sub_agent.id() == self.agent {
+            return Err(FanError::validation_with_ref(
+                "转委托须在原代理权存续期间、原代理权限内进行",
+                ValidationErrorType::OperationUnauthorized,
+                "《民法典》第一百六十九条",
+                "sub_delegate",
+                "AgencyAuthority",
+            ));
+        }
+        let mut sub = Self::build(
+            self.principal,
+            sub_agent.id(),
+            scope,
+            at,
+            self.valid_until,
+            Some(Box::new(self.clone())),
+        )?;
+        sub.sub_agency_permitted = self.sub_agency_permitted || emergency;
+        Ok(sub)
+    }
+
+    /// 代理权终止
+    pub fn terminate(&mut self, cause: AgencyTermination, at: DateTime<Utc>) -> FanResult<()> {
+        if self.termination.is_some() {
+            return Err(FanError::validation(
+                "代理权已经终止",
+                ValidationErrorType::OperationSequenceWrong,
+                "terminate",
+                "AgencyAuthority",
+            ));
+        }
+        self.termination = Some((cause, at));
+        Ok(())
+    }
+
+    /// 被代理人收回授权委托书，此后不再构成相对人相信代理权存续的外观
+    pub fn withdraw(&mut self, at: DateTime<Utc>) -> FanResult<()> {
+        if self.withdrawn_at.is_some() {
+            return Err(FanError::validation(
+                "授权委托书已经收回",
+                ValidationErrorType::OperationSequenceWrong,
+                "withdraw",
+                "AgencyAuthority",
+            ));
+        }
+        self.withdrawn_at = Some(at);
+        Ok(())
+    }
+
+    /// 被代理人将代理权终止告知某一相对人，该相对人不能再相信代理权存续
+    pub fn notify_termination(&mut self, counterparty: Uuid) {
+        if !self.notified.contains(&counterparty) {
+            self.notified.push(counterparty);
+        }
+    }
+
+    /// 代理权终止后，授权委托书是否仍使某一相对人有理由相信代理权存续
+    ///
+    /// 委托书记载的期限须涵盖该时刻，期限届满的相对人可从委托书上得知；委托书未被收回、相对人未被告知代理权终止，
+    /// 且所涉事项在原代理权限内。转委托的委托书由代理人出具，不构成被代理人的外观。
+    fn holds_out_to(
+        &self,
+        counterparty: Uuid,
+        content: &IntentContent,
+        at: DateTime<Utc>,
+    ) -> FanResult<bool> {
+        Ok(self.termination.is_some()
+            && !self.is_sub_agency()
+            && self.valid_from <= at
+            && self.valid_until.is_none_or(|until| at < until)
+            && self.withdrawn_at.is_none_or(|withdrawn| at < withdrawn)
+            && !self.notified.contains(&counterparty)
+            && self.scope.covers(content)?)
+    }
+
+    /// 在指定时刻是否在代理期限内且未终止，转委托的还须原代理权仍然存续
+    pub fn is_in_force_at(&self, at: DateTime<Utc>) -> bool {
+        self.valid_from <= at
+            && self.valid_until.is_none_or(|until| at < until)
+            && self.termination.as_ref().is_none_or(|(_, t)| at < *t)
+            && self.parent.as_ref().is_none_or(|p| p.is_in_force_at(at))
+    }
+
+    /// 是否为转委托
+    pub fn is_sub_agency(&self) -> bool {
+        self.parent.is_some()
+    }
+
+    /// 获取代理权ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// 获取被代理人
+    pub fn principal(&self) -> Uuid {
+        self.principal
+    }
+
+    /// 获取代理人
+    pub fn agent(&self) -> Uuid {
+        self.agent
+    }
+
+    /// 获取代理权限
+    pub fn scope(&self) -> &AgencyScope {
+        &self.scope
+    }
+
+    /// 获取代理期限起始
+    pub fn valid_from(&self) -> DateTime<Utc> {
+        self.valid_from
+    }
+
+    /// 获取代理期限届满之日
+    pub fn valid_until(&self) -> Option<DateTime<Utc>> {
+        self.valid_until
+    }
+
+    /// 获取转委托所依据的原代理权
+    pub fn parent(&self) -> Option<&AgencyAuthority> {
+        self.parent.as_deref()
+    }
+
+    /// 获取代理权终止的事由和时间
+    pub fn termination(&self) -> Option<&(AgencyTermination, DateTime<Utc>)> {
+        self.termination.as_ref()
+    }
+
+    /// 被代理人是否同意与某一相对人实施民事法律行为
+    pub fn consents_to(&self, counterparty: Uuid) -> bool {
+        self.consents.contains(&counterparty)
+    }
+
+    /// 获取收回授权委托书之日
+    pub fn withdrawn_at(&self) -> Option<DateTime<Utc>> {
+        self.withdrawn_at
+    }
+}
+
+/// 被代理人交付行为人的盖章空白合同书、介绍信等
+///
+/// 行为人持有的，构成相对人相信其有代理权的外观，直至被代理人收回或者告知相对人。
+#[derive(Debug, Clone)]
+pub struct SealedForms {
+    principal: Uuid,                     // 盖章的被代理人
+    holder: Uuid,                        // 持有人
+    issued_at: DateTime<Utc>,            // 交付之日
+    withdrawn_at: Option<DateTime<Utc>>, // 收回之日
+    notified: Vec<Uuid>,                 // 已被告知持有人无代理权的相对人
+}
+
+impl SealedForms {
+    /// 被代理人将盖章的空白合同书交付持有人
+    pub fn issue(
+        principal: &dyn Entity,
+        holder: &dyn Entity,
+        issued_at: DateTime<Utc>,
+    ) -> FanResult<Self> {
+        if !principal.has_capacity_at(issued_at) || principal.id() == holder.id() {
+            return Err(FanError::validation_with_ref(
+                "盖章的空白合同书须由具有行为能力的被代理人交付他人",
+                ValidationErrorType::EntityCapacityLacking,
+                "《民法典》第一百七十二条",
+                "issue",
+                "SealedForms",
+            ));
+        }
+        Ok(Self {
+            principal: principal.id(),
+            holder: holder.id(),
+            issued_at,
+            withdrawn_at: None,
+            notified: Vec::new(),
+        })
+    }
+
+    /// 被代理人收回空白合同书
+    pub fn withdraw(&mut self, at: DateTime<Utc>) -> FanResult<()> {
+        if self.withdrawn_at.is_some() {
+            return Err(FanError::validation(
+                "空白合同书已经收回",
+                ValidationErrorType::OperationSequenceWrong,
+                "withdraw",
+                "SealedForms",
+            ));
+        }
+        self.withdrawn_at = Some(at);
+        Ok(())
+    }
+
+    /// 被代理人告知某一相对人持有人没有代理权
+    pub fn notify(&mut self, counterparty: Uuid) {
+        if !self.notified.contains(&counterparty) {
+            self.notified.push(counterparty);
+        }
+    }
+
+    /// 获取盖章的被代理人
+    pub fn principal(&self) -> Uuid {
+        self.principal
+    }
+
+    /// 获取持有人
+    pub fn holder(&self) -> Uuid {
+        self.holder
+    }
+
+    /// 获取交付之日
+    pub fn issued_at(&self) -> DateTime<Utc> {
+        self.issued_at
+    }
+
+    /// 获取收回之日
+    pub fn withdrawn_at(&self) -> Option<DateTime<Utc>> {
+        self.withdrawn_at
+    }
+
+    /// 持有人以被代理人名义行事时，是否使某一相对人有理由相信其有代理权
+    fn holds_out_to(&self, counterparty: Uuid, at: DateTime<Utc>) -> bool {
+        self.issued_at <= at
+            && self.withdrawn_at.is_none_or(|withdrawn| at < withdrawn)
+            && !self.notified.contains(&counterparty)
+    }
+}
+
+/// 代理人以被代理人名义作出意思表示的情况
+#[derive(Debug, Clone)]
+pub struct AgentAct {
+    /// 实施代理行为的人
+    agent: Arc<dyn Entity>,
+    /// 所依据的代理权，没有代理权的为空
+    authority: Option<AgencyAuthority>,
+    /// 行为人出示的被代理人盖章的空白合同书
+    sealed_forms: Option<SealedForms>,
+}
+
+impl AgentAct {
+    pub fn new(agent: Arc<dyn Entity>, authority: Option<AgencyAuthority>) -> Self {
+        Self {
+            agent,
+            authority,
+            sealed_forms: None,
+        }
+    }
+
+    /// 行为人出示被代理人盖章的空白合同书
+    pub fn with_sealed_forms(mut self, forms: SealedForms) -> Self {
+        self.sealed_forms = Some(forms);
+        self
+    }
+
+    /// 获取实施代理行为的人
+    pub fn agent(&self) -> Arc<dyn Entity> {
+        self.agent.clone()
+    }
+
+    /// 获取所依据的代理权
+    pub fn authority(&self) -> Option<&AgencyAuthority> {
+        self.authority.as_ref()
+    }
+
+    /// 获取出示的盖章空白合同书
+    pub fn sealed_forms(&self) -> Option<&SealedForms> {
+        self.sealed_forms.as_ref()
+    }
+}
+
+/// 代理行为的效力
+#[derive(Debug, Clone, PartialEq)]
+pub enum AgencyAssessment {
+    /// 有权代理，对被代理人发生效力
+    Authorized,
+    /// 表见代理，代理行为有效（第一百七十二条）
+    Apparent(String),
+    /// 无权代理或者未经同意的自己代理、转委托，须经被代理人追认
+    Unauthorized(String),
+}
+
+/// 判断代理人以被代理人名义作出的意思表示的效力
+///
+/// 代理人丧失民事行为能力的，代理权终止，须经被代理人追认；代理权须在作出意思表示时存续，并涵盖其内容。
+/// 自己代理未经同意的，不因表见代理而有效，因为相对人即代理人本人。
+pub fn assess_agent_act(
+    principal: &dyn Entity,
+    act: &AgentAct,
+    recipient: Option<Uuid>,
+    content: &IntentContent,
+    at: DateTime<Utc>,
+) -> FanResult<AgencyAssessment> {
+    let agent_id = act.agent.id();
+    if !act.agent.has_capacity_at(at) {
+        return Ok(AgencyAssessment::Unauthorized(
+            "代理人丧失民事行为能力的，代理权终止，须经被代理人追认（《民法典》第一百七十三条、第一百七十一条）"
+                .to_string(),
+        ));
+    }
+
+    let (authority, defect) = match &act.authority {
+        None => (None, "行为人没有代理权".to_string()),
+        Some(authority) => (
+            Some(authority),
+            authority_defect(principal, agent_id, authority, content, at)?,
+        ),
+    };
+
+    // 自己代理：相对人为代理人本人
+    if recipient == Some(agent_id) && authority.is_none_or(|a| !a.consents_to(agent_id)) {
+        return Ok(AgencyAssessment::Unauthorized(
+            "代理人以被代理人的名义与自己实施民事法律行为，须经被代理人同意或者追认（《民法典》第一百六十八条）"
+                .to_string(),
+        ));
+    }
+    if defect.is_empty() {
+        return Ok(AgencyAssessment::Authorized);
+    }
+    Ok(
+        match apparent_ground(principal, act, recipient, content, at)? {
+            Some(ground) => AgencyAssessment::Apparent(format!(
+                "{}，但{}，相对人有理由相信其有代理权",
+                defect, ground
+            )),
+            None => AgencyAssessment::Unauthorized(format!(
+                "{}，须经被代理人追认（《民法典》第一百七十一条）",
+                defect
+            )),
+        },
+    )
+}
+
+/// 相对人有理由相信行为人有代理权的外观，没有的为空（第一百七十二条）
+///
+/// 曾经的代理权终止后授权委托书未收回，或者行为人持有被代理人盖章的空白合同书，
+/// 且被代理人未将此告知相对人。没有相对人的，无从产生信赖。
+fn apparent_ground(
+    principal: &dyn Entity,
+    act: &AgentAct,
+    recipient: Option<Uuid>,
+    content: &IntentContent,
+    at: DateTime<Utc>,
+) -> FanResult<Option<&'static str>> {
+    let Some(counterparty) = recipient else {
+        return Ok(None);
+    };
+    let (principal, agent) = (principal.id(), act.agent.id());
+    if let Some(authority) = &act.authority {
+        if authority.principal == principal
+            && authority.agent == agent
+            && !authority.is_in_force_at(at)
+            && authority.holds_out_to(counterparty, content, at)?
+        {
+            return Ok(Some("授权委托书未被收回"));
+        }
+    }
+    Ok(act
+        .sealed_forms
+        .as_ref()
+        .filter(|f| f.principal == principal && f.holder == agent)
+        .filter(|f| f.holds_out_to(counterparty, at))
+        .map(|_| "行为人持有被代理人盖章的空白合同书"))
+}
+
+/// 代理权的瑕疵，没有瑕疵的为空串
+fn authority_defect(
+    principal: &dyn Entity,
+    agent: Uuid,
+    authority: &AgencyAuthority,
+    content: &IntentContent,
+    at: DateTime<Utc>,
+) -> FanResult<String> {
+    let defect = if authority.principal != principal.id() || authority.agent != agent {
+        "代理权并非由被代理人授予该行为人"
+    } else if !authority.is_in_force_at(at) || principal.is_presumed_dead_at(at) {
+        "代理权已经终止或者不在代理期限内"
+    } else if authority.is_sub_agency() && !authority.sub_agency_permitted {
+        "转委托未经被代理人同意"
+    } else if !authority.scope.covers(content)? {
+        "超越代理权"
+    } else {
+        ""
+    };
+    Ok(defect.to_string())
+}
+
+/// 签署人以何种身份代表法人、非法人组织
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignatoryRole {
+    /// 法定代表人、代表非法人组织从事民事活动的负责人，以组织的名义从事民事活动（第六十一条、第一百零五条）
+    Representative,
+    /// 执行工作任务的人员，就组织人员名册所载职权范围内的事项以组织的名义实施民事法律行为（第一百七十条）
+    Staff,
+}
+
+/// 代表法人、非法人组织作出意思表示的签署人
+#[derive(Debug, Clone)]
+pub struct Signatory {
+    /// 实际签署的人
+    person: Arc<dyn Entity>,
+    /// 签署人的身份
+    role: SignatoryRole,
+}
+
+impl Signatory {
+    /// 由法定代表人或者负责人签署
+    pub fn representative(person: Arc<dyn Entity>) -> Self {
+        Self {
+            person,
+            role: SignatoryRole::Representative,
+        }
+    }
+
+    /// 由执行工作任务的人员签署，其职权范围以组织的人员名册为准
+    pub fn staff(person: Arc<dyn Entity>) -> Self {
+        Self {
+            person,
+            role: SignatoryRole::Staff,
+        }
+    }
+
+    /// 获取实际签署的人
+    pub fn person(&self) -> Arc<dyn Entity> {
+        self.person.clone()
+    }
+
+    /// 获取签署人的身份
+    pub fn role(&self) -> &SignatoryRole {
+        &self.role
+    }
+}
+
+/// 判断签署人以法人、非法人组织名义作出的意思表示是否对组织发生效力
+///
+/// 签署人不是组织的法定代表人、负责人或者人员名册所载的在职人员，或者超出其职权范围的，按无权代理处理，须经组织追认；
+/// 仅超越组织记载的内部限制的，除相对人在意思表示作出前已被告知该限制外，对组织发生效力（第五百零四条）。
+/// 签署人以组织的名义与自己实施民事法律行为的，按自己代理处理。
+pub fn assess_signatory(
+    organisation: &dyn Entity,
+    signatory: &Signatory,
+    recipient: Option<Uuid>,
+    content: &IntentContent,
+    at: DateTime<Utc>,
+) -> FanResult<AgencyAssessment> {
+    if matches!(
+        organisation.entity_type(),
+        EntityType::NaturalPerson | EntityType::Household
+    ) {
+        return Err(FanError::validation_with_ref(
+            "只有法人、非法人组织由签署人代表作出意思表示，自然人应当委托代理人",
+            ValidationErrorType::EntityRelationMalformed,
+            "《民法典》第六十一条、第一百七十条",
+            "assess_signatory",
+            "IntentDeclaration",
+        ));
+    }
+    if !signatory.person.has_capacity_at(at) {
+        return Err(FanError::validation(
+            "签署人无行为能力",
+            ValidationErrorType::EntityCapacityLacking,
+            "assess_signatory",
+            "IntentDeclaration",
+        ));
+    }
+
+    let person = signatory.person.id();
+    let personnel = organisation.personnel().unwrap_or_default();
+    let defect = match &signatory.role {
+        SignatoryRole::Representative if !organisation.representatives().contains(&person) => {
+            Some("签署人并非法定代表人或者负责人")
+        }
+        SignatoryRole::Representative => None,
+        SignatoryRole::Staff => match personnel.duties_at(person, at) {
+            None => Some("签署人并非组织执行工作任务的人员"),
+            Some(duties) if !duties.contains(content.subject_matter.subject_type()) => {
+                Some("签署人超出其职权范围")
+            }
+            Some(_) => None,
+        },
+    }
+    .or((recipient == Some(person)).then_some("签署人以组织的名义与自己实施民事法律行为"));
+    if let Some(defect) = defect {
+        return Ok(AgencyAssessment::Unauthorized(format!(
+            "{}，须经组织追认（《民法典》第一百七十一条）",
+            defect
+        )));
+    }
+
+    let known_limit = personnel
+        .limit_at(person, at)
+        .filter(|l| recipient.is_some_and(|r| l.is_known_to(r, at)))
+        .map(|l| AgencyScope {
+            matters: l.matters().map(<[_]>::to_vec),
+            price_limit: l.price_limit(),
+        });
+    match known_limit {
+        Some(limits) if !limits.covers(content)? => Ok(AgencyAssessment::Unauthorized(
+            "签署人超越权限，相对人知道或者应当知道，须经组织追认（《民法典》第五百零四条）"
+                .to_string(),
+        )),
+        _ => Ok(AgencyAssessment::Authorized),
+    }
+}
+
+/// 双方代理：同一代理人在同一合同中同时代理不同当事人，须经被代理的双方同意或者追认（第一百六十八条第二款）
+///
+/// 返回该当事人因双方代理而效力待定的原因。
+pub(crate) fn dual_representation(
+    declarations: &[IntentDeclaration],
+    party: Uuid,
+) -> Option<String> {
+    let own: Vec<&AgentAct> = declarations
+        .iter()
+        .filter(|d| d.declarant().id() == party)
+        .filter_map(|d| d.agent_act())
+        .collect();
+    for act in own {
+        let agent = act.agent.id();
+        for other in declarations.iter().filter(|d| d.declarant().id() != party) {
+            let Some(other_act) = other.agent_act().filter(|a| a.agent.id() == agent) else {
+                continue;
+            };
+            let other_party = other.declarant().id();
+            let consented = act
+                .authority
+                .as_ref()
+                .is_some_and(|a| a.consents_to(other_party))
+                && other_act
+                    .authority
+                    .as_ref()
+                    .is_some_and(|a| a.consents_to(party));
+            if !consented {
+                return Some(
+                    "代理人以被代理人的名义与自己同时代理的其他人实施民事法律行为，须经被代理的双方同意或者追认（《民法典》第一百六十八条）"
+                        .to_string(),
+                );
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::base::{BaseContract, Contract, ContractStatus};
+    use crate::contract::intent::content::{Price, SubjectMatter};
+    use crate::contract::intent::declaration::DeclarationType;
+    use crate::contract::ratification::RatificationGround;
+    use crate::core::clock::{Clock, ManualClock};
+    use crate::core::entity::{
+        AdjudicationKind, Applicant, CapacityAdjudication, CompanyType, LegalPerson,
+        LegalPersonType, MentalStatus, NaturalCapacity, NaturalPerson,
+    };
+    use crate::testing::{adult, now};
+    use chrono::{Duration, TimeZone};
+    use rust_decimal::Decimal;
+
+    fn sale(price: i64) -> IntentContent {
+        IntentContent {
+            subject_matter: SubjectMatter::new(
+                Uuid::nil(),
+                SubjectMatterType::SpecificGoods,
+                "二手车".to_string(),
+                None,
+            ),
+            price: Some(Price::new(
+                Money::cny(Decimal::from(price)),
+                "转账".to_string(),
+            )),
+            ..Default::default()
+        }
+    }
+
+    fn limited_to(price: i64) -> AgencyScope {
+        AgencyScope {
+            matters: Some(vec![SubjectMatterType::SpecificGoods]),
+            price_limit: Some(Money::cny(Decimal::from(price))),
+        }
+    }
+
+    #[test]
+    fn test_agency_authority() {
+        let now = now();
+        let clock: Arc<dyn Clock> = Arc::new(ManualClock::new(now));
+        let (principal, agent, sub_agent, buyer) = (
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+        );
+        let until = Some(now + Duration::days(90));
+        let mut authority = AgencyAuthority::entrusted(
+            principal.as_ref(),
+            agent.as_ref(),
+            limited_to(100_000),
+            now,
+            until,
+        )
+        .unwrap();
+        let assess = |act: &AgentAct, recipient: Uuid, price: i64, at| {
+            assess_agent_act(principal.as_ref(), act, Some(recipient), &sale(price), at).unwrap()
+        };
+
+        // 在代理权限和期限内的代理行为对被代理人发生效力
+        let act = AgentAct::new(agent.clone(), Some(authority.clone()));
+        assert_eq!(
+            assess(&act, buyer.id(), 80_000, now),
+            AgencyAssessment::Authorized
+        );
+        assert!(matches!(
+            assess(&act, buyer.id(), 120_000, now),
+            AgencyAssessment::Unauthorized(_)
+        ));
+
+        // 代理人丧失民事行为能力的，代理权终止，其代理行为须经被代理人追认
+        let mut lapsed = NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(1985, 1, 1, 0, 0, 0).unwrap(),
+            MentalStatus::SeverelyImpaired,
+            clock.clone(),
+        );
+        let entrusted = AgencyAuthority::entrusted(
+            principal.as_ref(),
+            &lapsed,
+            limited_to(100_000),
+            now,
+            until,
+        )
+        .unwrap();
+        lapsed
+            .adjudicate_capacity(
+                CapacityAdjudication::new(
+                    AdjudicationKind::Incapacity,
+                    Applicant::InterestedParty(principal.id()),
+                    "北京市海淀区人民法院".to_string(),
+                    now + Duration::days(10),
+                    NaturalCapacity::None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        let act_by_lapsed = AgentAct::new(Arc::new(lapsed), Some(entrusted));
+        assert_eq!(
+            assess(&act_by_lapsed, buyer.id(), 80_000, now),
+            AgencyAssessment::Authorized
+        );
+        assert!(matches!(
+            assess(&act_by_lapsed, buyer.id(), 80_000, now + Duration::days(10)),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        assert!(matches!(
+            assess(&act, buyer.id(), 80_000, now + Duration::days(90)),
+            AgencyAssessment::Unauthorized(_)
+        ));
+
+        // 自己代理须经被代理人同意
+        assert!(matches!(
+            assess(&act, agent.id(), 80_000, now),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        authority.consent_to(agent.id());
+        let act = AgentAct::new(agent.clone(), Some(authority.clone()));
+        assert_eq!(
+            assess(&act, agent.id(), 80_000, now),
+            AgencyAssessment::Authorized
+        );
+
+        // 转委托不得超出原代理权限，未经同意的须经追认
+        assert!(authority
+            .sub_delegate(sub_agent.as_ref(), AgencyScope::general(), now, false)
+            .is_err());
+        let sub = authority
+            .sub_delegate(sub_agent.as_ref(), limited_to(50_000), now, false)
+            .unwrap();
+        let sub_act = AgentAct::new(sub_agent.clone(), Some(sub));
+        assert!(matches!(
+            assess(&sub_act, buyer.id(), 30_000, now),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        authority.permit_sub_agency();
+        let sub = authority
+            .sub_delegate(sub_agent.as_ref(), limited_to(50_000), now, false)
+            .unwrap();
+        let sub_act = AgentAct::new(sub_agent.clone(), Some(sub));
+        assert_eq!(
+            assess(&sub_act, buyer.id(), 30_000, now),
+            AgencyAssessment::Authorized
+        );
+
+        // 代理权终止后授权委托书未收回的，未被告知的相对人可以主张表见代理
+        let later = now + Duration::days(10);
+        authority
+            .terminate(
+                AgencyTermination::RevokedByPrincipal,
+                now + Duration::days(5),
+            )
+            .unwrap();
+        let stale =
+            |authority: &AgencyAuthority| AgentAct::new(agent.clone(), Some(authority.clone()));
+        assert!(matches!(
+            assess(&stale(&authority), buyer.id(), 80_000, later),
+            AgencyAssessment::Apparent(_)
+        ));
+        // 超出原代理权限或者委托书记载期限的，不构成表见代理
+        assert!(matches!(
+            assess(&stale(&authority), buyer.id(), 120_000, later),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        assert!(matches!(
+            assess(
+                &stale(&authority),
+                buyer.id(),
+                80_000,
+                now + Duration::days(90)
+            ),
+            AgencyAssessment::Unauthorized(_)
+        ));
+
+        // 已被告知的相对人不能相信代理权存续，其他相对人仍可
+        let other = Arc::new(adult(&clock));
+        authority.notify_termination(buyer.id());
+        assert!(matches!(
+            assess(&stale(&authority), buyer.id(), 80_000, later),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        assert!(matches!(
+            assess(&stale(&authority), other.id(), 80_000, later),
+            AgencyAssessment::Apparent(_)
+        ));
+
+        // 收回授权委托书后，外观不复存在
+        authority.withdraw(later).unwrap();
+        assert!(authority.withdraw(later).is_err());
+        assert!(matches!(
+            assess(&stale(&authority), other.id(), 80_000, later),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        assert!(matches!(
+            assess(
+                &stale(&authority),
+                other.id(),
+                80_000,
+                later - Duration::days(1)
+            ),
+            AgencyAssessment::Apparent(_)
+        ));
+    }
+
+    #[test]
+    fn test_sealed_forms_ground_apparent_agency() {
+        let now = now();
+        let clock: Arc<dyn Clock> = Arc::new(ManualClock::new(now));
+        let (principal, holder, stranger, buyer) = (
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+        );
+        let assess = |act: &AgentAct, recipient: Option<Uuid>, at| {
+            assess_agent_act(principal.as_ref(), act, recipient, &sale(80_000), at).unwrap()
+        };
+        assert!(SealedForms::issue(principal.as_ref(), principal.as_ref(), now).is_err());
+
+        let mut forms = SealedForms::issue(principal.as_ref(), holder.as_ref(), now).unwrap();
+        let act = AgentAct::new(holder.clone(), None).with_sealed_forms(forms.clone());
+        assert!(matches!(
+            assess(&act, Some(buyer.id()), now),
+            AgencyAssessment::Apparent(_)
+        ));
+        // 没有相对人或者交付之前，无从产生信赖
+        assert!(matches!(
+            assess(&act, None, now),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        assert!(matches!(
+            assess(&act, Some(buyer.id()), now - Duration::days(1)),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        // 他人持有的空白合同书，或者他人盖章的空白合同书，不构成外观
+        let borrowed = AgentAct::new(stranger.clone(), None).with_sealed_forms(forms.clone());
+        assert!(matches!(
+            assess(&borrowed, Some(buyer.id()), now),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        let foreign = SealedForms::issue(stranger.as_ref(), holder.as_ref(), now).unwrap();
+        let act = AgentAct::new(holder.clone(), None).with_sealed_forms(foreign);
+        assert!(matches!(
+            assess(&act, Some(buyer.id()), now),
+            AgencyAssessment::Unauthorized(_)
+        ));
+
+        // 告知相对人或者收回后，不构成表见代理
+        forms.notify(buyer.id());
+        let act = AgentAct::new(holder.clone(), None).with_sealed_forms(forms.clone());
+        assert!(matches!(
+            assess(&act, Some(buyer.id()), now),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        let later = now + Duration::days(1);
+        forms.withdraw(later).unwrap();
+        let act = AgentAct::new(holder.clone(), None).with_sealed_forms(forms);
+        assert!(matches!(
+            assess(&act, Some(stranger.id()), now),
+            AgencyAssessment::Apparent(_)
+        ));
+        assert!(matches!(
+            assess(&act, Some(stranger.id()), later),
+            AgencyAssessment::Unauthorized(_)
+        ));
+    }
+
+    #[test]
+    fn test_unauthorized_and_dual_agency_contracts() {
+        let now = now();
+        let clock: Arc<dyn Clock> = Arc::new(ManualClock::new(now));
+        let (seller, buyer, agent) = (
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+        );
+        let (seller_dyn, buyer_dyn): (Arc<dyn Entity>, Arc<dyn Entity>) =
+            (seller.clone(), buyer.clone());
+        let mut for_seller = AgencyAuthority::entrusted(
+            seller.as_ref(),
+            agent.as_ref(),
+            limited_to(100_000),
+            now,
+            None,
+        )
+        .unwrap();
+        let mut for_buyer = AgencyAuthority::entrusted(
+            buyer.as_ref(),
+            agent.as_ref(),
+            AgencyScope::general(),
+            now,
+            None,
+        )
+        .unwrap();
+
+        let contract =
+            |for_seller: &AgencyAuthority, for_buyer: Option<&AgencyAuthority>, price| {
+                let offer = IntentDeclaration::through_agent_with_clock(
+                    DeclarationType::Offer,
+                    seller_dyn.clone(),
+                    AgentAct::new(agent.clone(), Some(for_seller.clone())),
+                    Some(buyer_dyn.clone()),
+                    sale(price),
+                    None,
+                    clock.clone(),
+                )
+                .unwrap();
+                let acceptance = match for_buyer {
+                    Some(a) => IntentDeclaration::through_agent_with_clock(
+                        DeclarationType::Acceptance,
+                        buyer_dyn.clone(),
+                        AgentAct::new(agent.clone(), Some(a.clone())),
+                        Some(seller_dyn.clone()),
+                        sale(price),
+                        None,
+                        clock.clone(),
+                    ),
+                    None => IntentDeclaration::with_clock(
+                        DeclarationType::Acceptance,
+                        buyer_dyn.clone(),
+                        Some(seller_dyn.clone()),
+                        sale(price),
+                        None,
+                        clock.clone(),
+                    ),
+                }
+                .unwrap();
+                BaseContract::with_clock(
+                    vec![seller_dyn.clone(), buyer_dyn.clone()],
+                    vec![offer, acceptance],
+                    vec![],
+                    None,
+                    clock.clone(),
+                )
+            };
+
+        // 超越代理权订立的合同，只能由被代理人追认
+        let mut exceeded = contract(&for_seller, None, 150_000);
+        exceeded.make_effective().unwrap();
+        assert_eq!(exceeded.status(), ContractStatus::PendingRatification);
+        assert_eq!(
+            exceeded.ratifications()[0].ground(),
+            &RatificationGround::UnauthorizedAgency {
+                principal: seller.id()
+            }
+        );
+        assert!(exceeded.ratify(seller.id(), agent.as_ref()).is_err());
+        exceeded.ratify(seller.id(), seller.as_ref()).unwrap();
+        assert_eq!(exceeded.status(), ContractStatus::Effective);
+
+        // 双方代理须经被代理的双方同意
+        let mut dual = contract(&for_seller, Some(&for_buyer), 80_000);
+        dual.make_effective().unwrap();
+        assert_eq!(dual.ratifications().len(), 2);
+        for_seller.consent_to(buyer.id());
+        for_buyer.consent_to(seller.id());
+        let mut consented = contract(&for_seller, Some(&for_buyer), 80_000);
+        consented.make_effective().unwrap();
+        assert_eq!(consented.status(), ContractStatus::Effective);
+    }
+
+    fn registered_company(clock: &Arc<dyn Clock>, director: Uuid) -> LegalPerson {
+        let now = clock.now();
+        let mut company = LegalPerson::with_clock(
+            LegalPersonType::Company(CompanyType::Limited),
+            Money::cny(Decimal::from(1_000_000)),
+            director,
+            "上海市徐汇区xxx路".to_string(),
+            now - Duration::days(365),
+            clock.clone(),
+        );
+        company
+            .register(
+                "上海市徐汇区市场监督管理局".to_string(),
+                "91310104MA0000000X".to_string(),
+                now - Duration::days(365),
+            )
+            .unwrap();
+        company
+    }
+
+    #[test]
+    fn test_staff_authority_comes_from_register() {
+        let now = now();
+        let clock: Arc<dyn Clock> = Arc::new(ManualClock::new(now));
+        let (director, clerk, porter, outsider, buyer) = (
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+        );
+        let mut company = registered_company(&clock, director.id());
+        company
+            .appoint_staff(clerk.as_ref(), vec![SubjectMatterType::SpecificGoods])
+            .unwrap();
+        company
+            .appoint_staff(porter.as_ref(), vec![SubjectMatterType::Service])
+            .unwrap();
+        let assess = |company: &LegalPerson, person: &Arc<NaturalPerson>| {
+            assess_signatory(
+                company,
+                &Signatory::staff(person.clone()),
+                Some(buyer.id()),
+                &sale(80_000),
+                clock.now(),
+            )
+            .unwrap()
+        };
+
+        // 工作人员就人员名册所载职权范围内的事项签署
+        assert_eq!(assess(&company, &clerk), AgencyAssessment::Authorized);
+        assert!(matches!(
+            assess(&company, &porter),
+            AgencyAssessment::Unauthorized(_)
+        ));
+
+        // 名册以外的人不能自称工作人员，离职后亦不再有职权
+        assert!(matches!(
+            assess(&company, &outsider),
+            AgencyAssessment::Unauthorized(_)
+        ));
+        company.dismiss_staff(clerk.id()).unwrap();
+        assert!(matches!(
+            assess(&company, &clerk),
+            AgencyAssessment::Unauthorized(_)
+        ));
+    }
+
+    #[test]
+    fn test_organisation_cannot_declare_in_person() {
+        let now = now();
+        let clock: Arc<dyn Clock> = Arc::new(ManualClock::new(now));
+        let (director, buyer) = (Arc::new(adult(&clock)), Arc::new(adult(&clock)));
+        let company: Arc<dyn Entity> = Arc::new(registered_company(&clock, director.id()));
+        let buyer: Arc<dyn Entity> = buyer;
+        let declare = |declarant: &Arc<dyn Entity>, recipient: &Arc<dyn Entity>| {
+            IntentDeclaration::with_clock(
+                DeclarationType::Offer,
+                declarant.clone(),
+                Some(recipient.clone()),
+                sale(80_000),
+                None,
+                clock.clone(),
+            )
+        };
+        assert!(declare(&company, &buyer).is_err());
+        assert_eq!(declare(&buyer, &company).unwrap().signer().id(), buyer.id());
+
+        let signed = IntentDeclaration::signed_by_with_clock(
+            DeclarationType::Offer,
+            company.clone(),
+            Signatory::representative(director.clone()),
+            Some(buyer.clone()),
+            sale(80_000),
+            None,
+            clock.clone(),
+        )
+        .unwrap();
+        assert_eq!(signed.signer().id(), director.id());
+    }
+
+    #[test]
+    fn test_organisation_signatories() {
+        let now = now();
+        let clock: Arc<dyn Clock> = Arc::new(ManualClock::new(now));
+        let (director, clerk, impostor, buyer) = (
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+        );
+        // 章程限制法定代表人单项交易的价款，组织只将该限制告知了买受人的一份副本
+        let mut company = registered_company(&clock, director.id());
+        company
+            .limit_authority(
+                director.id(),
+                None,
+                Some(Money::cny(Decimal::from(100_000))),
+            )
+            .unwrap();
+        let mut informed = company.clone();
+        informed
+            .notify_internal_limit(director.id(), buyer.id())
+            .unwrap();
+        let company = Arc::new(company);
+        let (company_dyn, informed_dyn, buyer_dyn): (
+            Arc<dyn Entity>,
+            Arc<dyn Entity>,
+            Arc<dyn Entity>,
+        ) = (company.clone(), Arc::new(informed), buyer.clone());
+        let offer_by = |declarant: &Arc<dyn Entity>, signatory: Signatory, price| {
+            IntentDeclaration::signed_by_with_clock(
+                DeclarationType::Offer,
+                declarant.clone(),
+                signatory,
+                Some(buyer_dyn.clone()),
+                sale(price),
+                None,
+                clock.clone(),
+            )
+        };
+        let offer = |signatory: Signatory, price| offer_by(&company_dyn, signatory, price);
+
+        // 章程对法定代表人的限制不得对抗未被告知的善意相对人
+        let by_director = offer(Signatory::representative(director.clone()), 150_000).unwrap();
+        assert!(!by_director.is_pending_ratification());
+        assert_eq!(by_director.signer().id(), director.id());
+        let within = offer_by(
+            &informed_dyn,
+            Signatory::representative(director.clone()),
+            80_000,
+        )
+        .unwrap();
+        assert!(!within.is_pending_ratification());
+        let known = offer_by(
+            &informed_dyn,
+            Signatory::representative(director.clone()),
+            150_000,
+        )
+        .unwrap();
+        assert!(known.is_pending_ratification());
+        assert!(offer(Signatory::representative(impostor.clone()), 80_000)
+            .unwrap()
+            .is_pending_ratification());
+
+        // 名册以外的人以工作人员身份签署的，须经组织追认
+        let outside = offer(Signatory::staff(clerk.clone()), 80_000).unwrap();
+        assert_eq!(
+            outside.ratification_ground(),
+            Some(RatificationGround::UnauthorizedAgency {
+                principal: company.id()
+            })
+        );
+
+        // 自然人不由签署人代表
+        assert!(IntentDeclaration::signed_by_with_clock(
+            DeclarationType::Acceptance,
+            buyer_dyn.clone(),
+            Signatory::representative(clerk.clone()),
+            Some(company_dyn.clone()),
+            sale(80_000),
+            None,
+            clock.clone(),
+        )
+        .is_err());
+
+        // 合同解析出为公司签署的人
+        let acceptance = IntentDeclaration::with_clock(
+            DeclarationType::Acceptance,
+            buyer_dyn.clone(),
+            Some(company_dyn.clone()),
+            sale(150_000),
+            None,
+            clock.clone(),
+        )
+        .unwrap();
+        let mut contract = BaseContract::with_clock(
+            vec![company_dyn.clone(), buyer_dyn.clone()],
+            vec![known, acceptance],
+            vec![],
+            None,
+            clock.clone(),
+        );
+        assert_eq!(contract.signers(company.id()).unwrap(), vec![director.id()]);
+        assert_eq!(contract.signers(buyer.id()).unwrap(), vec![buyer.id()]);
+        contract.make_effective().unwrap();
+        assert_eq!(contract.status(), ContractStatus::PendingRatification);
+        contract.ratify(company.id(), company.as_ref()).unwrap();
+        assert_eq!(contract.status(), ContractStatus::Effective);
+    }
+}
@@ -1,8 +1,14 @@
 //! 合同的基础定义
 //! 包括合同的基本特征和通用结构
 
+use super::agency::dual_representation;
 use super::intent::declaration::{DeclarationType, IntentDeclaration};
-use crate::core::entity::Entity;
+use super::ratification::{Ratification, RatificationGround, RatificationOutcome};
+use crate::core::clock::{default_clock, Clock};
+use crate::core::entity::{Entity, SuccessionAware, SuccessionRegistry};
+use crate::validate::business_scope::{check_business_activity, ScopeAssessment};
+use crate::validate::capacity::CapacityClassifier;
+use crate::validate::legal_person::check_restricted_act;
 use crate::{FanError, FanResult, ValidationErrorType};
 
 use chrono::{DateTime, Utc};
@@ -11,6 +17,7 @@ use uuid::Uuid;
 
 /// 合同条款
 #[derive(Debug, Clone)]
+#[allow(dead_code)] // 尚未参与合同的校验，仅通过 Debug 输出
 pub struct ContractTerm {
     /// 条款序号
     number: u32,
@@ -18,11 +25,43 @@ pub struct ContractTerm {
     content: String,
 }
 
+/// 原当事人因合并、分立或者设立中的法人成立而终止，由继受主体承受其合同地位（第六十七条、第七十五条）
+#[derive(Debug, Clone)]
+pub struct PartySuccession {
+    /// 原当事人
+    predecessor: Arc<dyn Entity>,
+    /// 承受其合同地位的主体
+    successors: Vec<Uuid>,
+    /// 继受主体中原非合同当事人的
+    joined: Vec<Uuid>,
+    /// 承受之时
+    at: DateTime<Utc>,
+}
+
+impl PartySuccession {
+    /// 获取原当事人
+    pub fn predecessor(&self) -> Arc<dyn Entity> {
+        self.predecessor.clone()
+    }
+
+    /// 获取承受其合同地位的主体
+    pub fn successors(&self) -> &[Uuid] {
+        &self.successors
+    }
+
+    /// 获取承受之时
+    pub fn at(&self) -> DateTime<Utc> {
+        self.at
+    }
+}
+
 /// 合同状态
 #[derive(Debug, Clone, PartialEq)]
 pub enum ContractStatus {
     /// 合同已订立但尚未生效
     Created,
+    /// 合同已成立但效力待定，等待法定代理人或被代理人追认
+    PendingRatification,
     /// 合同已经生效
     Effective,
     /// 合同正在履行中
@@ -79,24 +118,42 @@ pub trait Contract {
 /// - `effective_at`: 生效时间，表示合同的生效时间
 /// - `time_limit`: 履行期限，为一个可选的`DateTime`对象，表示合同的履行期限
 /// - `status`: 合同状态，表示合同的当前状态
+/// - `ratifications`: 效力待定时各当事人的追认程序
+/// - `aware_counterparties`: 订立合同时知道或者应当知道合同效力待定的相对人
+/// - `successions`: 因合并、分立或者法人成立而由他人承受其合同地位的原当事人
+/// - `classifier`: 判断限制民事行为能力人的行为是否与其年龄、智力相适应的标准
 #[derive(Debug)]
 pub struct BaseContract {
     /// 合同ID
     id: Uuid,
-    /// 合同当事人
+    /// 现在的合同当事人，已终止的当事人由其继受主体代替
     parties: Vec<Arc<dyn Entity>>,
     /// 订立合同过程中的意思表示
     intent_declarations: Vec<IntentDeclaration>,
     /// 合同条款
+    #[allow(dead_code)] // 尚未参与合同的校验
     terms: Vec<ContractTerm>,
     /// 创建时间
     created_at: DateTime<Utc>,
     /// 生效时间
     effective_at: Option<DateTime<Utc>>,
     /// 履行期限
+    #[allow(dead_code)] // 尚未参与合同的校验
     time_limit: Option<DateTime<Utc>>,
     /// 合同状态
     status: ContractStatus,
+    /// 合同成立时间，效力待定的合同经追认后自此时生效
+    concluded_at: Option<DateTime<Utc>>,
+    /// 效力待定时各当事人的追认程序
+    ratifications: Vec<Ratification>,
+    /// 订立合同时知道或者应当知道合同效力待定的相对人，不享有撤销权
+    aware_counterparties: Vec<Uuid>,
+    /// 由他人承受其合同地位的原当事人
+    successions: Vec<PartySuccession>,
+    /// 判断当事人行为能力所采用的标准
+    classifier: CapacityClassifier,
+    /// 合同状态变更所依据的时钟
+    clock: Arc<dyn Clock>,
 }
 
 impl BaseContract {
@@ -123,21 +180,237 @@ impl BaseContract {
         intent_declarations: Vec<IntentDeclaration>,
         terms: Vec<ContractTerm>,
         time_limit: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self::with_clock(
+            parties,
+            intent_declarations,
+            terms,
+            time_limit,
+            default_clock(),
+        )
+    }
+
+    /// 使用指定时钟创建新的合同
+    ///
+    /// 合同的创建时间、生效时间均以该时钟为准。
+    pub fn with_clock(
+        parties: Vec<Arc<dyn Entity>>,
+        intent_declarations: Vec<IntentDeclaration>,
+        terms: Vec<ContractTerm>,
+        time_limit: Option<DateTime<Utc>>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
             id: Uuid::new_v4(),
             parties,
             intent_declarations,
             terms,
-            created_at: Utc::now(),
+            created_at: clock.now(),
             effective_at: None,
             time_limit,
             status: ContractStatus::Created,
+            concluded_at: None,
+            ratifications: Vec::new(),
+            aware_counterparties: Vec::new(),
+            successions: Vec::new(),
+            classifier: CapacityClassifier::default(),
+            clock,
+        }
+    }
+
+    /// 采用指定的标准判断限制民事行为能力人订立的合同的效力
+    ///
+    /// 未指定的采用 [`CapacityClassifier::default`]。
+    pub fn with_classifier(mut self, classifier: CapacityClassifier) -> Self {
+        self.classifier = classifier;
+        self
+    }
+
+    /// 获取生效时间
+    pub fn effective_at(&self) -> Option<DateTime<Utc>> {
+        self.effective_at
+    }
+
+    /// 以继受主体代替已终止的当事人，记录承受之时
+    fn succeed_party(
+        &mut self,
+        index: usize,
+        expected: Vec<Uuid>,
+        successors: Vec<Arc<dyn Entity>>,
+    ) {
+        let predecessor = self.parties.remove(index);
+        // 继受主体承受原当事人的地位，原当事人订立合同时的知情亦及于继受主体
+        if self.aware_counterparties.contains(&predecessor.id()) {
+            self.aware_counterparties.extend(expected.iter().copied());
+        }
+        let mut joined = Vec::new();
+        for successor in successors {
+            if !self.parties.iter().any(|p| p.id() == successor.id()) {
+                joined.push(successor.id());
+                self.parties.push(successor);
+            }
+        }
+        self.successions.push(PartySuccession {
+            predecessor,
+            successors: expected,
+            joined,
+            at: self.clock.now(),
+        });
+    }
+
+    /// 现在的当事人，以及已由他人承受其合同地位的原当事人
+    fn known_party(&self, party: Uuid) -> Option<&Arc<dyn Entity>> {
+        self.parties
+            .iter()
+            .chain(self.successions.iter().map(|s| &s.predecessor))
+            .find(|p| p.id() == party)
+    }
+
+    /// 现在承受某一当事人合同地位的主体，依登记簿解析：未终止的为其本身，已终止的为其继受主体
+    ///
+    /// 合同尚未经 [`SuccessionAware::rebind_successors`] 代替当事人的，亦依登记簿解析。
+    pub fn party_successors(
+        &self,
+        party: Uuid,
+        registry: &SuccessionRegistry,
+    ) -> FanResult<Vec<Uuid>> {
+        if self.known_party(party).is_none() {
+            return Err(FanError::validation(
+                "该主体不是合同当事人",
+                ValidationErrorType::ContractPartyUnqualified,
+                "party_successors",
+                "BaseContract",
+            ));
+        }
+        Ok(registry.current_successors(party))
+    }
+
+    /// 获取由他人承受其合同地位的原当事人
+    pub fn successions(&self) -> &[PartySuccession] {
+        &self.successions
+    }
+
+    /// 当事人本身及其承受合同地位的全部原当事人
+    fn represented(&self, party: Uuid) -> Vec<Uuid> {
+        let mut represented = vec![party];
+        let mut i = 0;
+        while i < represented.len() {
+            let current = represented[i];
+            for succession in &self.successions {
+                let predecessor = succession.predecessor.id();
+                if succession.successors.contains(&current) && !represented.contains(&predecessor) {
+                    represented.push(predecessor);
+                }
+            }
+            i += 1;
+        }
+        represented
+    }
+
+    /// 订立合同的当事人：合同成立后才承受合同地位的主体由其原当事人代替
+    ///
+    /// 当事人能否实施合同约定的行为依合同成立时判断，不因其后的合并、分立而改变。
+    fn concluding_parties(&self) -> Vec<&Arc<dyn Entity>> {
+        let after_conclusion: Vec<&PartySuccession> = self
+            .successions
+            .iter()
+            .filter(|s| self.concluded_at.is_some_and(|concluded| s.at >= concluded))
+            .collect();
+        let joined = |id: Uuid| after_conclusion.iter().any(|s| s.joined.contains(&id));
+        self.parties
+            .iter()
+            .filter(|p| !joined(p.id()))
+            .chain(after_conclusion.iter().map(|s| &s.predecessor))
+            .collect()
+    }
+
+    /// 主体承受合同地位之时；原为当事人的为合同订立之时
+    fn joined_at(&self, party: Uuid) -> DateTime<Utc> {
+        self.successions
+            .iter()
+            .find(|s| s.joined.contains(&party))
+            .map_or(self.created_at, |s| s.at)
+    }
+
+    /// 对当事人的合同债务承担责任的主体，依次为承担责任的当事人或其继受法人，以及对其承担补充责任的主体
+    ///
+    /// 当事人因合并、分立终止的，由登记簿记载的继受法人承担，分立后的法人承担连带责任（第六十七条）；
+    /// 分支机构以自己的名义订立合同的，先以其管理的财产承担，不足以承担的由所属法人承担（第七十四条）；
+    /// 个人独资企业的财产不足以清偿的，由投资人承担（第一百零四条）；个体工商户、农村承包经营户的债务
+    /// 以经营者个人或者家庭的财产承担（第五十六条）。
+    pub fn liable_entities(
+        &self,
+        party: Uuid,
+        registry: &SuccessionRegistry,
+    ) -> FanResult<Vec<Uuid>> {
+        if self.known_party(party).is_none() {
+            return Err(FanError::validation(
+                "该主体不是合同当事人",
+                ValidationErrorType::ContractPartyUnqualified,
+                "liable_entities",
+                "BaseContract",
+            ));
+        }
+        let mut liable = Vec::new();
+        for id in registry.liable_for(party) {
+            let bearers = self
+                .known_party(id)
+                .map(|entity| entity.liability_bearers())
+                .unwrap_or_default();
+            for id in std::iter::once(id).chain(bearers) {
+                if !liable.contains(&id) {
+                    liable.push(id);
+                }
+            }
+        }
+        Ok(liable)
+    }
+
+    /// 实际为当事人作出意思表示的人
+    ///
+    /// 法人、非法人组织由其法定代表人、负责人或者执行工作任务的人员签署（第六十一条、第一百七十条），
+    /// 被代理人、被监护人由代理人、监护人作出，其他当事人为其本人。
+    pub fn signers(&self, party: Uuid) -> FanResult<Vec<Uuid>> {
+        if !self.parties.iter().any(|p| p.id() == party) {
+            return Err(FanError::validation(
+                "该主体不是合同当事人",
+                ValidationErrorType::ContractPartyUnqualified,
+                "signers",
+                "BaseContract",
+            ));
+        }
+        let mut signers = Vec::new();
+        for declaration in &self.intent_declarations {
+            let signer = declaration.signer().id();
+            if declaration.declarant().id() == party && !signers.contains(&signer) {
+                signers.push(signer);
+            }
         }
+        Ok(signers)
+    }
+
+    /// 获取效力待定时各当事人的追认程序
+    pub fn ratifications(&self) -> &[Ratification] {
+        &self.ratifications
     }
 
     /// 检查当事人的主体资格
     fn validate_parties(&self) -> FanResult<()> {
+        // 限制民事行为能力人独立订立的、与其年龄智力不相适应的合同效力待定
+        if let Some((_, _, reason)) = self.unratified_parties()?.into_iter().next() {
+            return Err(FanError::validation_with_ref(
+                format!("合同效力待定：{}", reason),
+                ValidationErrorType::ContractStatusIllegal,
+                "《民法典》第一百四十五条",
+                "validate_parties",
+                "BaseContract",
+            ));
+        }
+        Ok(())
+    }
+
+    /// 检查每个当事人的行为能力和代理权，返回意思表示效力待定且尚未被追认的当事人、追认事由及原因
+    fn unratified_parties(&self) -> FanResult<Vec<(Uuid, RatificationGround, String)>> {
         // 检查当事人数量
         if self.parties.is_empty() {
             return Err(FanError::validation(
@@ -148,18 +421,295 @@ impl BaseContract {
             ));
         }
 
-        // 检查每个当事人的行为能力
+        // 以其作出意思表示之时为准，承受合同地位的主体以原当事人的意思表示为准；
+        // 未作出意思表示的当事人以合同订立或者承受合同地位之时为准
+        let mut pending = Vec::new();
         for party in &self.parties {
-            if !party.has_capacity() {
-                return Err(FanError::validation(
-                    "当事人缺乏必要的行为能力",
-                    ValidationErrorType::EntityCapacityLacking,
-                    "validate_parties",
+            let represented = self.represented(party.id());
+            let declarations: Vec<&IntentDeclaration> = self
+                .intent_declarations
+                .iter()
+                .filter(|d| represented.contains(&d.declarant().id()))
+                .collect();
+
+            if declarations.is_empty() {
+                if !party.has_capacity_at(self.joined_at(party.id())) {
+                    return Err(FanError::validation(
+                        "当事人缺乏必要的行为能力",
+                        ValidationErrorType::EntityCapacityLacking,
+                        "validate_parties",
+                        "BaseContract",
+                    ));
+                }
+                continue;
+            }
+
+            let mut ground = None;
+            let mut reasons = Vec::new();
+            for declaration in declarations {
+                if let Some(reason) = declaration.assess_capacity(&self.classifier)? {
+                    ground = ground.or(Some(declaration.ground_if_pending()));
+                    reasons.push(reason);
+                }
+            }
+            // 同一代理人同时代理双方的，须经被代理的双方同意或者追认
+            for principal in &represented {
+                if let Some(reason) = dual_representation(&self.intent_declarations, *principal) {
+                    ground = ground.or(Some(RatificationGround::UnauthorizedAgency {
+                        principal: *principal,
+                    }));
+                    reasons.push(reason);
+                }
+            }
+            let ratified = self
+                .ratifications
+                .iter()
+                .any(|r| represented.contains(&r.party()) && r.is_ratified());
+            if let (Some(ground), false) = (ground, ratified) {
+                pending.push((party.id(), ground, reasons.join("；")));
+            }
+        }
+
+        Ok(pending)
+    }
+
+    /// 相对人催告法定代理人或被代理人自收到通知之日起三十日内予以追认（第一百四十五条第二款、第一百七十一条第二款）
+    pub fn notify_ratification(&mut self, party: Uuid, counterparty: Uuid) -> FanResult<()> {
+        self.check_ratification_deadlines();
+        self.ensure_pending("notify_ratification")?;
+        self.ensure_counterparty(counterparty, "notify_ratification")?;
+        let now = self.clock.now();
+        self.ratification_mut(party, "notify_ratification")?
+            .notify(counterparty, now)
+    }
+
+    /// 法定代理人、被代理人追认，或者当事人取得完全民事行为能力后自行追认
+    pub fn ratify(&mut self, party: Uuid, ratifier: &dyn Entity) -> FanResult<()> {
+        self.respond(party, ratifier, true)
+    }
+
+    /// 法定代理人、被代理人拒绝追认，合同自始无效
+    pub fn refuse_ratification(&mut self, party: Uuid, ratifier: &dyn Entity) -> FanResult<()> {
+        self.respond(party, ratifier, false)
+    }
+
+    /// 记录某一相对人在订立合同时知道或者应当知道表意人欠缺行为能力或者代理权
+    ///
+    /// 只能在合同成立之前记录，例如相对人收到了法定代理人或者被代理人的告知；该相对人此后不享有撤销权。
+    pub fn record_counterparty_knowledge(&mut self, counterparty: Uuid) -> FanResult<()> {
+        if self.status != ContractStatus::Created {
+            return Err(FanError::validation(
+                "相对人订立合同时的知情只能在合同成立之前记录",
+                ValidationErrorType::ContractStatusIllegal,
+                "record_counterparty_knowledge",
+                "BaseContract",
+            ));
+        }
+        if !self.parties.iter().any(|p| p.id() == counterparty) {
+            return Err(FanError::validation(
+                "该主体不是合同当事人",
+                ValidationErrorType::ContractPartyUnqualified,
+                "record_counterparty_knowledge",
+                "BaseContract",
+            ));
+        }
+        if !self.aware_counterparties.contains(&counterparty) {
+            self.aware_counterparties.push(counterparty);
+        }
+        Ok(())
+    }
+
+    /// 合同被追认之前，善意相对人以通知的方式撤销（第一百四十五条第二款、第一百七十一条第二款）
+    ///
+    /// 相对人是否善意，以合同成立前记录的知情为准，见 [`BaseContract::record_counterparty_knowledge`]。
+    pub fn revoke_by_counterparty(&mut self, counterparty: Uuid) -> FanResult<()> {
+        self.check_ratification_deadlines();
+        self.ensure_pending("revoke_by_counterparty")?;
+        self.ensure_counterparty(counterparty, "revoke_by_counterparty")?;
+        if self.aware_counterparties.contains(&counterparty) {
+            return Err(FanError::validation_with_ref(
+                "相对人订立合同时知道或者应当知道合同效力待定的，不享有撤销权",
+                ValidationErrorType::OperationUnauthorized,
+                "《民法典》第一百四十五条第二款",
+                "revoke_by_counterparty",
+                "BaseContract",
+            ));
+        }
+        let now = self.clock.now();
+        for ratification in self.ratifications.iter_mut().filter(|r| !r.is_resolved()) {
+            ratification.resolve(RatificationOutcome::RevokedByCounterparty {
+                by: counterparty,
+                at: now,
+            })?;
+        }
+        self.settle_ratifications();
+        Ok(())
+    }
+
+    /// 催告期满未作表示的，视为拒绝追认
+    pub fn check_ratification_deadlines(&mut self) {
+        if self.status != ContractStatus::PendingRatification {
+            return;
+        }
+        let now = self.clock.now();
+        for ratification in &mut self.ratifications {
+            ratification.lapse(now);
+        }
+        self.settle_ratifications();
+    }
+
+    fn respond(&mut self, party: Uuid, ratifier: &dyn Entity, ratified: bool) -> FanResult<()> {
+        let operation = if ratified {
+            "ratify"
+        } else {
+            "refuse_ratification"
+        };
+        self.check_ratification_deadlines();
+        self.ensure_pending(operation)?;
+        let now = self.clock.now();
+        let ground = self.ratification_mut(party, operation)?.ground().clone();
+        let party_entity = self
+            .parties
+            .iter()
+            .find(|p| p.id() == party)
+            .ok_or_else(|| {
+                FanError::validation(
+                    "追认程序只针对合同当事人",
+                    ValidationErrorType::ContractPartyUnqualified,
+                    operation,
+                    "BaseContract",
+                )
+            })?;
+
+        let authorized = ratifier.has_capacity_at(now)
+            && match ground {
+                // 法定代理人，或者取得完全民事行为能力的当事人本人
+                RatificationGround::Capacity => {
+                    ratifier.id() == party
+                        || party_entity
+                            .guardianships_at(now)
+                            .iter()
+                            .any(|g| g.guardian() == ratifier.id())
+                }
+                RatificationGround::UnauthorizedAgency { principal } => ratifier.id() == principal,
+            };
+        if !authorized {
+            return Err(FanError::validation_with_ref(
+                "只有法定代理人或者被代理人才能追认或拒绝追认",
+                ValidationErrorType::OperationUnauthorized,
+                "《民法典》第一百四十五条、第一百七十一条",
+                operation,
+                "BaseContract",
+            ));
+        }
+
+        let outcome = if ratified {
+            RatificationOutcome::Ratified {
+                by: ratifier.id(),
+                at: now,
+            }
+        } else {
+            RatificationOutcome::Refused {
+                by: ratifier.id(),
+                at: now,
+            }
+        };
+        self.ratification_mut(party, operation)?.resolve(outcome)?;
+        self.settle_ratifications();
+        Ok(())
+    }
+
+    /// 根据追认程序的结果确定合同效力：拒绝追认的无效，相对人撤销的被撤销，全部追认的自成立时生效
+    fn settle_ratifications(&mut self) {
+        let outcomes = || self.ratifications.iter().filter_map(|r| r.outcome());
+        if outcomes().any(|o| {
+            matches!(
+                o,
+                RatificationOutcome::Refused { .. } | RatificationOutcome::DeemedRefused { .. }
+            )
+        }) {
+            self.status = ContractStatus::Invalid;
+        } else if outcomes().any(|o| matches!(o, RatificationOutcome::RevokedByCounterparty { .. }))
+        {
+            self.status = ContractStatus::Revoked;
+        } else if self.ratifications.iter().all(|r| r.is_ratified()) {
+            self.status = ContractStatus::Effective;
+            self.effective_at = self.concluded_at;
+        }
+    }
+
+    fn ensure_pending(&self, operation: &str) -> FanResult<()> {
+        if self.status != ContractStatus::PendingRatification {
+            return Err(FanError::validation(
+                "合同不处于效力待定状态",
+                ValidationErrorType::ContractStatusIllegal,
+                operation,
+                "BaseContract",
+            ));
+        }
+        Ok(())
+    }
+
+    /// 催告与撤销只能由意思表示效力不受影响的其他当事人作出
+    fn ensure_counterparty(&self, counterparty: Uuid, operation: &str) -> FanResult<()> {
+        let is_party = self.parties.iter().any(|p| p.id() == counterparty);
+        let is_pending = self.ratifications.iter().any(|r| r.party() == counterparty);
+        if !is_party || is_pending {
+            return Err(FanError::validation(
+                "只有合同的相对人才能催告或撤销",
+                ValidationErrorType::ContractPartyUnqualified,
+                operation,
+                "BaseContract",
+            ));
+        }
+        Ok(())
+    }
+
+    fn ratification_mut(&mut self, party: Uuid, operation: &str) -> FanResult<&mut Ratification> {
+        self.ratifications
+            .iter_mut()
+            .find(|r| r.party() == party)
+            .ok_or_else(|| {
+                FanError::validation(
+                    "该当事人的意思表示无须追认",
+                    ValidationErrorType::ContractPartyUnqualified,
+                    operation,
                     "BaseContract",
-                ));
+                )
+            })
+    }
+
+    /// 各当事人从事合同约定的经营活动是否超越经营范围
+    ///
+    /// 超越经营范围的合同不因此无效（第五百零五条）；禁止经营或未取得许可的返回错误。
+    /// 合同已经成立的，以成立之时的许可证为准，其后许可证届满或被吊销不影响合同的效力。
+    pub fn scope_assessments(&self) -> FanResult<Vec<(Uuid, ScopeAssessment)>> {
+        let at = self.concluded_at.unwrap_or_else(|| self.clock.now());
+        let mut assessments = Vec::new();
+        for party in self.concluding_parties() {
+            let mut assessment = ScopeAssessment::NotApplicable;
+            for declaration in &self.intent_declarations {
+                match check_business_activity(party.as_ref(), &declaration.content(), at)? {
+                    ScopeAssessment::NotApplicable => {}
+                    ScopeAssessment::WithinScope
+                        if assessment == ScopeAssessment::BeyondScopeButValid => {}
+                    other => assessment = other,
+                }
             }
+            assessments.push((party.id(), assessment));
         }
+        Ok(assessments)
+    }
 
+    /// 检查法人当事人的类型是否允许其实施合同约定的行为，如保证、利润分配；
+    /// 以及当事人能否从事合同约定由其从事的经营活动
+    fn validate_party_types(&self) -> FanResult<()> {
+        for party in self.concluding_parties() {
+            for declaration in &self.intent_declarations {
+                check_restricted_act(party.as_ref(), &declaration.content())?;
+            }
+        }
+        self.scope_assessments()?;
         Ok(())
     }
 
@@ -230,6 +780,7 @@ impl Contract for BaseContract {
     fn validate(&self) -> FanResult<()> {
         // 验证当事人
         self.validate_parties()?;
+        self.validate_party_types()?;
 
         // 验证意思表示
         self.validate_declarations()?;
@@ -238,12 +789,34 @@ impl Contract for BaseContract {
     }
 
     fn make_effective(&mut self) -> FanResult<()> {
-        // 验证合同
-        self.validate()?;
+        if self.status != ContractStatus::Created {
+            return Err(FanError::validation(
+                "只有尚未生效的合同才能生效",
+                ValidationErrorType::ContractStatusIllegal,
+                "make_effective",
+                "BaseContract",
+            ));
+        }
+
+        // 验证合同；效力待定的，合同成立但须待追认
+        let pending = self.unratified_parties()?;
+        self.validate_party_types()?;
+        self.validate_declarations()?;
+        let now = self.clock.now();
+        self.concluded_at = Some(now);
+
+        if !pending.is_empty() {
+            self.ratifications = pending
+                .into_iter()
+                .map(|(party, ground, reason)| Ratification::new(party, ground, reason))
+                .collect();
+            self.status = ContractStatus::PendingRatification;
+            return Ok(());
+        }
 
         // 更新状态
         self.status = ContractStatus::Effective;
-        self.effective_at = Some(Utc::now());
+        self.effective_at = Some(now);
 
         Ok(())
     }
@@ -266,14 +839,92 @@ impl Contract for BaseContract {
     }
 }
 
+impl SuccessionAware for BaseContract {
+    /// 当事人因合并、分立或者设立中的法人成立而终止的，由登记簿记载的继受主体代替其为合同当事人
+    /// （第六十七条、第七十五条第一款）
+    ///
+    /// 效力待定的合同须先完成追认程序。分立后的法人对合同债务承担连带责任，见 [`BaseContract::liable_entities`]。
+    fn rebind_successors(
+        &mut self,
+        registry: &SuccessionRegistry,
+        entities: &[Arc<dyn Entity>],
+    ) -> FanResult<()> {
+        let retired: Vec<(Uuid, Vec<Uuid>)> = self
+            .parties
+            .iter()
+            .map(|p| (p.id(), registry.current_successors(p.id())))
+            .filter(|(id, successors)| successors != &[*id])
+            .collect();
+        if retired.is_empty() {
+            return Ok(());
+        }
+        if self.status == ContractStatus::PendingRatification {
+            return Err(FanError::validation(
+                "效力待定的合同须先完成追认程序",
+                ValidationErrorType::OperationSequenceWrong,
+                "rebind_successors",
+                "BaseContract",
+            ));
+        }
+
+        // 先找齐全部继受主体，再代替当事人
+        let mut rebinds = Vec::new();
+        for (party, expected) in retired {
+            let successors = expected
+                .iter()
+                .map(|id| {
+                    self.parties
+                        .iter()
+                        .chain(entities)
+                        .find(|e| e.id() == *id)
+                        .cloned()
+                        .ok_or_else(|| {
+                            FanError::validation_with_ref(
+                                "未提供登记簿记载的继受主体",
+                                ValidationErrorType::EntityRelationMalformed,
+                                "《民法典》第六十七条",
+                                "rebind_successors",
+                                "BaseContract",
+                            )
+                        })
+                })
+                .collect::<FanResult<Vec<_>>>()?;
+            rebinds.push((party, expected, successors));
+        }
+        for (party, expected, successors) in rebinds {
+            let index = self
+                .parties
+                .iter()
+                .position(|p| p.id() == party)
+                .expect("已终止的当事人");
+            self.succeed_party(index, expected, successors);
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::contract::agency::Signatory;
+    use crate::contract::intent::content::{
+        BusinessActivity, Price, SubjectMatter, SubjectMatterType,
+    };
+    use crate::contract::IntentContent;
+    use crate::core::clock::{FixedClock, ManualClock};
+    use crate::core::entity::{
+        CompanyType, GuardianshipAction, GuardianshipScope, IndustryCode, LegalPerson,
+        LegalPersonType, Licence, MentalStatus, NaturalPerson, Relationship,
+    };
+    use crate::core::money::{Currency, Money};
+    use crate::validate::capacity::AppropriatenessThresholds;
+    use chrono::{Duration, TimeZone};
+    use rust_decimal::Decimal;
 
     #[test]
     fn test_contract_lifecycle() {
         // 创建测试数据
-        let mut contract = BaseContract::new(
+        let contract = BaseContract::new(
             vec![], // 需要添加测试用的当事人
             vec![], // 需要添加测试用的意思表示
             vec![], // 需要添加测试用的合同条款
@@ -285,4 +936,712 @@ mod tests {
 
         // TODO: 添加更多具体的测试用例
     }
+
+    #[test]
+    fn test_contract_timestamps_follow_clock() {
+        let instant = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        let contract = BaseContract::with_clock(
+            vec![],
+            vec![],
+            vec![],
+            None,
+            Arc::new(FixedClock::new(instant)),
+        );
+
+        assert_eq!(contract.created_at(), instant);
+        assert_eq!(contract.effective_at(), None);
+    }
+
+    fn purchase(price: i64) -> IntentContent {
+        IntentContent {
+            subject_matter: SubjectMatter::new(
+                Uuid::nil(),
+                SubjectMatterType::GenericGoods,
+                "文具".to_string(),
+                None,
+            ),
+            price: Some(Price::new(
+                Money::cny(Decimal::from(price)),
+                "现金".to_string(),
+            )),
+            ..Default::default()
+        }
+    }
+
+    /// 担任法定代表人的自然人
+    fn director(clock: &Arc<dyn Clock>) -> Arc<dyn Entity> {
+        Arc::new(NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(1975, 1, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock.clone(),
+        ))
+    }
+
+    /// 由法定代表人代表法人作出意思表示
+    fn signed(
+        declaration_type: DeclarationType,
+        organisation: &Arc<dyn Entity>,
+        director: &Arc<dyn Entity>,
+        recipient: &Arc<dyn Entity>,
+        content: IntentContent,
+        clock: &Arc<dyn Clock>,
+    ) -> IntentDeclaration {
+        IntentDeclaration::signed_by_with_clock(
+            declaration_type,
+            organisation.clone(),
+            Signatory::representative(director.clone()),
+            Some(recipient.clone()),
+            content,
+            None,
+            clock.clone(),
+        )
+        .unwrap()
+    }
+
+    fn contract_with_minor(price: i64) -> BaseContract {
+        let clock: Arc<dyn Clock> = Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let shop: Arc<dyn Entity> = Arc::new(NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+        let pupil: Arc<dyn Entity> = Arc::new(NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(2016, 3, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+        let offer = IntentDeclaration::with_clock(
+            DeclarationType::Offer,
+            shop.clone(),
+            Some(pupil.clone()),
+            purchase(price),
+            None,
+            clock.clone(),
+        )
+        .unwrap();
+        let acceptance = IntentDeclaration::with_clock(
+            DeclarationType::Acceptance,
+            pupil.clone(),
+            Some(shop.clone()),
+            purchase(price),
+            None,
+            clock.clone(),
+        )
+        .unwrap();
+        BaseContract::with_clock(
+            vec![shop, pupil],
+            vec![offer, acceptance],
+            vec![],
+            None,
+            clock,
+        )
+    }
+
+    #[test]
+    fn test_minor_contract_validity() {
+        // 九岁学生购买文具，与其年龄相适应
+        assert!(contract_with_minor(20).validate().is_ok());
+
+        // 超出相适应的范围，效力待定
+        let err = contract_with_minor(3000).validate().unwrap_err();
+        assert!(matches!(
+            err,
+            FanError::ValidationError {
+                error_type: ValidationErrorType::ContractStatusIllegal,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_contract_applies_injected_thresholds() {
+        let thresholds = |limit: i64| {
+            CapacityClassifier::new(AppropriatenessThresholds::new(
+                vec![(8, Decimal::from(limit))],
+                Decimal::from(1000),
+                Currency::CNY,
+                vec![SubjectMatterType::GenericGoods, SubjectMatterType::Service],
+            ))
+        };
+
+        // 按默认标准，九岁学生可以独立购买四十元的文具
+        assert!(contract_with_minor(40).validate().is_ok());
+        // 采用更严格的标准的，同一合同效力待定
+        let strict = contract_with_minor(40).with_classifier(thresholds(30));
+        assert_eq!(strict.unratified_parties().unwrap().len(), 1);
+        assert!(strict.validate().is_err());
+
+        // 采用更宽松的标准的，超出默认标准的合同亦有效
+        assert!(contract_with_minor(3000).validate().is_err());
+        assert!(contract_with_minor(3000)
+            .with_classifier(thresholds(5000))
+            .validate()
+            .is_ok());
+    }
+
+    /// 九岁学生与商店订立超出其年龄的买卖合同，父亲为其监护人
+    fn pending_contract(
+        clock: &Arc<ManualClock>,
+    ) -> (
+        BaseContract,
+        Arc<dyn Entity>,
+        Arc<dyn Entity>,
+        NaturalPerson,
+    ) {
+        let clock_dyn: Arc<dyn Clock> = clock.clone();
+        let shop: Arc<dyn Entity> = Arc::new(NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock_dyn.clone(),
+        ));
+        let mut pupil = NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(2016, 3, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock_dyn.clone(),
+        );
+        let mut father = NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(1985, 1, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock_dyn.clone(),
+        );
+        pupil.add_relative(father.id(), Relationship::Parent);
+        let basis = pupil
+            .select_guardians(vec![pupil.guardian_candidate(&father)])
+            .unwrap()
+            .basis_for(father.id())
+            .unwrap();
+        pupil
+            .set_guardian(
+                &mut father,
+                basis,
+                GuardianshipScope::new([GuardianshipAction::PropertyManagement]),
+            )
+            .unwrap();
+        let pupil: Arc<dyn Entity> = Arc::new(pupil);
+
+        let declare = |kind, from: &Arc<dyn Entity>, to: &Arc<dyn Entity>| {
+            IntentDeclaration::with_clock(
+                kind,
+                from.clone(),
+                Some(to.clone()),
+                purchase(3000),
+                None,
+                clock_dyn.clone(),
+            )
+            .unwrap()
+        };
+        let declarations = vec![
+            declare(DeclarationType::Offer, &shop, &pupil),
+            declare(DeclarationType::Acceptance, &pupil, &shop),
+        ];
+        let contract = BaseContract::with_clock(
+            vec![shop.clone(), pupil.clone()],
+            declarations,
+            vec![],
+            None,
+            clock_dyn,
+        );
+        (contract, shop, pupil, father)
+    }
+
+    #[test]
+    fn test_ratification_by_guardian() {
+        let concluded = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let clock = Arc::new(ManualClock::new(concluded));
+        let (mut contract, shop, pupil, father) = pending_contract(&clock);
+
+        contract.make_effective().unwrap();
+        assert_eq!(contract.status(), ContractStatus::PendingRatification);
+        assert_eq!(contract.effective_at(), None);
+        assert!(contract.validate().is_err());
+
+        // 商店本人不能追认，效力待定的一方也不能催告
+        assert!(contract.ratify(pupil.id(), shop.as_ref()).is_err());
+        assert!(contract
+            .notify_ratification(pupil.id(), pupil.id())
+            .is_err());
+
+        contract.notify_ratification(pupil.id(), shop.id()).unwrap();
+        clock.advance(Duration::days(10));
+        contract.ratify(pupil.id(), &father).unwrap();
+
+        // 追认后合同自成立时生效
+        assert_eq!(contract.status(), ContractStatus::Effective);
+        assert_eq!(contract.effective_at(), Some(concluded));
+        assert!(contract.validate().is_ok());
+        assert!(contract.revoke_by_counterparty(shop.id()).is_err());
+    }
+
+    #[test]
+    fn test_ratification_refused_or_revoked() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+
+        // 催告后三十日内未作表示，视为拒绝追认
+        let (mut contract, shop, pupil, father) = pending_contract(&clock);
+        contract.make_effective().unwrap();
+        contract.notify_ratification(pupil.id(), shop.id()).unwrap();
+        clock.advance(Duration::days(30));
+        contract.check_ratification_deadlines();
+        assert_eq!(contract.status(), ContractStatus::Invalid);
+        assert!(matches!(
+            contract.ratifications()[0].outcome(),
+            Some(RatificationOutcome::DeemedRefused { .. })
+        ));
+        assert!(contract.ratify(pupil.id(), &father).is_err());
+
+        // 明确拒绝追认
+        let (mut contract, _, pupil, father) = pending_contract(&clock);
+        contract.make_effective().unwrap();
+        contract.refuse_ratification(pupil.id(), &father).unwrap();
+        assert_eq!(contract.status(), ContractStatus::Invalid);
+
+        // 订立合同时已被告知效力待定的相对人不得撤销，知情须在合同成立前记录
+        let (mut contract, shop, _, _) = pending_contract(&clock);
+        contract.record_counterparty_knowledge(shop.id()).unwrap();
+        contract.make_effective().unwrap();
+        assert!(contract.revoke_by_counterparty(shop.id()).is_err());
+
+        // 善意相对人在追认前撤销
+        let (mut contract, shop, _, _) = pending_contract(&clock);
+        contract.make_effective().unwrap();
+        assert!(contract.record_counterparty_knowledge(shop.id()).is_err());
+        contract.revoke_by_counterparty(shop.id()).unwrap();
+        assert_eq!(contract.status(), ContractStatus::Revoked);
+    }
+
+    /// 买卖双方为两家已登记的有限责任公司，买方以承诺订立合同
+    fn company_sale(clock: &Arc<dyn Clock>) -> (BaseContract, LegalPerson, LegalPerson) {
+        let director = director(clock);
+        let company = || {
+            let mut company = LegalPerson::with_clock(
+                LegalPersonType::Company(CompanyType::Limited),
+                Money::cny(Decimal::from(1_000_000)),
+                director.id(),
+                "上海市浦东新区xxx路".to_string(),
+                Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+                clock.clone(),
+            );
+            company
+                .register(
+                    "上海市浦东新区市场监督管理局".to_string(),
+                    "91310115MA0000000X".to_string(),
+                    Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+                )
+                .unwrap();
+            company
+        };
+        let (buyer, seller) = (company(), company());
+        let (buyer_arc, seller_arc): (Arc<dyn Entity>, Arc<dyn Entity>) =
+            (Arc::new(buyer.clone()), Arc::new(seller.clone()));
+        let offer = signed(
+            DeclarationType::Offer,
+            &seller_arc,
+            &director,
+            &buyer_arc,
+            purchase(5000),
+            clock,
+        );
+        let acceptance = signed(
+            DeclarationType::Acceptance,
+            &buyer_arc,
+            &director,
+            &seller_arc,
+            purchase(5000),
+            clock,
+        );
+        let contract = BaseContract::with_clock(
+            vec![buyer_arc, seller_arc],
+            vec![offer, acceptance],
+            vec![],
+            None,
+            clock.clone(),
+        );
+        (contract, buyer, seller)
+    }
+
+    #[test]
+    fn test_absorbing_company_succeeds_to_party() {
+        let clock: Arc<dyn Clock> = Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let (mut contract, buyer, mut seller) = company_sale(&clock);
+        contract.make_effective().unwrap();
+        let (_, mut acquirer, heir) = company_sale(&clock);
+
+        let mut registry = SuccessionRegistry::new();
+        seller.divide(&[&heir], true, &mut registry).unwrap();
+        acquirer.absorb(&mut seller, &mut registry).unwrap();
+
+        // 尚未代替当事人的，亦依登记簿解析承受合同地位和承担债务的主体
+        assert_eq!(
+            contract.party_successors(seller.id(), &registry).unwrap(),
+            vec![acquirer.id()]
+        );
+        assert_eq!(
+            contract.liable_entities(seller.id(), &registry).unwrap(),
+            vec![heir.id(), acquirer.id()]
+        );
+
+        let entities: Vec<Arc<dyn Entity>> =
+            vec![Arc::new(acquirer.clone()), Arc::new(heir.clone())];
+        registry.rebind(&mut [&mut contract], &entities).unwrap();
+
+        // 卖方被吸收后由吸收方承受其合同地位，合同仍然有效；分出的法人对其债务承担连带责任
+        let parties: Vec<Uuid> = contract.parties().iter().map(|p| p.id()).collect();
+        assert_eq!(parties, vec![buyer.id(), acquirer.id()]);
+        assert_eq!(
+            contract.party_successors(seller.id(), &registry).unwrap(),
+            vec![acquirer.id()]
+        );
+        assert_eq!(contract.successions()[0].predecessor().id(), seller.id());
+        assert_eq!(
+            contract.liable_entities(seller.id(), &registry).unwrap(),
+            vec![heir.id(), acquirer.id()]
+        );
+        assert_eq!(
+            contract.liable_entities(buyer.id(), &registry).unwrap(),
+            vec![buyer.id()]
+        );
+        assert!(contract.validate().is_ok());
+        assert!(contract.party_successors(heir.id(), &registry).is_err());
+        assert!(contract.liable_entities(heir.id(), &registry).is_err());
+
+        // 再次代替不发生变化
+        registry.rebind(&mut [&mut contract], &entities).unwrap();
+        assert_eq!(contract.successions().len(), 1);
+    }
+
+    #[test]
+    fn test_division_rebinds_party_to_all_new_entities() {
+        let clock: Arc<dyn Clock> = Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let (mut contract, buyer, mut seller) = company_sale(&clock);
+        contract.make_effective().unwrap();
+        let (_, east, west) = company_sale(&clock);
+
+        let (mut other, other_buyer, other_seller) = company_sale(&clock);
+        other.make_effective().unwrap();
+
+        let mut registry = SuccessionRegistry::new();
+        seller
+            .divide(&[&east, &west], false, &mut registry)
+            .unwrap();
+        let entities: Vec<Arc<dyn Entity>> = vec![Arc::new(east.clone()), Arc::new(west.clone())];
+        registry
+            .rebind(&mut [&mut contract, &mut other], &entities)
+            .unwrap();
+
+        // 新设分立的卖方已注销，合同由分立后的两家法人承受，不再以卖方为当事人验证
+        let parties: Vec<Uuid> = contract.parties().iter().map(|p| p.id()).collect();
+        assert_eq!(parties, vec![buyer.id(), east.id(), west.id()]);
+        assert_eq!(
+            contract.party_successors(seller.id(), &registry).unwrap(),
+            vec![east.id(), west.id()]
+        );
+        assert_eq!(
+            contract.party_successors(buyer.id(), &registry).unwrap(),
+            vec![buyer.id()]
+        );
+        assert_eq!(
+            contract.liable_entities(seller.id(), &registry).unwrap(),
+            vec![east.id(), west.id()]
+        );
+        assert!(contract.validate().is_ok());
+
+        // 与分立无关的合同不受影响
+        let parties: Vec<Uuid> = other.parties().iter().map(|p| p.id()).collect();
+        assert_eq!(parties, vec![other_buyer.id(), other_seller.id()]);
+        assert!(other.successions().is_empty());
+    }
+
+    #[test]
+    fn test_succession_must_match_registry() {
+        let clock: Arc<dyn Clock> = Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let (mut contract, buyer, mut seller) = company_sale(&clock);
+        let (_, mut acquirer, stranger) = company_sale(&clock);
+        let mut registry = SuccessionRegistry::new();
+
+        // 卖方尚未终止的，不发生合同地位的承受
+        let acquirers: Vec<Arc<dyn Entity>> = vec![Arc::new(acquirer.clone())];
+        registry.rebind(&mut [&mut contract], &acquirers).unwrap();
+        assert!(contract.successions().is_empty());
+
+        // 未提供登记簿记载的继受主体的，不代替当事人
+        acquirer.absorb(&mut seller, &mut registry).unwrap();
+        assert!(registry
+            .rebind(&mut [&mut contract], &[Arc::new(stranger)])
+            .is_err());
+        assert_eq!(contract.parties().len(), 2);
+        assert!(contract.successions().is_empty());
+
+        registry.rebind(&mut [&mut contract], &acquirers).unwrap();
+        assert!(contract.party_successors(buyer.id(), &registry).is_ok());
+    }
+
+    #[test]
+    fn test_guarantor_must_be_qualified() {
+        let clock: Arc<dyn Clock> = Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let established = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let director = director(&clock);
+        let registered = |legal_person_type: LegalPersonType, address: &str| {
+            let mut legal_person = LegalPerson::with_clock(
+                legal_person_type,
+                Money::cny(Decimal::from(1_000_000)),
+                director.id(),
+                address.to_string(),
+                established,
+                clock.clone(),
+            );
+            if legal_person.legal_person_type().requires_registration() {
+                legal_person
+                    .register(
+                        "北京市市场监督管理局".to_string(),
+                        "91110000MA0000000X".to_string(),
+                        established,
+                    )
+                    .unwrap();
+            }
+            legal_person
+        };
+        let bank: Arc<dyn Entity> = Arc::new(registered(
+            LegalPersonType::Company(CompanyType::JointStock),
+            "北京市西城区xxx街",
+        ));
+        let guarantee_by = |legal_person_type| {
+            let guarantor: Arc<dyn Entity> =
+                Arc::new(registered(legal_person_type, "北京市海淀区xxx路"));
+            let content = IntentContent {
+                subject_matter: SubjectMatter::new(
+                    Uuid::new_v4(),
+                    SubjectMatterType::Guarantee {
+                        guarantor: guarantor.id(),
+                    },
+                    "借款保证".to_string(),
+                    None,
+                ),
+                price: Some(Price::new(
+                    Money::cny(Decimal::from(500_000)),
+                    "转账".to_string(),
+                )),
+                ..Default::default()
+            };
+            let offer = signed(
+                DeclarationType::Offer,
+                &guarantor,
+                &director,
+                &bank,
+                content.clone(),
+                &clock,
+            );
+            let acceptance = signed(
+                DeclarationType::Acceptance,
+                &bank,
+                &director,
+                &guarantor,
+                content,
+                &clock,
+            );
+            BaseContract::with_clock(
+                vec![guarantor, bank.clone()],
+                vec![offer, acceptance],
+                vec![],
+                None,
+                clock.clone(),
+            )
+        };
+
+        assert!(guarantee_by(LegalPersonType::Company(CompanyType::Limited))
+            .validate()
+            .is_ok());
+        assert!(guarantee_by(LegalPersonType::StateOrgan)
+            .validate()
+            .is_err());
+        let mut school = guarantee_by(LegalPersonType::Institution);
+        assert!(school.make_effective().is_err());
+        assert!(guarantee_by(LegalPersonType::SocialOrganization {
+            public_welfare: false
+        })
+        .validate()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_business_scope_and_licences() {
+        let clock: Arc<dyn Clock> = Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+        ));
+        let established = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let director = director(&clock);
+        let company = || {
+            let mut company = LegalPerson::with_clock(
+                LegalPersonType::Company(CompanyType::Limited),
+                Money::cny(Decimal::from(1_000_000)),
+                director.id(),
+                "北京市海淀区xxx路".to_string(),
+                established,
+                clock.clone(),
+            );
+            company
+                .register(
+                    "北京市海淀区市场监督管理局".to_string(),
+                    "91110108MA0000000X".to_string(),
+                    established,
+                )
+                .unwrap();
+            company
+                .add_permitted_activity("软件开发".to_string())
+                .unwrap();
+            company
+        };
+        let contract = |seller: Arc<dyn Entity>, activity: &str| {
+            let buyer: Arc<dyn Entity> = Arc::new(company());
+            let content = IntentContent {
+                business_activity: Some(BusinessActivity::new(seller.id(), activity.to_string())),
+                ..purchase(5_000)
+            };
+            let offer = signed(
+                DeclarationType::Offer,
+                &seller,
+                &director,
+                &buyer,
+                content.clone(),
+                &clock,
+            );
+            let acceptance = signed(
+                DeclarationType::Acceptance,
+                &buyer,
+                &director,
+                &seller,
+                content,
+                &clock,
+            );
+            BaseContract::with_clock(
+                vec![seller, buyer],
+                vec![offer, acceptance],
+                vec![],
+                None,
+                clock.clone(),
+            )
+        };
+
+        // 超越经营范围销售计算机，合同仍然有效
+        let seller: Arc<dyn Entity> = Arc::new(company());
+        let within = contract(seller.clone(), "应用软件开发");
+        assert_eq!(
+            within.scope_assessments().unwrap()[0],
+            (seller.id(), ScopeAssessment::WithinScope)
+        );
+        let beyond = contract(seller.clone(), "计算机销售");
+        assert!(beyond.validate().is_ok());
+        assert_eq!(
+            beyond.scope_assessments().unwrap()[0].1,
+            ScopeAssessment::BeyondScopeButValid
+        );
+
+        // 药品零售须取得药品经营许可证，许可证过期的合同无效
+        assert!(contract(seller.clone(), "药品零售").validate().is_err());
+        let mut pharmacy = company();
+        pharmacy
+            .add_licence(
+                Licence::new(
+                    "药品经营许可证".to_string(),
+                    "北京市药品监督管理局".to_string(),
+                    "京AA0000000".to_string(),
+                    vec![IndustryCode::new("525").unwrap()],
+                    established,
+                    Some(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        let pharmacy: Arc<dyn Entity> = Arc::new(pharmacy);
+        let mut expired = contract(pharmacy, "药品零售");
+        assert!(expired.make_effective().is_err());
+        assert_eq!(expired.status(), ContractStatus::Created);
+    }
+
+    #[test]
+    fn test_licence_judged_at_conclusion() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let clock_dyn: Arc<dyn Clock> = clock.clone();
+        let director = director(&clock_dyn);
+        let company = || {
+            let mut company = LegalPerson::with_clock(
+                LegalPersonType::Company(CompanyType::Limited),
+                Money::cny(Decimal::from(1_000_000)),
+                director.id(),
+                "北京市海淀区xxx路".to_string(),
+                Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+                clock_dyn.clone(),
+            );
+            company
+                .register(
+                    "北京市海淀区市场监督管理局".to_string(),
+                    "91110108MA0000000X".to_string(),
+                    Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+                )
+                .unwrap();
+            company
+        };
+        let mut pharmacy = company();
+        pharmacy
+            .add_licence(
+                Licence::new(
+                    "药品经营许可证".to_string(),
+                    "北京市药品监督管理局".to_string(),
+                    "京AA0000000".to_string(),
+                    vec![IndustryCode::new("525").unwrap()],
+                    Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+                    Some(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        let (pharmacy, hospital): (Arc<dyn Entity>, Arc<dyn Entity>) =
+            (Arc::new(pharmacy), Arc::new(company()));
+        let content = IntentContent {
+            business_activity: Some(BusinessActivity::new(pharmacy.id(), "药品零售".to_string())),
+            ..purchase(5_000)
+        };
+        let declare = |declaration_type, from: &Arc<dyn Entity>, to: &Arc<dyn Entity>| {
+            signed(
+                declaration_type,
+                from,
+                &director,
+                to,
+                content.clone(),
+                &clock_dyn,
+            )
+        };
+        let mut contract = BaseContract::with_clock(
+            vec![pharmacy.clone(), hospital.clone()],
+            vec![
+                declare(DeclarationType::Offer, &pharmacy, &hospital),
+                declare(DeclarationType::Acceptance, &hospital, &pharmacy),
+            ],
+            vec![],
+            None,
+            clock_dyn.clone(),
+        );
+        contract.make_effective().unwrap();
+
+        // 许可证在合同成立后届满，不影响已经成立的合同
+        clock.advance(Duration::days(365));
+        assert!(contract.validate().is_ok());
+        assert_eq!(
+            contract.scope_assessments().unwrap()[0],
+            (pharmacy.id(), ScopeAssessment::BeyondScopeButValid)
+        );
+    }
 }
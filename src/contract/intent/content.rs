@@ -1,6 +1,9 @@
 //! 意思表示的具体内容
 //! 包括合同的标的物、数量、质量、价款等实质性内容
 
+pub use crate::core::matter::SubjectMatterType;
+use crate::core::money::{Currency, Money};
+
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use std::cmp::PartialEq;
@@ -8,53 +11,6 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use uuid::Uuid;
 
-/// 标的物类型
-#[derive(Debug, Clone, PartialEq)]
-pub enum SubjectMatterType {
-    /// 特定物
-    SpecificGoods,
-    /// 种类物
-    GenericGoods,
-    /// 特定服务
-    Service,
-    /// 知识产权
-    IntellectualProperty,
-    /// 其他类型
-    Other(String),
-}
-
-impl SubjectMatterType {
-    /// 创建新的标的物类型
-    ///
-    /// 该构造函数用于初始化一个标的物类型的实例，其中包含了标的物类型的名称。
-    ///
-    /// # 参数 Arguments
-    /// - `name`: 标的物类型名称，表示标的物的类型，如特定物、种类物、特定服务等。
-    ///
-    /// # 返回值 Returns
-    /// 返回一个标的物类型的实例，其中包含了提供的参数值。
-    pub fn new(name: String) -> Self {
-        match name.as_str() {
-            "specific_goods" => Self::SpecificGoods,
-            "generic_goods" => Self::GenericGoods,
-            "service" => Self::Service,
-            "intellectual_property" => Self::IntellectualProperty,
-            _ => Self::Other(name),
-        }
-    }
-
-    /// 获取标的物类型的名称
-    fn to_string(&self) -> String {
-        match self {
-            Self::SpecificGoods => "specific_goods".to_string(),
-            Self::GenericGoods => "generic_goods".to_string(),
-            Self::Service => "service".to_string(),
-            Self::IntellectualProperty => "intellectual_property".to_string(),
-            Self::Other(name) => name.clone(),
-        }
-    }
-}
-
 /// 标的物
 #[derive(Debug, Clone)]
 pub struct SubjectMatter {
@@ -65,6 +21,7 @@ pub struct SubjectMatter {
     /// 标的物名称
     name: String,
     /// 标的物描述
+    #[allow(dead_code)] // 仅供记载，不参与意思表示的校验
     description: Option<String>,
 }
 
@@ -94,23 +51,22 @@ impl SubjectMatter {
             description,
         }
     }
+
+    /// 获取标的物类型
+    pub fn subject_type(&self) -> &SubjectMatterType {
+        &self.subject_type
+    }
 }
 
 impl Display for SubjectMatter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}_{}", self.name, self.subject_type.to_string())
+        write!(f, "{}_{}", self.name, self.subject_type)
     }
 }
 
 impl PartialEq for SubjectMatter {
     fn eq(&self, other: &Self) -> bool {
-        if self.id == other.id
-            || (self.name == other.name && self.subject_type == other.subject_type)
-        {
-            true
-        } else {
-            false
-        }
+        self.id == other.id || (self.name == other.name && self.subject_type == other.subject_type)
     }
 }
 
@@ -147,6 +103,7 @@ pub struct Quantity {
 
 /// 质量要求
 #[derive(Debug, Clone)]
+#[allow(dead_code)] // 尚未参与意思表示的校验
 pub struct Quality {
     /// 质量标准
     standard: String,
@@ -159,29 +116,44 @@ pub struct Quality {
 /// 价款或报酬
 #[derive(Debug, Clone)]
 pub struct Price {
-    /// 金额
-    amount: Decimal,
-    /// 币种
-    currency: String,
+    /// 金额及币种
+    money: Money,
     /// 支付方式
+    #[allow(dead_code)] // 仅供记载，不参与意思表示的校验
     payment_method: String,
     /// 支付期限
+    #[allow(dead_code)] // 尚未参与意思表示的校验
     payment_deadline: Option<DateTime<Utc>>,
 }
 
 impl Price {
-    pub fn new(amount: Decimal, currency: String, payment_method: String) -> Self {
+    pub fn new(money: Money, payment_method: String) -> Self {
         Self {
-            amount,
-            currency,
+            money,
             payment_method,
             payment_deadline: None,
         }
     }
+
+    /// 获取金额及币种
+    pub fn money(&self) -> &Money {
+        &self.money
+    }
+
+    /// 获取金额
+    pub fn amount(&self) -> Decimal {
+        self.money.amount()
+    }
+
+    /// 获取币种
+    pub fn currency(&self) -> Currency {
+        self.money.currency()
+    }
 }
 
 /// 履行地点
 #[derive(Debug, Clone)]
+#[allow(dead_code)] // 尚未参与意思表示的校验
 pub struct Location {
     /// 地址
     address: String,
@@ -191,6 +163,7 @@ pub struct Location {
 
 /// 履行期限
 #[derive(Debug, Clone)]
+#[allow(dead_code)] // 尚未参与意思表示的校验
 pub struct TimeLimit {
     /// 开始时间
     start_time: Option<DateTime<Utc>>,
@@ -202,8 +175,41 @@ pub struct TimeLimit {
     installment_plan: Option<Vec<DateTime<Utc>>>,
 }
 
+/// 合同约定由某一当事人从事的经营活动
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusinessActivity {
+    /// 从事该经营活动的当事人，如出卖人、服务提供者
+    pub performer: Uuid,
+    /// 经营活动的表述或国民经济行业代码
+    pub activity: String,
+}
+
+impl BusinessActivity {
+    pub fn new(performer: Uuid, activity: String) -> Self {
+        Self {
+            performer,
+            activity,
+        }
+    }
+}
+
+/// 标的物或服务的给付方向
+#[derive(Debug, Clone, PartialEq)]
+pub struct Performance {
+    /// 给付标的物或提供服务的当事人，如出卖人、赠与人
+    pub giver: Uuid,
+    /// 受领标的物或服务的当事人，如买受人、受赠人
+    pub receiver: Uuid,
+}
+
+impl Performance {
+    pub fn new(giver: Uuid, receiver: Uuid) -> Self {
+        Self { giver, receiver }
+    }
+}
+
 /// 意思表示的具体内容
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct IntentContent {
     /// 标的物
     pub subject_matter: SubjectMatter,
@@ -221,6 +227,10 @@ pub struct IntentContent {
     pub additional_obligations: Vec<String>,
     /// 其他条款
     pub additional_terms: HashMap<String, String>,
+    /// 履行合同所涉及的经营活动
+    pub business_activity: Option<BusinessActivity>,
+    /// 标的物或服务的给付方向，未约定的不推定表意人受领给付
+    pub performance: Option<Performance>,
 }
 
 impl IntentContent {
@@ -259,6 +269,8 @@ impl IntentContent {
             additional_obligations: Vec::new(),
             // 初始化为空的哈希映射，用于存储额外条款
             additional_terms: HashMap::new(),
+            business_activity: None,
+            performance: None,
         }
     }
 
@@ -283,6 +295,10 @@ impl IntentContent {
                 // 知识产权合同必须有使用范围和报酬
                 self.price.is_some() && !self.additional_terms.is_empty()
             }
+            SubjectMatterType::Guarantee { .. } | SubjectMatterType::ProfitDistribution { .. } => {
+                // 保证合同须有被保证的债权数额，利润分配须有分配数额
+                self.price.is_some()
+            }
             SubjectMatterType::Other(_) => {
                 // 其他类型根据具体情况判断
                 true
@@ -301,7 +317,7 @@ impl IntentContent {
 
         // 如果有价款，添加价款信息
         if let Some(ref price) = self.price {
-            essential.push(format!("{}_{}", price.amount, price.currency));
+            essential.push(format!("{}_{}", price.amount(), price.currency()));
         }
 
         // 如果有数量，添加数量信息
@@ -354,9 +370,9 @@ impl IntentContent {
             return false;
         }
 
-        // 价款必须一致（如果双方都指定了价款）
+        // 价款必须一致（如果双方都指定了价款），币种不同的视为不一致
         if let (Some(self_price), Some(other_price)) = (&self.price, &other.price) {
-            if self_price.amount != other_price.amount {
+            if self_price.money != other_price.money {
                 return false;
             }
         }
@@ -366,21 +382,6 @@ impl IntentContent {
     }
 }
 
-impl Default for IntentContent {
-    fn default() -> Self {
-        Self {
-            subject_matter: SubjectMatter::default(),
-            quantity: None,
-            quality: None,
-            price: None,
-            time_limit: None,
-            location: None,
-            additional_obligations: Vec::new(),
-            additional_terms: HashMap::new(),
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -396,8 +397,7 @@ mod tests {
         };
 
         let price = Price {
-            amount: Decimal::from(9999),
-            currency: "CNY".to_string(),
+            money: Money::cny(Decimal::from(9999)),
             payment_method: "支付宝".to_string(),
             payment_deadline: None,
         };
@@ -1,8 +1,17 @@
 //! 意思表示的核心定义
 //! 包括意思表示的类型、结构和基本行为
 
-use super::content::IntentContent;
-use crate::core::entity::Entity;
+use super::content::{IntentContent, SubjectMatterType};
+use crate::contract::agency::{
+    assess_agent_act, assess_signatory, AgencyAssessment, AgentAct, Signatory,
+};
+use crate::contract::ratification::RatificationGround;
+use crate::core::clock::{default_clock, Clock};
+use crate::core::entity::{
+    CapacityStatus, Entity, EntityType, GuardianshipAction, NaturalCapacity,
+};
+use crate::core::money::Money;
+use crate::validate::capacity::{ActValidity, CapacityClassifier, PartyRole};
 use crate::{FanError, FanResult, ValidationErrorType};
 
 use chrono::{DateTime, Utc};
@@ -45,6 +54,118 @@ pub enum DeclarationStatus {
     Withdrawn, // 意思表示被撤销
 }
 
+/// 监护人代理被监护人作出意思表示的情况
+///
+/// 无民事行为能力人、限制民事行为能力人的监护人是其法定代理人（第二十三条），
+/// 代理时须在监护范围内，并按照最有利于被监护人的原则履行监护职责（第三十五条）。
+/// 所实施行为的类别不由监护人自行申报，而是依意思表示的类型和内容确定。
+#[derive(Debug, Clone)]
+pub struct GuardianAct {
+    /// 实施代理的监护人
+    guardian: Arc<dyn Entity>,
+    /// 处分被监护人财产时，维护被监护人利益的依据
+    ward_interest: Option<WardInterest>,
+}
+
+impl GuardianAct {
+    pub fn new(guardian: Arc<dyn Entity>) -> Self {
+        Self {
+            guardian,
+            ward_interest: None,
+        }
+    }
+
+    /// 提供该行为系为维护被监护人利益而实施的依据
+    pub fn for_ward_interest(mut self, interest: WardInterest) -> Self {
+        self.ward_interest = Some(interest);
+        self
+    }
+
+    /// 获取实施代理的监护人
+    pub fn guardian(&self) -> Arc<dyn Entity> {
+        self.guardian.clone()
+    }
+
+    /// 获取维护被监护人利益的依据
+    pub fn ward_interest(&self) -> Option<&WardInterest> {
+        self.ward_interest.as_ref()
+    }
+}
+
+/// 监护人处分被监护人财产系为维护被监护人利益的依据（第三十五条第一款）
+///
+/// 以财产的评估价值为准：处分须有对价，且对价不低于评估价值；
+/// 无偿处分、为他人提供保证或者分配利润，均不能以此证明系为维护被监护人利益。
+#[derive(Debug, Clone)]
+pub struct WardInterest {
+    /// 被处分财产的评估价值
+    appraised_value: Money,
+    /// 处分所得的用途，如支付被监护人的医疗费用
+    purpose: String,
+}
+
+impl WardInterest {
+    pub fn new(appraised_value: Money, purpose: String) -> Self {
+        Self {
+            appraised_value,
+            purpose,
+        }
+    }
+
+    /// 获取被处分财产的评估价值
+    pub fn appraised_value(&self) -> &Money {
+        &self.appraised_value
+    }
+
+    /// 获取处分所得的用途
+    pub fn purpose(&self) -> &str {
+        &self.purpose
+    }
+
+    /// 处分的内容是否足以维护被监护人利益，不足的返回原因
+    fn shortfall(&self, content: &IntentContent) -> Option<String> {
+        if !matches!(
+            content.subject_matter.subject_type(),
+            SubjectMatterType::SpecificGoods
+                | SubjectMatterType::GenericGoods
+                | SubjectMatterType::IntellectualProperty
+        ) {
+            return Some(format!(
+                "{}类行为不能以对价维护被监护人利益",
+                content.subject_matter.subject_type()
+            ));
+        }
+        let Some(ref price) = content.price else {
+            return Some("无偿处分被监护人的财产".to_string());
+        };
+        match price.money().checked_cmp(&self.appraised_value) {
+            Ok(std::cmp::Ordering::Less) => Some(format!(
+                "处分对价 {} 低于评估价值 {}",
+                price.money(),
+                self.appraised_value
+            )),
+            Ok(_) => None,
+            Err(_) => Some(format!(
+                "处分对价 {} 与评估价值 {} 币种不同",
+                price.money(),
+                self.appraised_value
+            )),
+        }
+    }
+}
+
+/// 意思表示由谁作出
+enum Representation {
+    /// 表意人亲自作出
+    InPerson,
+    /// 监护人代理被监护人作出
+    Guardian(GuardianAct),
+    /// 代理人以被代理人名义作出
+    Agent(Box<AgentAct>),
+    /// 签署人代表法人、非法人组织作出
+    Signatory(Signatory),
+}
+
 /// 意思表示的核心结构
 ///
 /// 本结构体用于定义和描述一个意思表示的基本信息，包括其唯一标识符、类型、表意人、相对人、具体内容、生成时间、有效期及当前状态。意思表示是民法上的概念，指表意人通过语言、文字或其他方式表达其内心意思的行为，是法律行为的基础。
@@ -57,6 +178,10 @@ pub enum DeclarationStatus {
 /// - created_at：生成时间，使用DateTime<Utc>来记录生成时间，采用UTC时间标准。
 /// - valid_until：有效期，使用Option<DateTime<Utc>>来记录有效期，可能为空表示长期有效。
 /// - status：当前状态，使用DeclarationStatus枚举来定义。
+/// - guardian_act：监护人代理被监护人作出意思表示时的代理情况。
+/// - agent_act：代理人以被代理人名义作出意思表示时的代理情况。
+/// - signatory：代表法人、非法人组织作出意思表示的签署人。
+/// - pending_ratification：超出监护范围等原因导致效力待定时的说明。
 #[derive(Debug)]
 pub struct IntentDeclaration {
     /// 唯一标识符，使用UUID来唯一标识一个意思表示，确保在全局范围内的唯一性。
@@ -80,6 +205,16 @@ pub struct IntentDeclaration {
     delivered_at: Option<DateTime<Utc>>,
     /// 意思表示的当前状态，通过DeclarationStatus枚举来定义意思表示的当前状态，如生效、失效等。
     status: DeclarationStatus,
+    /// 判断生成时间、到达时间与是否过期所依据的时钟
+    clock: Arc<dyn Clock>,
+    /// 监护人代理被监护人作出意思表示时的代理情况
+    guardian_act: Option<GuardianAct>,
+    /// 代理人以被代理人名义作出意思表示时的代理情况
+    agent_act: Option<AgentAct>,
+    /// 代表法人、非法人组织作出意思表示的签署人
+    signatory: Option<Signatory>,
+    /// 效力待定、须经追认的原因
+    pending_ratification: Option<String>,
 }
 
 impl IntentDeclaration {
@@ -101,19 +236,212 @@ impl IntentDeclaration {
         content: IntentContent,
         valid_until: Option<DateTime<Utc>>,
     ) -> FanResult<Self> {
-        // 先验证表意人的行为能力
-        if !declarant.has_capacity() {
-            return Err(FanError::validation(
-                "表意人无行为能力",
-                ValidationErrorType::EntityCapacityLacking,
-                "new_intent_declaration",
-                "IntentDeclaration",
-            ));
-        }
+        Self::with_clock(
+            declaration_type,
+            declarant,
+            recipient,
+            content,
+            valid_until,
+            default_clock(),
+        )
+    }
+
+    /// # 使用指定时钟创建新的意思表示
+    ///
+    /// 生成时间、到达时间以及有效期的判断均以该时钟为准。
+    pub fn with_clock(
+        declaration_type: DeclarationType,
+        declarant: Arc<dyn Entity>,
+        recipient: Option<Arc<dyn Entity>>,
+        content: IntentContent,
+        valid_until: Option<DateTime<Utc>>,
+        clock: Arc<dyn Clock>,
+    ) -> FanResult<Self> {
+        Self::build(
+            declaration_type,
+            declarant,
+            Representation::InPerson,
+            recipient,
+            content,
+            valid_until,
+            clock,
+        )
+    }
+
+    /// # 由监护人代理被监护人作出意思表示
+    ///
+    /// 表意人为被监护人，`act` 说明由哪位监护人实施何种行为。
+    /// 监护人须与表意人之间存在存续的监护关系；处分被监护人财产须为维护被监护人利益，否则不予认可；
+    /// 超出监护范围的，意思表示效力待定，须经追认。
+    pub fn through_guardian(
+        declaration_type: DeclarationType,
+        ward: Arc<dyn Entity>,
+        act: GuardianAct,
+        recipient: Option<Arc<dyn Entity>>,
+        content: IntentContent,
+        valid_until: Option<DateTime<Utc>>,
+    ) -> FanResult<Self> {
+        Self::through_guardian_with_clock(
+            declaration_type,
+            ward,
+            act,
+            recipient,
+            content,
+            valid_until,
+            default_clock(),
+        )
+    }
+
+    /// # 使用指定时钟，由监护人代理被监护人作出意思表示
+    pub fn through_guardian_with_clock(
+        declaration_type: DeclarationType,
+        ward: Arc<dyn Entity>,
+        act: GuardianAct,
+        recipient: Option<Arc<dyn Entity>>,
+        content: IntentContent,
+        valid_until: Option<DateTime<Utc>>,
+        clock: Arc<dyn Clock>,
+    ) -> FanResult<Self> {
+        Self::build(
+            declaration_type,
+            ward,
+            Representation::Guardian(act),
+            recipient,
+            content,
+            valid_until,
+            clock,
+        )
+    }
+
+    /// # 由代理人以被代理人名义作出意思表示
+    ///
+    /// 表意人为被代理人，`act` 说明由谁依据何种代理权实施。代理人须具有完全民事行为能力；
+    /// 无权代理、超越代理权、未经同意的自己代理或者转委托的，意思表示效力待定，须经被代理人追认；
+    /// 相对人有理由相信行为人有代理权的，构成表见代理，意思表示有效（第一百六十二条、第一百七十一条、第一百七十二条）。
+    pub fn through_agent(
+        declaration_type: DeclarationType,
+        principal: Arc<dyn Entity>,
+        act: AgentAct,
+        recipient: Option<Arc<dyn Entity>>,
+        content: IntentContent,
+        valid_until: Option<DateTime<Utc>>,
+    ) -> FanResult<Self> {
+        Self::through_agent_with_clock(
+            declaration_type,
+            principal,
+            act,
+            recipient,
+            content,
+            valid_until,
+            default_clock(),
+        )
+    }
+
+    /// # 使用指定时钟，由代理人以被代理人名义作出意思表示
+    pub fn through_agent_with_clock(
+        declaration_type: DeclarationType,
+        principal: Arc<dyn Entity>,
+        act: AgentAct,
+        recipient: Option<Arc<dyn Entity>>,
+        content: IntentContent,
+        valid_until: Option<DateTime<Utc>>,
+        clock: Arc<dyn Clock>,
+    ) -> FanResult<Self> {
+        Self::build(
+            declaration_type,
+            principal,
+            Representation::Agent(Box::new(act)),
+            recipient,
+            content,
+            valid_until,
+            clock,
+        )
+    }
+
+    /// # 由签署人代表法人、非法人组织作出意思表示
+    ///
+    /// 表意人为组织，`signatory` 说明由谁以何种身份签署。签署人不是法定代表人、负责人或者超出职权范围的，
+    /// 意思表示效力待定，须经组织追认；章程或者内部对其权限的限制，不得对抗善意相对人（第六十一条、第一百七十条）。
+    pub fn signed_by(
+        declaration_type: DeclarationType,
+        organisation: Arc<dyn Entity>,
+        signatory: Signatory,
+        recipient: Option<Arc<dyn Entity>>,
+        content: IntentContent,
+        valid_until: Option<DateTime<Utc>>,
+    ) -> FanResult<Self> {
+        Self::signed_by_with_clock(
+            declaration_type,
+            organisation,
+            signatory,
+            recipient,
+            content,
+            valid_until,
+            default_clock(),
+        )
+    }
 
-        // 验证相对人的行为能力
+    /// # 使用指定时钟，由签署人代表法人、非法人组织作出意思表示
+    pub fn signed_by_with_clock(
+        declaration_type: DeclarationType,
+        organisation: Arc<dyn Entity>,
+        signatory: Signatory,
+        recipient: Option<Arc<dyn Entity>>,
+        content: IntentContent,
+        valid_until: Option<DateTime<Utc>>,
+        clock: Arc<dyn Clock>,
+    ) -> FanResult<Self> {
+        Self::build(
+            declaration_type,
+            organisation,
+            Representation::Signatory(signatory),
+            recipient,
+            content,
+            valid_until,
+            clock,
+        )
+    }
+
+    fn build(
+        declaration_type: DeclarationType,
+        declarant: Arc<dyn Entity>,
+        representation: Representation,
+        recipient: Option<Arc<dyn Entity>>,
+        content: IntentContent,
+        valid_until: Option<DateTime<Utc>>,
+        clock: Arc<dyn Clock>,
+    ) -> FanResult<Self> {
+        // 行为能力以意思表示作出之时为准
+        let created_at = clock.now();
+
+        // 先验证表意人的行为能力，由监护人或代理人代理的则验证代理是否合法
+        let classifier = CapacityClassifier::default();
+        let pending_ratification = match &representation {
+            Representation::Guardian(act) => {
+                check_guardian_act(declarant.as_ref(), act, &content, created_at, &classifier)?
+            }
+            Representation::Agent(act) => check_agent_act(
+                declarant.as_ref(),
+                act,
+                recipient.as_ref().map(|r| r.id()),
+                &content,
+                created_at,
+            )?,
+            Representation::Signatory(signatory) => check_signatory(
+                declarant.as_ref(),
+                signatory,
+                recipient.as_ref().map(|r| r.id()),
+                &content,
+                created_at,
+            )?,
+            Representation::InPerson => {
+                check_own_capacity(declarant.as_ref(), &content, created_at, &classifier)?
+            }
+        };
+
+        // 验证相对人的行为能力，限制民事行为能力人可以受领意思表示
         if let Some(ref r) = recipient {
-            if !r.has_capacity() {
+            if !can_receive(r.as_ref(), created_at) {
                 return Err(FanError::validation(
                     "相对人无行为能力",
                     ValidationErrorType::EntityCapacityLacking,
@@ -123,6 +451,13 @@ impl IntentDeclaration {
             }
         }
 
+        let (mut guardian_act, mut agent_act, mut signatory) = (None, None, None);
+        match representation {
+            Representation::InPerson => {}
+            Representation::Guardian(act) => guardian_act = Some(act),
+            Representation::Agent(act) => agent_act = Some(*act),
+            Representation::Signatory(s) => signatory = Some(s),
+        }
         let mut instance = Self {
             id: Uuid::new_v4(),
             match_code: String::new(), // 临时空值
@@ -130,10 +465,15 @@ impl IntentDeclaration {
             declarant,
             recipient,
             content,
-            created_at: Utc::now(),
+            created_at,
             valid_until,
             delivered_at: None,
             status: DeclarationStatus::Created,
+            clock,
+            guardian_act,
+            agent_act,
+            signatory,
+            pending_ratification,
         };
 
         // 计算并设置哈希值
@@ -142,10 +482,10 @@ impl IntentDeclaration {
         Ok(instance)
     }
 
-    /// # 计算内容哈希值
-    ///
-    /// 本函数旨在为当前声明计算一个唯一的哈希值，该哈希值基于当事人ID、内容的必要哈希值和声明类型。
-    /// 这用于确保声明的完整性和唯一性。
+    // /// # 计算内容哈希值
+    // ///
+    // /// 本函数旨在为当前声明计算一个唯一的哈希值，该哈希值基于当事人ID、内容的必要哈希值和声明类型。
+    // /// 这用于确保声明的完整性和唯一性。
     // fn calculate_match_code(&self) -> String {
     //     // 获取当事人ID并排序
     //     // 如果存在接收方，则也获取其ID
@@ -239,9 +579,18 @@ impl IntentDeclaration {
             return false;
         }
 
+        // 要约作出后要约人被宣告死亡的，要约失效
+        let now = self.clock.now();
+        if self.declaration_type == DeclarationType::Offer
+            && !self.declarant.is_presumed_dead_at(self.created_at)
+            && self.declarant.is_presumed_dead_at(now)
+        {
+            return false;
+        }
+
         // 检查是否在有效期内
         if let Some(valid_until) = self.valid_until {
-            if Utc::now() > valid_until {
+            if now > valid_until {
                 println!("Expired");
                 return false;
             }
@@ -251,20 +600,56 @@ impl IntentDeclaration {
     }
 
     /// 验证表意人的行为能力
+    ///
+    /// 以意思表示作出时（`created_at`）的行为能力为准，而非验证时。
+    /// 由监护人代理作出的，验证代理在当时是否合法。
     pub fn validate_capacity(&self) -> FanResult<()> {
+        self.assess_capacity(&CapacityClassifier::default())
+            .map(|_| ())
+    }
+
+    /// 按给定的标准判断意思表示的效力
+    ///
+    /// 以意思表示作出时（`created_at`）的行为能力为准；无效的返回错误，效力待定的返回其原因。
+    /// 限制民事行为能力人的行为是否与其年龄、智力相适应，以及监护人代理的行为类别，依 `classifier` 判断。
+    pub fn assess_capacity(&self, classifier: &CapacityClassifier) -> FanResult<Option<String>> {
         // 检查表意人的行为能力
-        if !self.declarant.has_capacity() {
-            return Err(FanError::validation(
-                "表意人无行为能力",
-                ValidationErrorType::EntityCapacityLacking,
-                "validate_capacity",
-                "IntentDeclaration",
-            ));
-        }
+        let pending = if let Some(ref act) = self.guardian_act {
+            check_guardian_act(
+                self.declarant.as_ref(),
+                act,
+                &self.content,
+                self.created_at,
+                classifier,
+            )?
+        } else if let Some(ref act) = self.agent_act {
+            check_agent_act(
+                self.declarant.as_ref(),
+                act,
+                self.recipient.as_ref().map(|r| r.id()),
+                &self.content,
+                self.created_at,
+            )?
+        } else if let Some(ref signatory) = self.signatory {
+            check_signatory(
+                self.declarant.as_ref(),
+                signatory,
+                self.recipient.as_ref().map(|r| r.id()),
+                &self.content,
+                self.created_at,
+            )?
+        } else {
+            check_own_capacity(
+                self.declarant.as_ref(),
+                &self.content,
+                self.created_at,
+                classifier,
+            )?
+        };
 
         // 如果有相对人，也需要检查相对人的行为能力
         if let Some(ref recipient) = self.recipient {
-            if !recipient.has_capacity() {
+            if !can_receive(recipient.as_ref(), self.created_at) {
                 return Err(FanError::validation(
                     "相对人无行为能力",
                     ValidationErrorType::EntityCapacityLacking,
@@ -274,7 +659,7 @@ impl IntentDeclaration {
             }
         }
 
-        Ok(())
+        Ok(pending)
     }
 
     /// 撤回意思表示（在到达相对人之前）
@@ -321,7 +706,7 @@ impl IntentDeclaration {
 
     /// 标记意思表示已到达相对人
     pub fn mark_as_delivered(&mut self) -> FanResult<()> {
-        self.delivered_at = Some(Utc::now());
+        self.delivered_at = Some(self.clock.now());
         self.status = DeclarationStatus::Effective;
         Ok(())
     }
@@ -367,17 +752,294 @@ impl IntentDeclaration {
     pub fn id(&self) -> Uuid {
         self.id
     }
+
+    /// 获取意思表示到达相对人的时间
+    pub fn delivered_at(&self) -> Option<DateTime<Utc>> {
+        self.delivered_at
+    }
+
+    /// 获取监护人代理的情况
+    pub fn guardian_act(&self) -> Option<&GuardianAct> {
+        self.guardian_act.as_ref()
+    }
+
+    /// 获取代理人代理的情况
+    pub fn agent_act(&self) -> Option<&AgentAct> {
+        self.agent_act.as_ref()
+    }
+
+    /// 获取代表组织签署的情况
+    pub fn signatory(&self) -> Option<&Signatory> {
+        self.signatory.as_ref()
+    }
+
+    /// 实际作出意思表示的人：监护人、代理人、签署人，或者作为自然人的表意人本人
+    pub fn signer(&self) -> Arc<dyn Entity> {
+        if let Some(ref act) = self.guardian_act {
+            act.guardian()
+        } else if let Some(ref act) = self.agent_act {
+            act.agent()
+        } else if let Some(ref signatory) = self.signatory {
+            signatory.person()
+        } else {
+            self.declarant.clone()
+        }
+    }
+
+    /// 效力待定时的追认事由
+    ///
+    /// 委托代理的代理人或者组织的签署人无权代理的，由被代理人追认；其他情形由法定代理人或者取得完全民事行为能力的表意人追认。
+    pub fn ratification_ground(&self) -> Option<RatificationGround> {
+        self.pending_ratification.as_ref()?;
+        Some(self.ground_if_pending())
+    }
+
+    /// 意思表示效力待定时应当援引的追认事由，不论其当前是否效力待定
+    pub(crate) fn ground_if_pending(&self) -> RatificationGround {
+        if self.signatory.is_some() || self.agent_act.is_some() {
+            RatificationGround::UnauthorizedAgency {
+                principal: self.declarant.id(),
+            }
+        } else {
+            RatificationGround::Capacity
+        }
+    }
+
+    /// 获取效力待定的原因
+    pub fn pending_ratification(&self) -> Option<&str> {
+        self.pending_ratification.as_deref()
+    }
+
+    /// 意思表示是否效力待定，须经追认
+    pub fn is_pending_ratification(&self) -> bool {
+        self.pending_ratification.is_some()
+    }
+}
+
+/// 相对人能否受领意思表示
+///
+/// 限制民事行为能力人可以受领意思表示，由其作出的承诺的效力另行判断；
+/// 无民事行为能力人和被宣告死亡的人不能受领。
+fn can_receive(recipient: &dyn Entity, at: DateTime<Utc>) -> bool {
+    !recipient.is_presumed_dead_at(at)
+        && (recipient.has_capacity_at(at)
+            || recipient.capacity_status_at(at)
+                == CapacityStatus::NaturalPerson(NaturalCapacity::Limited))
+}
+
+/// 验证表意人能否独立作出该意思表示
+///
+/// 限制民事行为能力人纯获利益或与其年龄、智力相适应的意思表示有效，其他的效力待定，返回其原因；
+/// 无民事行为能力人独立作出的意思表示无效（第一百四十四条）。
+/// 表意人是否受领给付依意思表示内容约定的给付方向判断，未约定的视为给付财产。
+/// 被宣告死亡的人实际生存的，其实施的民事法律行为依其行为能力判断，不受死亡宣告影响（第四十九条）。
+/// 法人、非法人组织不能亲自作出意思表示，须由签署人代表或者委托代理人（第六十一条、第一百七十条）。
+fn check_own_capacity(
+    declarant: &dyn Entity,
+    content: &IntentContent,
+    at: DateTime<Utc>,
+    classifier: &CapacityClassifier,
+) -> FanResult<Option<String>> {
+    if !matches!(
+        declarant.entity_type(),
+        EntityType::NaturalPerson | EntityType::Household
+    ) {
+        return Err(FanError::validation_with_ref(
+            "法人、非法人组织须由法定代表人、负责人或者执行工作任务的人员签署，或者由代理人代为作出意思表示",
+            ValidationErrorType::EntityRelationMalformed,
+            "《民法典》第六十一条、第一百七十条",
+            "check_own_capacity",
+            "IntentDeclaration",
+        ));
+    }
+    if declarant.has_capacity_at(at) {
+        return Ok(None);
+    }
+    let lacking = || {
+        FanError::validation(
+            "表意人无行为能力",
+            ValidationErrorType::EntityCapacityLacking,
+            "check_own_capacity",
+            "IntentDeclaration",
+        )
+    };
+    let CapacityStatus::NaturalPerson(capacity) = declarant.capacity_status_at(at) else {
+        return Err(lacking());
+    };
+
+    let assessment = classifier.classify(
+        &capacity,
+        declarant.age_at(at),
+        content,
+        &party_role(declarant.id(), content),
+    );
+    match assessment.validity() {
+        ActValidity::Valid => Ok(None),
+        ActValidity::PendingRatification => Ok(Some(assessment.reason().to_string())),
+        ActValidity::Void => Err(FanError::validation_with_ref(
+            "表意人无行为能力",
+            ValidationErrorType::EntityCapacityLacking,
+            "《民法典》第一百四十四条",
+            "check_own_capacity",
+            "IntentDeclaration",
+        )),
+    }
+}
+
+/// 当事人在交易中的地位：约定由其受领给付的为取得一方，其他的或者未约定给付方向的视为给付一方
+fn party_role(party: Uuid, content: &IntentContent) -> PartyRole {
+    match content.performance {
+        Some(ref performance) if performance.receiver == party => PartyRole::Acquirer,
+        _ => PartyRole::Transferor,
+    }
+}
+
+/// 依意思表示的内容确定监护人代理被监护人实施的行为类别
+///
+/// 给付特定物、种类物或知识产权，以及由被监护人提供保证、分配利润的，属于处分被监护人的财产；
+/// 其他交易中，标的属于 `classifier` 认定的日常消费品、日常服务的为日常生活所需的行为，其余为财产管理。
+fn guardianship_action(
+    ward: Uuid,
+    content: &IntentContent,
+    classifier: &CapacityClassifier,
+) -> GuardianshipAction {
+    let subject_type = content.subject_matter.subject_type();
+    let disposes = match subject_type {
+        SubjectMatterType::Guarantee { guarantor } => *guarantor == ward,
+        SubjectMatterType::ProfitDistribution { distributor } => *distributor == ward,
+        SubjectMatterType::SpecificGoods
+        | SubjectMatterType::GenericGoods
+        | SubjectMatterType::IntellectualProperty => {
+            party_role(ward, content) == PartyRole::Transferor
+        }
+        _ => false,
+    };
+    if disposes {
+        GuardianshipAction::PropertyDisposal
+    } else if classifier
+        .thresholds()
+        .everyday_subjects()
+        .contains(subject_type)
+    {
+        GuardianshipAction::DailyTransaction
+    } else {
+        GuardianshipAction::PropertyManagement
+    }
+}
+
+/// 验证监护人代理被监护人作出意思表示是否合法
+///
+/// 不合法的返回错误；超出监护范围的返回效力待定的原因。
+fn check_guardian_act(
+    ward: &dyn Entity,
+    act: &GuardianAct,
+    content: &IntentContent,
+    at: DateTime<Utc>,
+    classifier: &CapacityClassifier,
+) -> FanResult<Option<String>> {
+    if ward.has_capacity_at(at) {
+        return Err(FanError::validation_with_ref(
+            "完全民事行为能力人无须由监护人代理",
+            ValidationErrorType::OperationUnauthorized,
+            "《民法典》第二十三条",
+            "check_guardian_act",
+            "IntentDeclaration",
+        ));
+    }
+    if !act.guardian.has_capacity_at(at) {
+        return Err(FanError::validation(
+            "监护人无行为能力",
+            ValidationErrorType::EntityCapacityLacking,
+            "check_guardian_act",
+            "IntentDeclaration",
+        ));
+    }
+
+    let guardian_id = act.guardian.id();
+    let guardianship = ward
+        .guardianships_at(at)
+        .into_iter()
+        .find(|g| g.guardian() == guardian_id)
+        .ok_or_else(|| {
+            FanError::validation_with_ref(
+                "代理人不是表意人的监护人",
+                ValidationErrorType::OperationUnauthorized,
+                "《民法典》第二十三条",
+                "check_guardian_act",
+                "IntentDeclaration",
+            )
+        })?;
+
+    let action = guardianship_action(ward.id(), content, classifier);
+    if action.disposes_property() {
+        let shortfall = match act.ward_interest {
+            Some(ref interest) => interest.shortfall(content),
+            None => Some("未提供维护被监护人利益的依据".to_string()),
+        };
+        if let Some(shortfall) = shortfall {
+            return Err(FanError::validation_with_ref(
+                format!("除为维护被监护人利益外，监护人不得处分被监护人的财产：{shortfall}"),
+                ValidationErrorType::OperationUnauthorized,
+                "《民法典》第三十五条第一款",
+                "check_guardian_act",
+                "IntentDeclaration",
+            ));
+        }
+    }
+
+    if !guardianship.scope().permits(&action) {
+        return Ok(Some(format!("{:?} 超出监护范围，须经追认", action)));
+    }
+
+    Ok(None)
+}
+
+/// 验证代理人以被代理人名义作出意思表示是否对被代理人发生效力
+///
+/// 代理人无行为能力的返回错误；须经被代理人追认的返回效力待定的原因，表见代理视为有权代理。
+fn check_agent_act(
+    principal: &dyn Entity,
+    act: &AgentAct,
+    recipient: Option<Uuid>,
+    content: &IntentContent,
+    at: DateTime<Utc>,
+) -> FanResult<Option<String>> {
+    match assess_agent_act(principal, act, recipient, content, at)? {
+        AgencyAssessment::Authorized | AgencyAssessment::Apparent(_) => Ok(None),
+        AgencyAssessment::Unauthorized(reason) => Ok(Some(reason)),
+    }
+}
+
+/// 验证签署人代表法人、非法人组织作出意思表示是否对组织发生效力
+///
+/// 签署人无行为能力或者表意人并非组织的返回错误；须经组织追认的返回效力待定的原因。
+fn check_signatory(
+    organisation: &dyn Entity,
+    signatory: &Signatory,
+    recipient: Option<Uuid>,
+    content: &IntentContent,
+    at: DateTime<Utc>,
+) -> FanResult<Option<String>> {
+    match assess_signatory(organisation, signatory, recipient, content, at)? {
+        AgencyAssessment::Authorized | AgencyAssessment::Apparent(_) => Ok(None),
+        AgencyAssessment::Unauthorized(reason) => Ok(Some(reason)),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::contract::intent::content::{
-        Quantity, QuantityUnit, SubjectMatter, SubjectMatterType,
+        Performance, Quantity, QuantityUnit, SubjectMatter, SubjectMatterType,
     };
+    use crate::core::clock::{FixedClock, ManualClock};
     use crate::core::entity::{
-        CompanyType, Entity, LegalPerson, LegalPersonType, MentalStatus, NaturalPerson,
+        AbsenceDeclaration, AdjudicationKind, Applicant, CapacityAdjudication, CompanyType,
+        DisappearanceCause, Entity, GuardianshipAction, GuardianshipScope, LegalPerson,
+        LegalPersonType, MentalStatus, NaturalCapacity, NaturalPerson, Relationship,
+        SyncNaturalPerson,
     };
+    use crate::core::money::Money;
     use chrono::TimeZone;
     use chrono::{Duration, Utc};
     use rust_decimal::Decimal;
@@ -397,8 +1059,7 @@ mod tests {
             }),
             None,
             Some(crate::contract::intent::content::Price::new(
-                Decimal::try_from(100.0).unwrap(),
-                "CNY".to_string(),
+                Money::cny(Decimal::from(100)),
                 "现金".to_string(),
             )),
             None,
@@ -464,13 +1125,251 @@ mod tests {
         assert!(declaration_a.can_form_contract_with(&declaration_b));
     }
 
+    #[test]
+    fn test_declaration_expires_with_clock() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap(),
+        ));
+        let birthday = Utc.with_ymd_and_hms(1990, 1, 1, 0, 0, 0).unwrap();
+        let person_a = Arc::new(NaturalPerson::with_clock(
+            birthday,
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+        let person_b = Arc::new(NaturalPerson::with_clock(
+            birthday,
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+
+        let mut offer = IntentDeclaration::with_clock(
+            DeclarationType::Offer,
+            person_a,
+            Some(person_b),
+            test_content(),
+            Some(clock.now() + Duration::days(3)),
+            clock.clone(),
+        )
+        .unwrap();
+        offer.mark_as_delivered().unwrap();
+
+        assert_eq!(offer.created_at(), clock.now());
+        assert_eq!(offer.delivered_at(), Some(clock.now()));
+        assert!(offer.is_valid());
+
+        clock.advance(Duration::days(4));
+        assert!(!offer.is_valid());
+    }
+
+    #[test]
+    fn test_offer_lapses_when_offeror_presumed_dead() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap(),
+        ));
+        let birthday = Utc.with_ymd_and_hms(1990, 1, 1, 0, 0, 0).unwrap();
+        let offeror = Arc::new(SyncNaturalPerson::with_clock(
+            birthday,
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+        let offeree = Arc::new(NaturalPerson::with_clock(
+            birthday,
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+
+        let mut offer = IntentDeclaration::with_clock(
+            DeclarationType::Offer,
+            offeror.clone(),
+            Some(offeree),
+            test_content(),
+            None,
+            clock.clone(),
+        )
+        .unwrap();
+        offer.mark_as_delivered().unwrap();
+        assert!(offer.is_valid());
+
+        // 要约人乘坐的船只失事，经有关机关证明不可能生存，被宣告死亡
+        clock.advance(Duration::days(30));
+        offeror
+            .record_absence(
+                AbsenceDeclaration::death(
+                    Uuid::new_v4(),
+                    "上海海事法院".to_string(),
+                    clock.now() - Duration::days(20),
+                    clock.now(),
+                    DisappearanceCause::AccidentCertifiedFatal {
+                        occurred_at: clock.now() - Duration::days(20),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert!(!offer.is_valid());
+        // 死亡宣告不影响其行为能力
+        assert!(offeror.has_capacity());
+    }
+
+    #[test]
+    fn test_declared_dead_person_still_acts_validly() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2024, 5, 1, 9, 0, 0).unwrap(),
+        ));
+        let birthday = Utc.with_ymd_and_hms(1990, 1, 1, 0, 0, 0).unwrap();
+        let person = Arc::new(SyncNaturalPerson::with_clock(
+            birthday,
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+        let counterparty = Arc::new(NaturalPerson::with_clock(
+            birthday,
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+        let death = AbsenceDeclaration::death(
+            Uuid::new_v4(),
+            "上海海事法院".to_string(),
+            clock.now() - Duration::days(20),
+            clock.now(),
+            DisappearanceCause::AccidentCertifiedFatal {
+                occurred_at: clock.now() - Duration::days(20),
+            },
+        )
+        .unwrap();
+        let death_id = death.id();
+        person.record_absence(death).unwrap();
+
+        // 被宣告死亡的人实际生存，在宣告期间作出的意思表示有效（第四十九条）
+        clock.advance(Duration::days(10));
+        let mut offer = IntentDeclaration::with_clock(
+            DeclarationType::Offer,
+            person.clone(),
+            Some(counterparty),
+            test_content(),
+            None,
+            clock.clone(),
+        )
+        .unwrap();
+        assert!(person.is_presumed_dead_at(offer.created_at));
+        assert!(!offer.is_pending_ratification());
+        assert!(offer.validate_capacity().is_ok());
+        offer.mark_as_delivered().unwrap();
+        assert!(offer.is_valid());
+
+        // 撤销死亡宣告后仍然有效
+        clock.advance(Duration::days(10));
+        person.revoke_absence(death_id, clock.now()).unwrap();
+        assert!(offer.validate_capacity().is_ok());
+        assert!(offer.is_valid());
+    }
+
+    #[test]
+    fn test_minor_acts_alone() {
+        let clock: Arc<dyn Clock> = Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let minor = Arc::new(NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(2016, 3, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+        let adult = Arc::new(NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(1995, 3, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+        // `receives` 为真的由未成年人受领给付，否则由其给付
+        let declare = |declaration_type, price: Option<i64>, receives: bool| {
+            let mut content = test_content();
+            content.performance = Some(if receives {
+                Performance::new(adult.id(), minor.id())
+            } else {
+                Performance::new(minor.id(), adult.id())
+            });
+            content.price = price.map(|amount| {
+                crate::contract::intent::content::Price::new(
+                    Money::cny(Decimal::from(amount)),
+                    "现金".to_string(),
+                )
+            });
+            IntentDeclaration::with_clock(
+                declaration_type,
+                minor.clone(),
+                Some(adult.clone()),
+                content,
+                None,
+                clock.clone(),
+            )
+        };
+
+        // 接受赠与或者请求他人赠与，纯获利益
+        let gift = declare(DeclarationType::Acceptance, None, true).unwrap();
+        assert!(!gift.is_pending_ratification());
+        assert!(!declare(DeclarationType::Offer, None, true)
+            .unwrap()
+            .is_pending_ratification());
+        // 购买零食，与年龄相适应
+        let snack = declare(DeclarationType::Offer, Some(10), true).unwrap();
+        assert!(!snack.is_pending_ratification());
+        // 将自己的物品赠与他人，无论以要约还是承诺作出，效力待定
+        assert!(declare(DeclarationType::Offer, None, false)
+            .unwrap()
+            .is_pending_ratification());
+        assert!(declare(DeclarationType::Acceptance, None, false)
+            .unwrap()
+            .is_pending_ratification());
+        // 购买贵重物品，效力待定
+        let console = declare(DeclarationType::Offer, Some(3000), true).unwrap();
+        assert!(console.is_pending_ratification());
+        assert!(console.validate_capacity().is_ok());
+
+        // 未约定给付方向的，不推定未成年人纯获利益
+        let mut unspecified = test_content();
+        unspecified.price = None;
+        assert!(IntentDeclaration::with_clock(
+            DeclarationType::Acceptance,
+            minor.clone(),
+            Some(adult.clone()),
+            unspecified,
+            None,
+            clock.clone(),
+        )
+        .unwrap()
+        .is_pending_ratification());
+
+        // 成年人可以向未成年人发出要约
+        assert!(IntentDeclaration::with_clock(
+            DeclarationType::Offer,
+            adult.clone(),
+            Some(minor.clone()),
+            test_content(),
+            None,
+            clock.clone(),
+        )
+        .is_ok());
+    }
+
     #[test]
     fn test_intent_declaration_with_no_capacity() {
-        // 创建一个无民事行为能力的自然人（成年但精神状态受损）
-        let incapacitated_person = Arc::new(NaturalPerson::new(
+        // 创建一个无民事行为能力的自然人（成年但精神状态受损，经法院认定）
+        let mut incapacitated_person = NaturalPerson::new(
             Utc::now() - Duration::days(365 * 30),
             MentalStatus::SeverelyImpaired,
-        ));
+        );
+        incapacitated_person
+            .adjudicate_capacity(
+                CapacityAdjudication::new(
+                    AdjudicationKind::Incapacity,
+                    Applicant::InterestedParty(Uuid::new_v4()),
+                    "北京市海淀区人民法院".to_string(),
+                    Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap(),
+                    NaturalCapacity::None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        let incapacitated_person = Arc::new(incapacitated_person);
 
         let normal_person = Arc::new(NaturalPerson::new(
             Utc::now() - Duration::days(365 * 30),
@@ -501,13 +1400,21 @@ mod tests {
         let legal_representative = NaturalPerson::new(birthday, MentalStatus::Normal);
 
         // 创建一个法人作为相对人
-        let recipient = Arc::new(LegalPerson::new(
+        let mut recipient = LegalPerson::new(
             LegalPersonType::Company(CompanyType::Limited),
-            1000000.0,
+            Money::cny(Decimal::from(1_000_000)),
             legal_representative.id(),
             "北京".to_string(),
             Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
-        ));
+        );
+        recipient
+            .register(
+                "北京市市场监督管理局".to_string(),
+                "91110000MA0000000X".to_string(),
+                Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+            )
+            .unwrap();
+        let recipient = Arc::new(recipient);
 
         // 创建意思表示
         let declaration = IntentDeclaration::new(
@@ -521,4 +1428,162 @@ mod tests {
         // 验证行为能力
         assert!(declaration.unwrap().validate_capacity().is_ok());
     }
+
+    #[test]
+    fn test_intent_declaration_through_guardian() {
+        let clock: Arc<dyn Clock> = Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let mut ward = NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(2015, 3, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock.clone(),
+        );
+        let mut guardian = NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(1985, 3, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock.clone(),
+        );
+        ward.add_relative(guardian.id(), Relationship::Parent);
+        let basis = ward
+            .select_guardians(vec![ward.guardian_candidate(&guardian)])
+            .unwrap()
+            .basis_for(guardian.id())
+            .unwrap();
+        ward.set_guardian(
+            &mut guardian,
+            basis,
+            GuardianshipScope::new([
+                GuardianshipAction::DailyTransaction,
+                GuardianshipAction::PropertyDisposal,
+            ]),
+        )
+        .unwrap();
+        let ward = Arc::new(ward);
+        let guardian = Arc::new(guardian);
+        let stranger = Arc::new(NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(1995, 3, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock.clone(),
+        ));
+        let declare = |declaration_type: DeclarationType, act: GuardianAct, content| {
+            IntentDeclaration::through_guardian_with_clock(
+                declaration_type,
+                ward.clone(),
+                act,
+                Some(stranger.clone()),
+                content,
+                None,
+                clock.clone(),
+            )
+        };
+        let purchase = |mut content: IntentContent| {
+            content.performance = Some(Performance::new(stranger.id(), ward.id()));
+            content
+        };
+        let with_subject = |subject_type: SubjectMatterType, price: i64| {
+            let mut content = test_content();
+            content.performance = Some(Performance::new(ward.id(), stranger.id()));
+            content.subject_matter =
+                SubjectMatter::new(Uuid::new_v4(), subject_type, "标的".to_string(), None);
+            content.price = Some(crate::contract::intent::content::Price::new(
+                Money::cny(Decimal::from(price)),
+                "现金".to_string(),
+            ));
+            content
+        };
+
+        // 购买日常消费品属于日常生活所需，在监护范围内
+        let declaration = declare(
+            DeclarationType::Acceptance,
+            GuardianAct::new(guardian.clone()),
+            purchase(test_content()),
+        )
+        .unwrap();
+        assert!(!declaration.is_pending_ratification());
+        assert!(declaration.validate_capacity().is_ok());
+        assert!(!declare(
+            DeclarationType::Offer,
+            GuardianAct::new(guardian.clone()),
+            purchase(test_content()),
+        )
+        .unwrap()
+        .is_pending_ratification());
+
+        // 购入特定物属于财产管理，超出监护范围，效力待定
+        let declaration = declare(
+            DeclarationType::Acceptance,
+            GuardianAct::new(guardian.clone()),
+            purchase(with_subject(SubjectMatterType::SpecificGoods, 5000)),
+        )
+        .unwrap();
+        assert!(declaration.is_pending_ratification());
+        // 同一标的由被监护人给付的属于处分，承诺出售亦须有维护其利益的依据
+        assert!(declare(
+            DeclarationType::Acceptance,
+            GuardianAct::new(guardian.clone()),
+            with_subject(SubjectMatterType::SpecificGoods, 5000),
+        )
+        .is_err());
+
+        // 出售被监护人的财产属于处分，须有维护其利益的依据
+        let sale = || with_subject(SubjectMatterType::SpecificGoods, 5000);
+        assert!(declare(
+            DeclarationType::Offer,
+            GuardianAct::new(guardian.clone()),
+            sale()
+        )
+        .is_err());
+        let interest = |value: i64| {
+            WardInterest::new(
+                Money::cny(Decimal::from(value)),
+                "支付被监护人的医疗费用".to_string(),
+            )
+        };
+        assert!(declare(
+            DeclarationType::Offer,
+            GuardianAct::new(guardian.clone()).for_ward_interest(interest(5000)),
+            sale()
+        )
+        .is_ok());
+        // 对价低于评估价值的，不能证明系为维护被监护人利益
+        assert!(declare(
+            DeclarationType::Offer,
+            GuardianAct::new(guardian.clone()).for_ward_interest(interest(8000)),
+            sale()
+        )
+        .is_err());
+        // 以被监护人为保证人的，无论如何不属于维护被监护人利益
+        assert!(declare(
+            DeclarationType::Offer,
+            GuardianAct::new(guardian.clone()).for_ward_interest(interest(0)),
+            with_subject(
+                SubjectMatterType::Guarantee {
+                    guarantor: ward.id()
+                },
+                5000
+            )
+        )
+        .is_err());
+
+        // 非监护人不能代理
+        assert!(declare(
+            DeclarationType::Acceptance,
+            GuardianAct::new(stranger.clone()),
+            purchase(test_content())
+        )
+        .is_err());
+
+        // 完全民事行为能力人无须由监护人代理
+        assert!(IntentDeclaration::through_guardian_with_clock(
+            DeclarationType::Offer,
+            stranger.clone(),
+            GuardianAct::new(guardian.clone()),
+            None,
+            test_content(),
+            None,
+            clock.clone(),
+        )
+        .is_err());
+    }
 }
@@ -5,5 +5,5 @@
 pub mod content;
 pub mod declaration;
 
-pub use content::IntentContent;
-pub use declaration::{DeclarationType, IntentDeclaration};
+pub use content::{BusinessActivity, IntentContent, Performance};
+pub use declaration::{DeclarationType, GuardianAct, IntentDeclaration, WardInterest};
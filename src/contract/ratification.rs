@@ -0,0 +1,236 @@
+//! 效力待定合同的追认
+//! 对应《民法典》第一百四十五条、第一百七十一条：限制民事行为能力人超出其能力订立的合同、
+//! 无权代理人订立的合同，经法定代理人或被代理人追认后有效。相对人可以催告其自收到通知之日起三十日内予以追认，
+//! 未作表示的视为拒绝追认；合同被追认之前，善意相对人有撤销的权利。
+
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// 催告后予以追认的期限（日）
+pub const RATIFICATION_PERIOD_DAYS: i64 = 30;
+
+/// 合同效力待定的事由
+#[derive(Debug, Clone, PartialEq)]
+pub enum RatificationGround {
+    /// 限制民事行为能力人实施与其年龄、智力不相适应的行为，或监护人超出监护范围（第一百四十五条）
+    Capacity,
+    /// 行为人没有代理权、超越代理权或者代理权终止后以被代理人名义订立合同（第一百七十一条）
+    UnauthorizedAgency { principal: Uuid },
+}
+
+/// 追认程序的结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum RatificationOutcome {
+    /// 法定代理人或被代理人予以追认
+    Ratified { by: Uuid, at: DateTime<Utc> },
+    /// 明确拒绝追认
+    Refused { by: Uuid, at: DateTime<Utc> },
+    /// 催告期满未作表示，视为拒绝追认
+    DeemedRefused { at: DateTime<Utc> },
+    /// 善意相对人在追认前撤销
+    RevokedByCounterparty { by: Uuid, at: DateTime<Utc> },
+}
+
+/// 相对人的催告
+#[derive(Debug, Clone)]
+pub struct RatificationNotice {
+    counterparty: Uuid,         // 发出催告的相对人
+    notified_at: DateTime<Utc>, // 催告到达之日
+    deadline: DateTime<Utc>,    // 追认期限届满之时
+}
+
+impl RatificationNotice {
+    /// 获取发出催告的相对人
+    pub fn counterparty(&self) -> Uuid {
+        self.counterparty
+    }
+
+    /// 获取催告到达之日
+    pub fn notified_at(&self) -> DateTime<Utc> {
+        self.notified_at
+    }
+
+    /// 获取追认期限
+    pub fn deadline(&self) -> DateTime<Utc> {
+        self.deadline
+    }
+}
+
+/// 某一当事人的意思表示效力待定时的追认程序
+#[derive(Debug, Clone)]
+pub struct Ratification {
+    party: Uuid,                          // 意思表示效力待定的当事人
+    ground: RatificationGround,           // 效力待定的事由
+    reason: String,                       // 具体原因
+    notice: Option<RatificationNotice>,   // 相对人的催告
+    outcome: Option<RatificationOutcome>, // 追认程序的结果
+}
+
+impl Ratification {
+    pub(crate) fn new(party: Uuid, ground: RatificationGround, reason: String) -> Self {
+        Self {
+            party,
+            ground,
+            reason,
+            notice: None,
+            outcome: None,
+        }
+    }
+
+    /// 是否已有结果
+    pub fn is_resolved(&self) -> bool {
+        self.outcome.is_some()
+    }
+
+    /// 是否已被追认
+    pub fn is_ratified(&self) -> bool {
+        matches!(self.outcome, Some(RatificationOutcome::Ratified { .. }))
+    }
+
+    /// 记录相对人的催告，追认期限自催告到达之日起计算
+    pub(crate) fn notify(&mut self, counterparty: Uuid, at: DateTime<Utc>) -> FanResult<()> {
+        self.ensure_unresolved("notify")?;
+        if self.notice.is_some() {
+            return Err(FanError::validation(
+                "相对人已经催告",
+                ValidationErrorType::OperationSequenceWrong,
+                "notify",
+                "Ratification",
+            ));
+        }
+        self.notice = Some(RatificationNotice {
+            counterparty,
+            notified_at: at,
+            deadline: at + Duration::days(RATIFICATION_PERIOD_DAYS),
+        });
+        Ok(())
+    }
+
+    /// 催告期满未作表示的，视为拒绝追认
+    pub(crate) fn lapse(&mut self, at: DateTime<Utc>) {
+        if let (None, Some(notice)) = (&self.outcome, &self.notice) {
+            if notice.deadline <= at {
+                self.outcome = Some(RatificationOutcome::DeemedRefused {
+                    at: notice.deadline,
+                });
+            }
+        }
+    }
+
+    /// 记录追认、拒绝追认或相对人撤销
+    pub(crate) fn resolve(&mut self, outcome: RatificationOutcome) -> FanResult<()> {
+        self.ensure_unresolved("resolve")?;
+        self.outcome = Some(outcome);
+        Ok(())
+    }
+
+    fn ensure_unresolved(&self, operation: &str) -> FanResult<()> {
+        if self.is_resolved() {
+            return Err(FanError::validation_with_ref(
+                "追认程序已经结束",
+                ValidationErrorType::OperationSequenceWrong,
+                "《民法典》第一百四十五条",
+                operation,
+                "Ratification",
+            ));
+        }
+        Ok(())
+    }
+
+    /// 获取意思表示效力待定的当事人
+    pub fn party(&self) -> Uuid {
+        self.party
+    }
+
+    /// 获取效力待定的事由
+    pub fn ground(&self) -> &RatificationGround {
+        &self.ground
+    }
+
+    /// 获取具体原因
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// 获取相对人的催告
+    pub fn notice(&self) -> Option<&RatificationNotice> {
+        self.notice.as_ref()
+    }
+
+    /// 获取追认程序的结果
+    pub fn outcome(&self) -> Option<&RatificationOutcome> {
+        self.outcome.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::now;
+
+    fn pending() -> Ratification {
+        Ratification::new(
+            Uuid::new_v4(),
+            RatificationGround::Capacity,
+            "限制民事行为能力人超出其能力订立合同".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_notice_starts_ratification_period() {
+        let mut ratification = pending();
+        let counterparty = Uuid::new_v4();
+        ratification.notify(counterparty, now()).unwrap();
+        let notice = ratification.notice().unwrap();
+        assert_eq!(notice.counterparty(), counterparty);
+        assert_eq!(
+            notice.deadline(),
+            now() + Duration::days(RATIFICATION_PERIOD_DAYS)
+        );
+        assert!(ratification.notify(counterparty, now()).is_err());
+    }
+
+    #[test]
+    fn test_silence_after_deadline_is_refusal() {
+        let mut ratification = pending();
+        // 未经催告的，不因时间经过而视为拒绝
+        ratification.lapse(now() + Duration::days(365));
+        assert!(!ratification.is_resolved());
+
+        ratification.notify(Uuid::new_v4(), now()).unwrap();
+        let deadline = now() + Duration::days(RATIFICATION_PERIOD_DAYS);
+        ratification.lapse(deadline - Duration::days(1));
+        assert!(!ratification.is_resolved());
+        ratification.lapse(deadline + Duration::days(5));
+        assert_eq!(
+            ratification.outcome(),
+            Some(&RatificationOutcome::DeemedRefused { at: deadline })
+        );
+        assert!(!ratification.is_ratified());
+    }
+
+    #[test]
+    fn test_outcome_is_final() {
+        let mut ratification = pending();
+        let guardian = Uuid::new_v4();
+        ratification
+            .resolve(RatificationOutcome::Ratified {
+                by: guardian,
+                at: now(),
+            })
+            .unwrap();
+        assert!(ratification.is_ratified());
+        assert!(ratification
+            .resolve(RatificationOutcome::Refused {
+                by: guardian,
+                at: now(),
+            })
+            .is_err());
+        assert!(ratification.notify(Uuid::new_v4(), now()).is_err());
+        // 已有结果的，催告期满不改变结果
+        ratification.lapse(now() + Duration::days(365));
+        assert!(ratification.is_ratified());
+    }
+}
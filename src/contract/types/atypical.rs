@@ -73,8 +73,6 @@ impl Contract for AtypicalContract {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[test]
     fn test_atypical_contract() {
         // TODO: 添加测试用例
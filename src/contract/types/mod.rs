@@ -1,2 +1,5 @@
+// 具体合同类型尚未接入合同订立流程，暂不对外公开
+#[allow(dead_code)]
+mod atypical;
+#[allow(dead_code)]
 mod sale;
-mod atypical;
\ No newline at end of file
@@ -3,10 +3,10 @@
 use super::super::base::{BaseContract, Contract, ContractStatus};
 use super::super::typical::TypicalContract;
 use crate::core::entity::Entity;
+use crate::core::money::Money;
 use crate::{FanError, FanResult, ValidationErrorType};
 
 use chrono::{DateTime, Utc};
-use rust_decimal::Decimal;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -28,10 +28,8 @@ pub struct SubjectMatter {
 /// 价款
 #[derive(Debug, Clone)]
 pub struct Price {
-    /// 金额
-    amount: Decimal,
-    /// 币种
-    currency: String,
+    /// 金额及币种
+    money: Money,
     /// 支付方式
     payment_method: String,
     /// 支付期限
@@ -147,7 +145,7 @@ impl TypicalContract for SaleContract {
         }
 
         // 验证价款
-        if self.price.amount.is_sign_negative() {
+        if !self.price.money.is_positive() {
             return Err(FanError::validation(
                 "价款必须大于0",
                 ValidationErrorType::ContractContentIllegal,
@@ -172,8 +170,6 @@ impl TypicalContract for SaleContract {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[test]
     fn test_sale_contract_validation() {
         // TODO: 实现具体的测试用例
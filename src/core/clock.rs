@@ -0,0 +1,120 @@
+//! 时钟抽象
+//! 框架内所有依赖“当前时间”的判断（行为能力、意思表示有效期、合同生效等）
+//! 均通过 `Clock` 获取时间，以便在指定的法律日期重放整个场景。
+
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// 时钟
+pub trait Clock: Debug + Send + Sync {
+    /// 获取当前时间
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// 系统时钟，返回真实的当前时间
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// 固定时钟，始终返回同一时刻
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock {
+    instant: DateTime<Utc>,
+}
+
+impl FixedClock {
+    pub fn new(instant: DateTime<Utc>) -> Self {
+        Self { instant }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.instant
+    }
+}
+
+/// 手动时钟，时间只在显式设置或推进时改变
+#[derive(Debug)]
+pub struct ManualClock {
+    instant: RwLock<DateTime<Utc>>,
+}
+
+impl ManualClock {
+    pub fn new(instant: DateTime<Utc>) -> Self {
+        Self {
+            instant: RwLock::new(instant),
+        }
+    }
+
+    /// 将时间设置为指定时刻
+    pub fn set(&self, instant: DateTime<Utc>) {
+        *self.instant.write() = instant;
+    }
+
+    /// 将时间向前推进
+    pub fn advance(&self, duration: Duration) {
+        let mut instant = self.instant.write();
+        *instant += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.instant.read()
+    }
+}
+
+/// 进程级默认时钟，未显式指定时钟的构造函数均使用它
+static DEFAULT_CLOCK: RwLock<Option<Arc<dyn Clock>>> = parking_lot::const_rwlock(None);
+
+/// 获取默认时钟，未设置时为系统时钟
+pub fn default_clock() -> Arc<dyn Clock> {
+    DEFAULT_CLOCK
+        .read()
+        .clone()
+        .unwrap_or_else(|| Arc::new(SystemClock))
+}
+
+/// 替换默认时钟
+pub fn set_default_clock(clock: Arc<dyn Clock>) {
+    *DEFAULT_CLOCK.write() = Some(clock);
+}
+
+/// 恢复默认时钟为系统时钟
+pub fn reset_default_clock() {
+    *DEFAULT_CLOCK.write() = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_fixed_clock() {
+        let instant = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        let clock = FixedClock::new(instant);
+        assert_eq!(clock.now(), instant);
+        assert_eq!(clock.now(), instant);
+    }
+
+    #[test]
+    fn test_manual_clock() {
+        let instant = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+        let clock = ManualClock::new(instant);
+
+        clock.advance(Duration::days(30));
+        assert_eq!(clock.now(), instant + Duration::days(30));
+
+        clock.set(instant);
+        assert_eq!(clock.now(), instant);
+    }
+}
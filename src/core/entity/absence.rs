@@ -0,0 +1,498 @@
+//! 宣告失踪和宣告死亡
+//! 对应《民法典》第四十条至第五十三条：自然人下落不明满二年的，利害关系人可以申请宣告其为失踪人；
+//! 下落不明满四年，或者因意外事件下落不明满二年的，可以申请宣告其死亡。
+//! 被宣告失踪、死亡的人重新出现的，经本人或者利害关系人申请，人民法院应当撤销宣告。
+
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Months, Utc};
+use uuid::Uuid;
+
+/// 下落不明的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum DisappearanceCause {
+    /// 一般情形，须下落不明满四年（第四十六条第一款第一项）
+    Ordinary,
+    /// 因意外事件下落不明，须满二年（第四十六条第一款第二项）
+    Accident { occurred_at: DateTime<Utc> },
+    /// 因意外事件下落不明，经有关机关证明不可能生存，不受二年时间的限制（第四十六条第二款）
+    AccidentCertifiedFatal { occurred_at: DateTime<Utc> },
+}
+
+/// 宣告的类型
+#[derive(Debug, Clone, PartialEq)]
+pub enum AbsenceKind {
+    /// 宣告失踪，由财产代管人代管其财产（第四十二条）
+    Missing { custodian: Uuid },
+    /// 宣告死亡，`presumed_death` 为推定的死亡日期（第四十八条）
+    Dead { presumed_death: DateTime<Utc> },
+}
+
+/// 自然人在某一时刻的宣告状态
+#[derive(Debug, Clone, PartialEq)]
+pub enum CivilStatus {
+    /// 未被宣告失踪或死亡，或者宣告已被撤销
+    Present,
+    /// 被宣告失踪
+    Missing { custodian: Uuid },
+    /// 被宣告死亡
+    PresumedDead { presumed_death: DateTime<Utc> },
+}
+
+/// 宣告失踪或宣告死亡的记录
+#[derive(Debug, Clone)]
+pub struct AbsenceDeclaration {
+    id: Uuid,                          // 宣告记录ID
+    kind: AbsenceKind,                 // 宣告类型
+    applicant: Uuid,                   // 申请人（利害关系人）
+    court: String,                     // 作出宣告的人民法院
+    last_heard_of: DateTime<Utc>,      // 最后获得该自然人音讯之日
+    declared_at: DateTime<Utc>,        // 判决作出之日
+    revoked_at: Option<DateTime<Utc>>, // 重新出现后宣告被撤销的日期
+}
+
+impl AbsenceDeclaration {
+    /// 宣告失踪，下落不明须满二年（第四十条）
+    pub fn missing(
+        applicant: Uuid,
+        court: String,
+        last_heard_of: DateTime<Utc>,
+        declared_at: DateTime<Utc>,
+        custodian: Uuid,
+    ) -> FanResult<Self> {
+        check_period(
+            last_heard_of,
+            declared_at,
+            24,
+            "自然人下落不明满二年的，才能宣告失踪",
+            "《民法典》第四十条",
+        )?;
+        Ok(Self::new(
+            AbsenceKind::Missing { custodian },
+            applicant,
+            court,
+            last_heard_of,
+            declared_at,
+        ))
+    }
+
+    /// 宣告死亡（第四十六条），并按第四十八条确定推定的死亡日期
+    ///
+    /// 一般情形以判决作出之日为死亡日期；因意外事件下落不明的，以意外事件发生之日为死亡日期。
+    /// 宣告失踪不是宣告死亡的必经程序（第四十七条）。
+    pub fn death(
+        applicant: Uuid,
+        court: String,
+        last_heard_of: DateTime<Utc>,
+        declared_at: DateTime<Utc>,
+        cause: DisappearanceCause,
+    ) -> FanResult<Self> {
+        let presumed_death = match cause {
+            DisappearanceCause::Ordinary => {
+                check_period(
+                    last_heard_of,
+                    declared_at,
+                    48,
+                    "自然人下落不明满四年的，才能宣告死亡",
+                    "《民法典》第四十六条第一款",
+                )?;
+                declared_at
+            }
+            DisappearanceCause::Accident { occurred_at } => {
+                check_period(
+                    occurred_at,
+                    declared_at,
+                    24,
+                    "因意外事件下落不明满二年的，才能宣告死亡",
+                    "《民法典》第四十六条第一款",
+                )?;
+                occurred_at
+            }
+            DisappearanceCause::AccidentCertifiedFatal { occurred_at } => {
+                check_period(
+                    occurred_at,
+                    declared_at,
+                    0,
+                    "宣告死亡不能早于意外事件发生之日",
+                    "《民法典》第四十六条第二款",
+                )?;
+                occurred_at
+            }
+        };
+        Ok(Self::new(
+            AbsenceKind::Dead { presumed_death },
+            applicant,
+            court,
+            last_heard_of,
+            declared_at,
+        ))
+    }
+
+    fn new(
+        kind: AbsenceKind,
+        applicant: Uuid,
+        court: String,
+        last_heard_of: DateTime<Utc>,
+        declared_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            kind,
+            applicant,
+            court,
+            last_heard_of,
+            declared_at,
+            revoked_at: None,
+        }
+    }
+
+    /// 在指定时刻该宣告是否有效
+    pub fn is_in_force_at(&self, at: DateTime<Utc>) -> bool {
+        self.declared_at <= at && self.revoked_at.is_none_or(|revoked_at| at < revoked_at)
+    }
+
+    /// 本人重新出现，撤销该宣告（第四十五条、第五十条）
+    pub(crate) fn revoke(&mut self, revoked_at: DateTime<Utc>) -> FanResult<()> {
+        if self.revoked_at.is_some() || revoked_at < self.declared_at {
+            return Err(FanError::validation(
+                "宣告已被撤销或撤销日期早于宣告日期",
+                ValidationErrorType::OperationSequenceWrong,
+                "revoke",
+                "AbsenceDeclaration",
+            ));
+        }
+        self.revoked_at = Some(revoked_at);
+        Ok(())
+    }
+
+    /// 变更财产代管人（第四十四条）
+    pub(crate) fn change_custodian(&mut self, custodian: Uuid) -> FanResult<()> {
+        match self.kind {
+            AbsenceKind::Missing {
+                custodian: ref mut current,
+            } if self.revoked_at.is_none() => {
+                *current = custodian;
+                Ok(())
+            }
+            _ => Err(FanError::validation_with_ref(
+                "只有有效的失踪宣告才能变更财产代管人",
+                ValidationErrorType::OperationSequenceWrong,
+                "《民法典》第四十四条",
+                "change_custodian",
+                "AbsenceDeclaration",
+            )),
+        }
+    }
+
+    /// 获取宣告记录ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// 获取宣告类型
+    pub fn kind(&self) -> &AbsenceKind {
+        &self.kind
+    }
+
+    /// 获取申请人
+    pub fn applicant(&self) -> Uuid {
+        self.applicant
+    }
+
+    /// 获取作出宣告的法院
+    pub fn court(&self) -> &str {
+        &self.court
+    }
+
+    /// 获取最后获得音讯之日
+    pub fn last_heard_of(&self) -> DateTime<Utc> {
+        self.last_heard_of
+    }
+
+    /// 获取判决作出之日
+    pub fn declared_at(&self) -> DateTime<Utc> {
+        self.declared_at
+    }
+
+    /// 获取撤销日期
+    pub fn revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+}
+
+/// 校验下落不明的期间是否届满
+fn check_period(
+    from: DateTime<Utc>,
+    declared_at: DateTime<Utc>,
+    months: u32,
+    message: &str,
+    legal_reference: &str,
+) -> FanResult<()> {
+    let satisfied = from
+        .checked_add_months(Months::new(months))
+        .is_some_and(|due| due <= declared_at);
+    if !satisfied {
+        return Err(FanError::validation_with_ref(
+            message,
+            ValidationErrorType::OperationTimingWrong,
+            legal_reference,
+            "new_absence_declaration",
+            "AbsenceDeclaration",
+        ));
+    }
+    Ok(())
+}
+
+/// 在指定时刻的宣告状态，宣告死亡优先于宣告失踪
+pub(crate) fn civil_status_at(
+    declarations: &[AbsenceDeclaration],
+    at: DateTime<Utc>,
+) -> CivilStatus {
+    let in_force = || declarations.iter().filter(|d| d.is_in_force_at(at));
+    if let Some(AbsenceKind::Dead { presumed_death }) = in_force()
+        .filter(|d| matches!(d.kind, AbsenceKind::Dead { .. }))
+        .max_by_key(|d| d.declared_at)
+        .map(|d| &d.kind)
+    {
+        return CivilStatus::PresumedDead {
+            presumed_death: *presumed_death,
+        };
+    }
+    match in_force().max_by_key(|d| d.declared_at).map(|d| &d.kind) {
+        Some(AbsenceKind::Missing { custodian }) => CivilStatus::Missing {
+            custodian: *custodian,
+        },
+        _ => CivilStatus::Present,
+    }
+}
+
+/// 校验一项宣告能否记录：已被宣告死亡的不再宣告，已被宣告失踪的不再重复宣告失踪
+pub(crate) fn check_absence(
+    declarations: &[AbsenceDeclaration],
+    declaration: &AbsenceDeclaration,
+) -> FanResult<()> {
+    let current = civil_status_at(declarations, declaration.declared_at);
+    let conflicting = matches!(
+        (&current, &declaration.kind),
+        (CivilStatus::PresumedDead { .. }, _)
+            | (CivilStatus::Missing { .. }, AbsenceKind::Missing { .. })
+    );
+    if conflicting {
+        return Err(FanError::validation(
+            "该自然人已处于宣告死亡或宣告失踪状态",
+            ValidationErrorType::EntityStatusIllegal,
+            "record_absence",
+            "NaturalPerson",
+        ));
+    }
+    Ok(())
+}
+
+/// 在记录中查找并撤销某一宣告
+pub(crate) fn revoke_absence_in(
+    declarations: &mut [AbsenceDeclaration],
+    declaration_id: Uuid,
+    revoked_at: DateTime<Utc>,
+) -> FanResult<()> {
+    find_in(declarations, declaration_id)?.revoke(revoked_at)
+}
+
+/// 在记录中查找某一宣告
+pub(crate) fn find_in(
+    declarations: &mut [AbsenceDeclaration],
+    declaration_id: Uuid,
+) -> FanResult<&mut AbsenceDeclaration> {
+    declarations
+        .iter_mut()
+        .find(|d| d.id == declaration_id)
+        .ok_or_else(|| {
+            FanError::validation(
+                "未找到该宣告记录",
+                ValidationErrorType::EntityError,
+                "find_absence",
+                "NaturalPerson",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    #[test]
+    fn test_declaration_periods() {
+        let last_heard_of = Utc.with_ymd_and_hms(2020, 3, 1, 0, 0, 0).unwrap();
+        let applicant = Uuid::new_v4();
+        let court = "北京市海淀区人民法院".to_string();
+
+        assert!(AbsenceDeclaration::missing(
+            applicant,
+            court.clone(),
+            last_heard_of,
+            Utc.with_ymd_and_hms(2022, 2, 28, 0, 0, 0).unwrap(),
+            Uuid::new_v4(),
+        )
+        .is_err());
+        assert!(AbsenceDeclaration::missing(
+            applicant,
+            court.clone(),
+            last_heard_of,
+            Utc.with_ymd_and_hms(2022, 3, 1, 0, 0, 0).unwrap(),
+            Uuid::new_v4(),
+        )
+        .is_ok());
+
+        let declared_at = Utc.with_ymd_and_hms(2023, 3, 1, 0, 0, 0).unwrap();
+        assert!(AbsenceDeclaration::death(
+            applicant,
+            court.clone(),
+            last_heard_of,
+            declared_at,
+            DisappearanceCause::Ordinary,
+        )
+        .is_err());
+
+        // 意外事件下落不明满二年的，以意外事件发生之日为死亡日期
+        let death = AbsenceDeclaration::death(
+            applicant,
+            court,
+            last_heard_of,
+            declared_at,
+            DisappearanceCause::Accident {
+                occurred_at: last_heard_of,
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            death.kind(),
+            &AbsenceKind::Dead {
+                presumed_death: last_heard_of
+            }
+        );
+    }
+
+    fn heard_of() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2020, 3, 1, 0, 0, 0).unwrap()
+    }
+
+    fn missing(declared_at: DateTime<Utc>) -> AbsenceDeclaration {
+        AbsenceDeclaration::missing(
+            Uuid::new_v4(),
+            "北京市海淀区人民法院".to_string(),
+            heard_of(),
+            declared_at,
+            Uuid::new_v4(),
+        )
+        .unwrap()
+    }
+
+    fn dead(declared_at: DateTime<Utc>) -> AbsenceDeclaration {
+        AbsenceDeclaration::death(
+            Uuid::new_v4(),
+            "北京市海淀区人民法院".to_string(),
+            heard_of(),
+            declared_at,
+            DisappearanceCause::Ordinary,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_certified_fatal_accident_needs_no_period() {
+        let occurred_at = heard_of();
+        let death = AbsenceDeclaration::death(
+            Uuid::new_v4(),
+            "北京市海淀区人民法院".to_string(),
+            occurred_at,
+            occurred_at + Duration::days(30),
+            DisappearanceCause::AccidentCertifiedFatal { occurred_at },
+        )
+        .unwrap();
+        assert_eq!(
+            death.kind(),
+            &AbsenceKind::Dead {
+                presumed_death: occurred_at
+            }
+        );
+        assert!(AbsenceDeclaration::death(
+            Uuid::new_v4(),
+            "北京市海淀区人民法院".to_string(),
+            occurred_at,
+            occurred_at - Duration::days(1),
+            DisappearanceCause::AccidentCertifiedFatal { occurred_at },
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_death_takes_precedence_over_missing() {
+        let missing_at = Utc.with_ymd_and_hms(2022, 6, 1, 0, 0, 0).unwrap();
+        let dead_at = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let declarations = vec![missing(missing_at), dead(dead_at)];
+        assert_eq!(
+            civil_status_at(&declarations, missing_at - Duration::days(1)),
+            CivilStatus::Present
+        );
+        assert!(matches!(
+            civil_status_at(&declarations, missing_at),
+            CivilStatus::Missing { .. }
+        ));
+        assert_eq!(
+            civil_status_at(&declarations, dead_at),
+            CivilStatus::PresumedDead {
+                presumed_death: dead_at
+            }
+        );
+    }
+
+    #[test]
+    fn test_conflicting_declarations_rejected() {
+        let missing_at = Utc.with_ymd_and_hms(2022, 6, 1, 0, 0, 0).unwrap();
+        let dead_at = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut declarations = vec![missing(missing_at)];
+        // 已被宣告失踪的，可以宣告死亡，但不再重复宣告失踪
+        assert!(check_absence(&declarations, &missing(dead_at)).is_err());
+        assert!(check_absence(&declarations, &dead(dead_at)).is_ok());
+        declarations.push(dead(dead_at));
+        assert!(check_absence(&declarations, &dead(dead_at + Duration::days(1))).is_err());
+    }
+
+    #[test]
+    fn test_revocation_restores_presence() {
+        let declared_at = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+        let mut declarations = vec![dead(declared_at)];
+        let id = declarations[0].id();
+        assert!(revoke_absence_in(&mut declarations, id, declared_at - Duration::days(1)).is_err());
+        assert!(revoke_absence_in(&mut declarations, Uuid::new_v4(), declared_at).is_err());
+
+        let returned = declared_at + Duration::days(200);
+        revoke_absence_in(&mut declarations, id, returned).unwrap();
+        assert!(matches!(
+            civil_status_at(&declarations, returned - Duration::days(1)),
+            CivilStatus::PresumedDead { .. }
+        ));
+        assert_eq!(
+            civil_status_at(&declarations, returned),
+            CivilStatus::Present
+        );
+        assert!(revoke_absence_in(&mut declarations, id, returned).is_err());
+        // 撤销后可以重新宣告
+        assert!(check_absence(&declarations, &missing(returned)).is_ok());
+    }
+
+    #[test]
+    fn test_custodian_changes_only_while_missing() {
+        let declared_at = Utc.with_ymd_and_hms(2022, 6, 1, 0, 0, 0).unwrap();
+        let mut declaration = missing(declared_at);
+        let custodian = Uuid::new_v4();
+        declaration.change_custodian(custodian).unwrap();
+        assert_eq!(declaration.kind(), &AbsenceKind::Missing { custodian });
+
+        declaration.revoke(declared_at).unwrap();
+        assert!(declaration.change_custodian(Uuid::new_v4()).is_err());
+        assert!(dead(declared_at + Duration::days(730))
+            .change_custodian(custodian)
+            .is_err());
+    }
+}
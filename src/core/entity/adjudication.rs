@@ -0,0 +1,173 @@
+//! 成年人民事行为能力的司法认定
+//! 对应《民法典》第二十四条：不能辨认或者不能完全辨认自己行为的成年人，
+//! 经利害关系人或者有关组织申请，由人民法院认定为无民事行为能力人或者限制民事行为能力人；
+//! 经本人、利害关系人或者有关组织申请，人民法院可以认定其恢复为限制民事行为能力人或者完全民事行为能力人。
+
+use crate::core::entity::base::NaturalCapacity;
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 认定类型
+#[derive(Debug, Clone, PartialEq)]
+pub enum AdjudicationKind {
+    /// 认定为无民事行为能力人或者限制民事行为能力人（第二十四条第一款）
+    Incapacity,
+    /// 认定恢复为限制民事行为能力人或者完全民事行为能力人（第二十四条第二款）
+    Restoration,
+}
+
+/// 认定申请人
+#[derive(Debug, Clone, PartialEq)]
+pub enum Applicant {
+    /// 被认定人本人（仅可申请恢复）
+    Person,
+    /// 利害关系人，如近亲属、债权人
+    InterestedParty(Uuid),
+    /// 有关组织，如居民委员会、村民委员会、学校、医疗机构、民政部门等（第二十四条第三款）
+    Organization(Uuid),
+}
+
+/// 民事行为能力认定记录
+#[derive(Debug, Clone)]
+pub struct CapacityAdjudication {
+    id: Uuid,                            // 认定记录ID
+    kind: AdjudicationKind,              // 认定类型
+    applicant: Applicant,                // 申请人
+    court: String,                       // 作出认定的人民法院
+    adjudicated_at: DateTime<Utc>,       // 认定生效日期
+    resulting_capacity: NaturalCapacity, // 认定后的行为能力
+    revoked_at: Option<DateTime<Utc>>,   // 认定被撤销的日期
+}
+
+impl CapacityAdjudication {
+    /// 创建一项认定记录，并校验申请人与认定结果是否符合第二十四条
+    ///
+    /// - 认定无能力或限制能力时，申请人不能为本人，结果只能是无或限制民事行为能力
+    /// - 认定恢复时，结果只能是限制或完全民事行为能力
+    pub fn new(
+        kind: AdjudicationKind,
+        applicant: Applicant,
+        court: String,
+        adjudicated_at: DateTime<Utc>,
+        resulting_capacity: NaturalCapacity,
+    ) -> FanResult<Self> {
+        match kind {
+            AdjudicationKind::Incapacity => {
+                if applicant == Applicant::Person {
+                    return Err(FanError::validation_with_ref(
+                        "认定无民事行为能力或限制民事行为能力须由利害关系人或有关组织申请",
+                        ValidationErrorType::OperationUnauthorized,
+                        "《民法典》第二十四条第一款",
+                        "new_capacity_adjudication",
+                        "CapacityAdjudication",
+                    ));
+                }
+                if resulting_capacity == NaturalCapacity::Full {
+                    return Err(FanError::validation_with_ref(
+                        "行为能力认定的结果只能是无民事行为能力或限制民事行为能力",
+                        ValidationErrorType::EntityError,
+                        "《民法典》第二十四条第一款",
+                        "new_capacity_adjudication",
+                        "CapacityAdjudication",
+                    ));
+                }
+            }
+            AdjudicationKind::Restoration => {
+                if resulting_capacity == NaturalCapacity::None {
+                    return Err(FanError::validation_with_ref(
+                        "恢复认定的结果只能是限制民事行为能力或完全民事行为能力",
+                        ValidationErrorType::EntityError,
+                        "《民法典》第二十四条第二款",
+                        "new_capacity_adjudication",
+                        "CapacityAdjudication",
+                    ));
+                }
+            }
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            kind,
+            applicant,
+            court,
+            adjudicated_at,
+            resulting_capacity,
+            revoked_at: None,
+        })
+    }
+
+    /// 在指定时刻该认定是否有效
+    pub fn is_in_force_at(&self, at: DateTime<Utc>) -> bool {
+        self.adjudicated_at <= at && self.revoked_at.is_none_or(|revoked_at| at < revoked_at)
+    }
+
+    /// 撤销该认定，撤销前的历史记录仍然保留
+    pub(crate) fn revoke(&mut self, revoked_at: DateTime<Utc>) -> FanResult<()> {
+        if self.revoked_at.is_some() {
+            return Err(FanError::validation(
+                "该认定已被撤销",
+                ValidationErrorType::OperationSequenceWrong,
+                "revoke",
+                "CapacityAdjudication",
+            ));
+        }
+        if revoked_at < self.adjudicated_at {
+            return Err(FanError::validation(
+                "撤销日期不能早于认定日期",
+                ValidationErrorType::OperationTimingWrong,
+                "revoke",
+                "CapacityAdjudication",
+            ));
+        }
+        self.revoked_at = Some(revoked_at);
+        Ok(())
+    }
+
+    /// 获取认定记录ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// 获取认定类型
+    pub fn kind(&self) -> &AdjudicationKind {
+        &self.kind
+    }
+
+    /// 获取申请人
+    pub fn applicant(&self) -> &Applicant {
+        &self.applicant
+    }
+
+    /// 获取作出认定的法院
+    pub fn court(&self) -> &str {
+        &self.court
+    }
+
+    /// 获取认定生效日期
+    pub fn adjudicated_at(&self) -> DateTime<Utc> {
+        self.adjudicated_at
+    }
+
+    /// 获取认定后的行为能力
+    pub fn resulting_capacity(&self) -> &NaturalCapacity {
+        &self.resulting_capacity
+    }
+
+    /// 获取撤销日期
+    pub fn revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+}
+
+/// 在指定时刻有效的最近一项认定
+pub(crate) fn adjudication_in_force_at(
+    adjudications: &[CapacityAdjudication],
+    at: DateTime<Utc>,
+) -> Option<&CapacityAdjudication> {
+    adjudications
+        .iter()
+        .filter(|a| a.is_in_force_at(at))
+        .max_by_key(|a| a.adjudicated_at)
+}
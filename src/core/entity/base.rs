@@ -1,14 +1,25 @@
+use crate::core::clock::Clock;
+use crate::core::entity::guardianship::Guardianship;
+use crate::core::entity::industry::IndustryCode;
+use crate::core::entity::legal_person::LegalPersonType;
+use crate::core::entity::licence::Licence;
+use crate::core::entity::staff::Personnel;
+use crate::core::entity::unincorporated::UnincorporatedOrgType;
+
 use chrono::{DateTime, Utc};
 use std::collections::HashSet;
 use std::fmt::Debug;
+use std::sync::Arc;
 use uuid::Uuid;
 
 /// 民事主体的类型
 #[derive(Debug, Clone, PartialEq)]
 pub enum EntityType {
-    NaturalPerson,     // 自然人
-    LegalPerson,       // 法人
-    UnincorporatedOrg, // 非法人组织
+    NaturalPerson,          // 自然人
+    LegalPerson,            // 法人
+    UnincorporatedOrg,      // 非法人组织
+    Household,              // 个体工商户、农村承包经营户
+    LegalPersonInFormation, // 设立中的法人
 }
 
 /// 民事主体的基本特征
@@ -19,6 +30,82 @@ pub trait Entity {
     fn created_at(&self) -> DateTime<Utc>;
     fn updated_at(&self) -> DateTime<Utc>;
     fn has_capacity(&self) -> bool;
+
+    /// 在指定时刻的行为能力状态
+    ///
+    /// 默认与当前状态一致，行为能力随时间变化的主体（如自然人）应覆盖此方法。
+    fn capacity_status_at(&self, _at: DateTime<Utc>) -> CapacityStatus {
+        self.capacity_status()
+    }
+
+    /// 在指定时刻是否具有相应的行为能力
+    fn has_capacity_at(&self, _at: DateTime<Utc>) -> bool {
+        self.has_capacity()
+    }
+
+    /// 在指定时刻的周岁年龄
+    ///
+    /// 默认为空，只有自然人具有年龄。
+    fn age_at(&self, _at: DateTime<Utc>) -> Option<u8> {
+        None
+    }
+
+    /// 在指定时刻以该主体为被监护人的存续监护关系
+    ///
+    /// 默认没有监护人，可能处于监护之下的主体（如自然人）应覆盖此方法。
+    fn guardianships_at(&self, _at: DateTime<Utc>) -> Vec<Guardianship> {
+        Vec::new()
+    }
+
+    /// 在指定时刻是否被宣告死亡
+    ///
+    /// 默认否，可被宣告死亡的主体（如自然人）应覆盖此方法。
+    fn is_presumed_dead_at(&self, _at: DateTime<Utc>) -> bool {
+        false
+    }
+
+    /// 法人的类型
+    ///
+    /// 默认为空，只有法人具有法人类型。
+    fn legal_form(&self) -> Option<LegalPersonType> {
+        None
+    }
+
+    /// 非法人组织的类型
+    ///
+    /// 默认为空，只有非法人组织具有非法人组织类型。
+    fn unincorporated_form(&self) -> Option<UnincorporatedOrgType> {
+        None
+    }
+
+    /// 持有的许可证
+    ///
+    /// 默认为空，可以从事许可经营项目的主体（如法人、非法人组织）应覆盖此方法。
+    fn licences(&self) -> Vec<Licence> {
+        Vec::new()
+    }
+
+    /// 以自己的财产对本主体的民事责任承担无限责任的其他主体
+    ///
+    /// 默认为空；个人独资企业为其投资人，分支机构为其所属的法人或者非法人组织，
+    /// 个体工商户和农村承包经营户为以其财产承担债务的经营者或者家庭成员。
+    fn liability_bearers(&self) -> Vec<Uuid> {
+        Vec::new()
+    }
+
+    /// 依照法律或者章程代表本主体从事民事活动的人
+    ///
+    /// 默认为空；法人为其法定代表人，非法人组织为执行事务合伙人、投资人或者分支机构的负责人，设立中的法人为其设立人。
+    fn representatives(&self) -> Vec<Uuid> {
+        Vec::new()
+    }
+
+    /// 人员名册
+    ///
+    /// 默认为空，只有法人、非法人组织记载执行工作任务的人员（第一百七十条）。
+    fn personnel(&self) -> Option<Personnel> {
+        None
+    }
 }
 
 impl Debug for dyn Entity {
@@ -43,6 +130,37 @@ pub struct BaseEntity {
     pub capacity_status: CapacityStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// 主体使用的时钟，用于时间戳和随时间变化的判断
+    pub clock: Arc<dyn Clock>,
+}
+
+impl BaseEntity {
+    /// 以给定时钟的当前时间创建基础主体信息
+    pub fn new(
+        entity_type: EntityType,
+        capacity_status: CapacityStatus,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let now = clock.now();
+        Self {
+            id: Uuid::new_v4(),
+            entity_type,
+            capacity_status,
+            created_at: now,
+            updated_at: now,
+            clock,
+        }
+    }
+
+    /// 获取时钟的当前时间
+    pub fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
+    /// 刷新更新时间
+    pub fn touch(&mut self) {
+        self.updated_at = self.clock.now();
+    }
 }
 
 /// 民事行为能力状态
@@ -69,10 +187,17 @@ pub enum NaturalCapacity {
 pub struct BusinessScope {
     // 是否属于正常经营状态
     pub status: BusinessStatus,
-    // 经营范围列表
-    pub permitted_activities: HashSet<String>,
+    // 经营范围列表，以国民经济行业代码表示
+    pub permitted_activities: HashSet<IndustryCode>,
     // 特别限制（如果有）
-    pub restrictions: Option<Vec<String>>,
+    pub restrictions: Option<Vec<IndustryCode>>,
+}
+
+impl BusinessScope {
+    /// 经营范围是否涵盖某一行业且不受特别限制
+    pub fn permits(&self, activity: &IndustryCode) -> bool {
+        scope_permits(&self.permitted_activities, &self.restrictions, activity)
+    }
 }
 
 /// 法人的经营状态
@@ -88,10 +213,29 @@ pub enum BusinessStatus {
 pub struct AuthorityScope {
     // 职权状态
     pub status: AuthorityStatus,
-    // 允许的职权范围
-    pub permitted_authorities: HashSet<String>,
+    // 允许的职权范围，以国民经济行业代码表示
+    pub permitted_authorities: HashSet<IndustryCode>,
     // 特别限制（如果有）
-    pub restrictions: Option<Vec<String>>,
+    pub restrictions: Option<Vec<IndustryCode>>,
+}
+
+impl AuthorityScope {
+    /// 职权范围是否涵盖某一行业且不受特别限制
+    pub fn permits(&self, activity: &IndustryCode) -> bool {
+        scope_permits(&self.permitted_authorities, &self.restrictions, activity)
+    }
+}
+
+/// 登记的行业涵盖其下属行业；特别限制同样涵盖其下属行业
+fn scope_permits(
+    permitted: &HashSet<IndustryCode>,
+    restrictions: &Option<Vec<IndustryCode>>,
+    activity: &IndustryCode,
+) -> bool {
+    permitted.iter().any(|p| p.covers(activity))
+        && !restrictions
+            .as_ref()
+            .is_some_and(|r| r.iter().any(|c| c.covers(activity)))
 }
 
 /// 非法人组织的职权状态
@@ -0,0 +1,664 @@
+//! 公司的股东名册与出资
+//! 记录股东认缴、实缴的出资及其期限，计算持股比例，识别未按期足额缴纳出资的股东（《公司法》第四十九条），
+//! 并处理有限责任公司股权对外转让时其他股东的优先购买权（《公司法》第八十四条）。
+
+use crate::core::money::{Currency, Money};
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// 其他股东自接到书面通知之日起答复的期限（日），逾期未答复的视为放弃优先购买权
+pub const PREEMPTION_PERIOD_DAYS: i64 = 30;
+
+/// 一期认缴出资
+#[derive(Debug, Clone)]
+pub struct Tranche {
+    amount: Money,      // 认缴出资额
+    due: DateTime<Utc>, // 缴纳期限
+}
+
+impl Tranche {
+    /// 获取认缴出资额
+    pub fn amount(&self) -> Money {
+        self.amount
+    }
+
+    /// 获取缴纳期限
+    pub fn due(&self) -> DateTime<Utc> {
+        self.due
+    }
+}
+
+/// 股东的出资
+#[derive(Debug, Clone)]
+pub struct Shareholding {
+    shareholder: Uuid,      // 股东，可以是任何民事主体
+    tranches: Vec<Tranche>, // 分期认缴的出资
+    paid_in: Money,         // 已实缴的出资额
+}
+
+impl Shareholding {
+    /// 获取股东ID
+    pub fn shareholder(&self) -> Uuid {
+        self.shareholder
+    }
+
+    /// 获取分期认缴的出资
+    pub fn tranches(&self) -> &[Tranche] {
+        &self.tranches
+    }
+
+    /// 认缴出资总额
+    pub fn subscribed(&self) -> Money {
+        Money::sum(
+            self.tranches.iter().map(|t| &t.amount),
+            self.paid_in.currency(),
+        )
+        .expect("股东名册中的金额币种一致")
+    }
+
+    /// 获取已实缴的出资额
+    pub fn paid_in(&self) -> Money {
+        self.paid_in
+    }
+
+    /// 在指定时刻已届缴纳期限而未缴纳的出资额
+    pub fn overdue_at(&self, at: DateTime<Utc>) -> Money {
+        let due = Money::sum(
+            self.tranches
+                .iter()
+                .filter(|t| t.due <= at)
+                .map(|t| &t.amount),
+            self.paid_in.currency(),
+        )
+        .expect("股东名册中的金额币种一致");
+        match due.checked_sub(&self.paid_in) {
+            Ok(overdue) if overdue.is_positive() => overdue,
+            _ => Money::zero(self.paid_in.currency()),
+        }
+    }
+
+    /// 按比例分出一部分出资，用于股权转让
+    fn split_off(&mut self, ratio: Decimal) -> FanResult<Shareholding> {
+        let mut moved = Vec::new();
+        for tranche in &mut self.tranches {
+            let part = tranche.amount.checked_mul(ratio)?;
+            tranche.amount = tranche.amount.checked_sub(&part)?;
+            moved.push(Tranche {
+                amount: part,
+                due: tranche.due,
+            });
+        }
+        let paid = self.paid_in.checked_mul(ratio)?;
+        self.paid_in = self.paid_in.checked_sub(&paid)?;
+        self.tranches.retain(|t| !t.amount.is_zero());
+        Ok(Shareholding {
+            shareholder: self.shareholder,
+            tranches: moved,
+            paid_in: paid,
+        })
+    }
+
+    /// 并入受让的出资
+    fn absorb(&mut self, other: Shareholding) -> FanResult<()> {
+        self.paid_in = self.paid_in.checked_add(&other.paid_in)?;
+        self.tranches.extend(other.tranches);
+        self.tranches.sort_by_key(|t| t.due);
+        Ok(())
+    }
+}
+
+/// 未按期足额缴纳出资的情况
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContributionDefault {
+    pub shareholder: Uuid, // 股东
+    pub overdue: Money,    // 逾期未缴的出资额
+}
+
+/// 股权对外转让的状态
+#[derive(Debug, Clone, PartialEq)]
+pub enum TransferStatus {
+    /// 已通知其他股东，等待其行使或放弃优先购买权
+    Noticed,
+    /// 已完成，`transferees` 为实际受让人
+    Completed { transferees: Vec<Uuid> },
+}
+
+/// 股权转让
+#[derive(Debug, Clone)]
+pub struct ShareTransfer {
+    id: Uuid,                  // 转让ID
+    transferor: Uuid,          // 转让股东
+    proposed_transferee: Uuid, // 拟受让人
+    amount: Money,             // 转让的认缴出资额
+    price: Money,              // 转让价格
+    noticed_at: DateTime<Utc>, // 书面通知其他股东之日
+    exercised: Vec<Uuid>,      // 主张行使优先购买权的股东
+    waived: Vec<Uuid>,         // 放弃优先购买权的股东
+    status: TransferStatus,    // 转让状态
+}
+
+impl ShareTransfer {
+    /// 获取转让ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// 获取转让股东
+    pub fn transferor(&self) -> Uuid {
+        self.transferor
+    }
+
+    /// 获取拟受让人
+    pub fn proposed_transferee(&self) -> Uuid {
+        self.proposed_transferee
+    }
+
+    /// 获取转让的认缴出资额
+    pub fn amount(&self) -> Money {
+        self.amount
+    }
+
+    /// 获取转让价格
+    pub fn price(&self) -> Money {
+        self.price
+    }
+
+    /// 获取通知日期
+    pub fn noticed_at(&self) -> DateTime<Utc> {
+        self.noticed_at
+    }
+
+    /// 优先购买权的答复期限
+    pub fn preemption_deadline(&self) -> DateTime<Utc> {
+        self.noticed_at + Duration::days(PREEMPTION_PERIOD_DAYS)
+    }
+
+    /// 获取主张行使优先购买权的股东
+    pub fn exercised(&self) -> &[Uuid] {
+        &self.exercised
+    }
+
+    /// 获取转让状态
+    pub fn status(&self) -> &TransferStatus {
+        &self.status
+    }
+}
+
+/// 股东名册
+#[derive(Debug, Clone)]
+pub struct CapTable {
+    currency: Currency,            // 出资的币种
+    preemption: bool,              // 对外转让时其他股东是否享有优先购买权（有限责任公司）
+    holdings: Vec<Shareholding>,   // 股东的出资
+    transfers: Vec<ShareTransfer>, // 股权转让记录
+}
+
+impl CapTable {
+    pub fn new(currency: Currency, preemption: bool) -> Self {
+        Self {
+            currency,
+            preemption,
+            holdings: Vec::new(),
+            transfers: Vec::new(),
+        }
+    }
+
+    /// 股东认缴一期出资
+    pub fn subscribe(
+        &mut self,
+        shareholder: Uuid,
+        amount: Money,
+        due: DateTime<Utc>,
+    ) -> FanResult<()> {
+        if !amount.is_positive() || amount.currency() != self.currency {
+            return Err(FanError::validation(
+                "认缴出资额必须大于零且与注册资本币种一致",
+                ValidationErrorType::EntityError,
+                "subscribe",
+                "CapTable",
+            ));
+        }
+        let tranche = Tranche { amount, due };
+        match self.holding_mut(shareholder) {
+            Some(holding) => {
+                holding.tranches.push(tranche);
+                holding.tranches.sort_by_key(|t| t.due);
+            }
+            None => self.holdings.push(Shareholding {
+                shareholder,
+                tranches: vec![tranche],
+                paid_in: Money::zero(self.currency),
+            }),
+        }
+        Ok(())
+    }
+
+    /// 股东实缴出资，累计实缴不能超过认缴
+    pub fn pay(&mut self, shareholder: Uuid, amount: Money) -> FanResult<()> {
+        let holding = self
+            .holding_mut(shareholder)
+            .ok_or_else(|| not_shareholder("pay"))?;
+        let paid_in = holding.paid_in.checked_add(&amount)?;
+        if !amount.is_positive() || paid_in.checked_cmp(&holding.subscribed())?.is_gt() {
+            return Err(FanError::validation(
+                "实缴出资额必须大于零且累计不超过认缴出资额",
+                ValidationErrorType::EntityError,
+                "pay",
+                "CapTable",
+            ));
+        }
+        holding.paid_in = paid_in;
+        Ok(())
+    }
+
+    /// 认缴出资总额
+    pub fn subscribed_total(&self) -> Money {
+        let subscribed: Vec<Money> = self.holdings.iter().map(|h| h.subscribed()).collect();
+        Money::sum(&subscribed, self.currency).expect("股东名册中的金额币种一致")
+    }
+
+    /// 实缴出资总额
+    pub fn paid_in_total(&self) -> Money {
+        Money::sum(self.holdings.iter().map(|h| &h.paid_in), self.currency)
+            .expect("股东名册中的金额币种一致")
+    }
+
+    /// 股东按认缴出资计算的持股比例
+    pub fn equity_ratio(&self, shareholder: Uuid) -> Decimal {
+        let total = self.subscribed_total().amount();
+        match self.holding(shareholder) {
+            Some(holding) if !total.is_zero() => holding.subscribed().amount() / total,
+            _ => Decimal::ZERO,
+        }
+    }
+
+    /// 在指定时刻未按期足额缴纳出资的股东
+    pub fn defaulters_at(&self, at: DateTime<Utc>) -> Vec<ContributionDefault> {
+        self.holdings
+            .iter()
+            .map(|h| ContributionDefault {
+                shareholder: h.shareholder,
+                overdue: h.overdue_at(at),
+            })
+            .filter(|d| d.overdue.is_positive())
+            .collect()
+    }
+
+    /// 股东转让其出资
+    ///
+    /// 向其他股东转让的，直接完成；有限责任公司向股东以外的人转让的，应当书面通知其他股东，
+    /// 其他股东在同等条件下有优先购买权（《公司法》第八十四条）。返回转让ID。
+    pub fn transfer(
+        &mut self,
+        transferor: Uuid,
+        transferee: Uuid,
+        amount: Money,
+        price: Money,
+        at: DateTime<Utc>,
+    ) -> FanResult<Uuid> {
+        let holding = self
+            .holding(transferor)
+            .ok_or_else(|| not_shareholder("transfer"))?;
+        if transferor == transferee
+            || !amount.is_positive()
+            || amount.checked_cmp(&holding.subscribed())?.is_gt()
+        {
+            return Err(FanError::validation(
+                "转让的出资额必须大于零且不超过其认缴出资额",
+                ValidationErrorType::EntityError,
+                "transfer",
+                "CapTable",
+            ));
+        }
+        if self
+            .transfers
+            .iter()
+            .any(|t| t.transferor == transferor && t.status == TransferStatus::Noticed)
+        {
+            return Err(FanError::validation(
+                "该股东尚有未完成的股权转让",
+                ValidationErrorType::OperationSequenceWrong,
+                "transfer",
+                "CapTable",
+            ));
+        }
+
+        let id = Uuid::new_v4();
+        self.transfers.push(ShareTransfer {
+            id,
+            transferor,
+            proposed_transferee: transferee,
+            amount,
+            price,
+            noticed_at: at,
+            exercised: Vec::new(),
+            waived: Vec::new(),
+            status: TransferStatus::Noticed,
+        });
+        if !self.preemption || self.holding(transferee).is_some() {
+            self.complete_transfer(id, at)?;
+        }
+        Ok(id)
+    }
+
+    /// 其他股东在答复期限内主张行使优先购买权
+    pub fn exercise_preemption(
+        &mut self,
+        transfer_id: Uuid,
+        shareholder: Uuid,
+        at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        self.respond(transfer_id, shareholder, at, true)
+    }
+
+    /// 其他股东放弃优先购买权
+    pub fn waive_preemption(
+        &mut self,
+        transfer_id: Uuid,
+        shareholder: Uuid,
+        at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        self.respond(transfer_id, shareholder, at, false)
+    }
+
+    /// 完成股权转让
+    ///
+    /// 须待全体其他股东均已答复或者答复期限届满。有股东行使优先购买权的，由其按照各自的出资比例受让；
+    /// 无人行使的，由拟受让人受让。
+    pub fn complete_transfer(&mut self, transfer_id: Uuid, at: DateTime<Utc>) -> FanResult<()> {
+        let others: Vec<Uuid> = {
+            let transfer = self.find_transfer(transfer_id)?;
+            self.holdings
+                .iter()
+                .map(|h| h.shareholder)
+                .filter(|s| *s != transfer.transferor)
+                .collect()
+        };
+        let transfer = self.find_transfer(transfer_id)?.clone();
+        if transfer.status != TransferStatus::Noticed {
+            return Err(transfer_closed("complete_transfer"));
+        }
+
+        let internal = self.holding(transfer.proposed_transferee).is_some();
+        let all_answered = others
+            .iter()
+            .all(|s| transfer.exercised.contains(s) || transfer.waived.contains(s));
+        let open =
+            self.preemption && !internal && !all_answered && at < transfer.preemption_deadline();
+        if open {
+            return Err(FanError::validation_with_ref(
+                "其他股东的优先购买权答复期限尚未届满",
+                ValidationErrorType::OperationTimingWrong,
+                "《公司法》第八十四条",
+                "complete_transfer",
+                "CapTable",
+            ));
+        }
+
+        // 确定受让人及各自受让的比例
+        let shares: Vec<(Uuid, Decimal)> = if transfer.exercised.is_empty() {
+            vec![(transfer.proposed_transferee, Decimal::ONE)]
+        } else {
+            let weights: Vec<(Uuid, Decimal)> = transfer
+                .exercised
+                .iter()
+                .map(|s| (*s, self.equity_ratio(*s)))
+                .collect();
+            let total: Decimal = weights.iter().map(|(_, w)| *w).sum();
+            weights.into_iter().map(|(s, w)| (s, w / total)).collect()
+        };
+
+        let holding = self
+            .holding_mut(transfer.transferor)
+            .ok_or_else(|| not_shareholder("complete_transfer"))?;
+        let mut moved =
+            holding.split_off(transfer.amount.amount() / holding.subscribed().amount())?;
+        let mut parts = Vec::new();
+        for (index, (transferee, share)) in shares.iter().enumerate() {
+            let part = if index + 1 == shares.len() {
+                std::mem::replace(
+                    &mut moved,
+                    Shareholding {
+                        shareholder: *transferee,
+                        tranches: Vec::new(),
+                        paid_in: Money::zero(self.currency),
+                    },
+                )
+            } else {
+                let remaining =
+                    Decimal::ONE - shares[..index].iter().map(|(_, s)| *s).sum::<Decimal>();
+                moved.split_off(*share / remaining)?
+            };
+            parts.push((*transferee, part));
+        }
+        for (transferee, mut part) in parts {
+            part.shareholder = transferee;
+            match self.holding_mut(transferee) {
+                Some(existing) => existing.absorb(part)?,
+                None => self.holdings.push(part),
+            }
+        }
+        self.holdings.retain(|h| !h.tranches.is_empty());
+
+        let transferees = shares.into_iter().map(|(s, _)| s).collect();
+        let transfer = self
+            .transfers
+            .iter_mut()
+            .find(|t| t.id == transfer_id)
+            .expect("转让记录已存在");
+        transfer.status = TransferStatus::Completed { transferees };
+        Ok(())
+    }
+
+    fn respond(
+        &mut self,
+        transfer_id: Uuid,
+        shareholder: Uuid,
+        at: DateTime<Utc>,
+        exercise: bool,
+    ) -> FanResult<()> {
+        let is_other = self.holding(shareholder).is_some();
+        let transfer = self
+            .transfers
+            .iter_mut()
+            .find(|t| t.id == transfer_id)
+            .ok_or_else(|| transfer_closed("respond_preemption"))?;
+        if transfer.status != TransferStatus::Noticed {
+            return Err(transfer_closed("respond_preemption"));
+        }
+        if !is_other
+            || shareholder == transfer.transferor
+            || transfer.exercised.contains(&shareholder)
+            || transfer.waived.contains(&shareholder)
+        {
+            return Err(not_shareholder("respond_preemption"));
+        }
+        if at >= transfer.preemption_deadline() {
+            return Err(FanError::validation_with_ref(
+                "优先购买权的答复期限已届满，视为放弃",
+                ValidationErrorType::OperationTimingWrong,
+                "《公司法》第八十四条",
+                "respond_preemption",
+                "CapTable",
+            ));
+        }
+        if exercise {
+            transfer.exercised.push(shareholder);
+        } else {
+            transfer.waived.push(shareholder);
+        }
+        Ok(())
+    }
+
+    fn find_transfer(&self, transfer_id: Uuid) -> FanResult<&ShareTransfer> {
+        self.transfers
+            .iter()
+            .find(|t| t.id == transfer_id)
+            .ok_or_else(|| transfer_closed("find_transfer"))
+    }
+
+    /// 获取某一股东的出资
+    pub fn holding(&self, shareholder: Uuid) -> Option<&Shareholding> {
+        self.holdings.iter().find(|h| h.shareholder == shareholder)
+    }
+
+    fn holding_mut(&mut self, shareholder: Uuid) -> Option<&mut Shareholding> {
+        self.holdings
+            .iter_mut()
+            .find(|h| h.shareholder == shareholder)
+    }
+
+    /// 获取全体股东的出资
+    pub fn holdings(&self) -> &[Shareholding] {
+        &self.holdings
+    }
+
+    /// 获取股权转让记录
+    pub fn transfers(&self) -> &[ShareTransfer] {
+        &self.transfers
+    }
+
+    /// 获取出资的币种
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// 对外转让时其他股东是否享有优先购买权
+    pub fn has_preemption(&self) -> bool {
+        self.preemption
+    }
+}
+
+fn not_shareholder(operation: &str) -> FanError {
+    FanError::validation(
+        "该主体不是公司股东",
+        ValidationErrorType::EntityRelationMalformed,
+        operation,
+        "CapTable",
+    )
+}
+
+fn transfer_closed(operation: &str) -> FanError {
+    FanError::validation(
+        "未找到待完成的股权转让",
+        ValidationErrorType::OperationSequenceWrong,
+        operation,
+        "CapTable",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn cny(amount: i64) -> Money {
+        Money::cny(Decimal::from(amount))
+    }
+
+    fn founded() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap()
+    }
+
+    /// A、B、C 分别认缴 600、300、100，A 已实缴，B 的出资一年后到期
+    fn table() -> (CapTable, Uuid, Uuid, Uuid) {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut table = CapTable::new(Currency::CNY, true);
+        table.subscribe(a, cny(600), founded()).unwrap();
+        table
+            .subscribe(b, cny(300), founded() + Duration::days(365))
+            .unwrap();
+        table.subscribe(c, cny(100), founded()).unwrap();
+        table.pay(a, cny(600)).unwrap();
+        (table, a, b, c)
+    }
+
+    #[test]
+    fn test_contributions_and_defaults() {
+        let (mut table, a, _, c) = table();
+        assert!(table.pay(c, cny(200)).is_err());
+        assert_eq!(table.equity_ratio(a), Decimal::new(6, 1));
+        assert_eq!(table.subscribed_total(), cny(1000));
+        assert_eq!(table.paid_in_total(), cny(600));
+
+        // C 的出资已届期未缴，B 的出资尚未届期
+        let defaulters = table.defaulters_at(founded() + Duration::days(30));
+        assert_eq!(defaulters.len(), 1);
+        assert_eq!(defaulters[0].shareholder, c);
+        assert_eq!(defaulters[0].overdue, cny(100));
+    }
+
+    #[test]
+    fn test_preemption_waits_for_every_answer() {
+        let (mut table, a, b, c) = table();
+        let outsider = Uuid::new_v4();
+        let noticed = founded() + Duration::days(60);
+        let transfer = table
+            .transfer(a, outsider, cny(300), cny(450), noticed)
+            .unwrap();
+        assert!(table.complete_transfer(transfer, noticed).is_err());
+
+        // B 已主张行使，C 尚未答复的，不能完成转让
+        table.exercise_preemption(transfer, b, noticed).unwrap();
+        assert!(table.complete_transfer(transfer, noticed).is_err());
+        table.waive_preemption(transfer, c, noticed).unwrap();
+        table.complete_transfer(transfer, noticed).unwrap();
+        assert!(table.holding(outsider).is_none());
+        assert_eq!(table.holding(b).unwrap().subscribed(), cny(600));
+        assert_eq!(table.holding(b).unwrap().paid_in(), cny(300));
+    }
+
+    #[test]
+    fn test_preemption_shared_pro_rata() {
+        let (mut table, a, b, c) = table();
+        let noticed = founded() + Duration::days(60);
+        let transfer = table
+            .transfer(a, Uuid::new_v4(), cny(600), cny(900), noticed)
+            .unwrap();
+
+        // B、C 均行使优先购买权，按 3:1 的出资比例受让
+        table.exercise_preemption(transfer, b, noticed).unwrap();
+        table.exercise_preemption(transfer, c, noticed).unwrap();
+        table.complete_transfer(transfer, noticed).unwrap();
+        assert_eq!(table.holding(b).unwrap().subscribed(), cny(750));
+        assert_eq!(table.holding(c).unwrap().subscribed(), cny(250));
+        assert!(table.holding(a).is_none());
+        assert_eq!(
+            table.transfers()[0].status(),
+            &TransferStatus::Completed {
+                transferees: vec![b, c]
+            }
+        );
+    }
+
+    #[test]
+    fn test_preemption_lapses_after_deadline() {
+        let (mut table, a, b, c) = table();
+        let outsider = Uuid::new_v4();
+        let noticed = founded() + Duration::days(60);
+        let transfer = table
+            .transfer(c, outsider, cny(100), cny(100), noticed)
+            .unwrap();
+        table.exercise_preemption(transfer, b, noticed).unwrap();
+
+        // 答复期限届满，A 未答复的视为放弃，由行使优先购买权的 B 受让
+        let lapsed = noticed + Duration::days(PREEMPTION_PERIOD_DAYS);
+        assert!(table.exercise_preemption(transfer, a, lapsed).is_err());
+        table.complete_transfer(transfer, lapsed).unwrap();
+        assert_eq!(table.holding(b).unwrap().subscribed(), cny(400));
+        assert!(table.holding(c).is_none());
+
+        // 无人答复的，由拟受让人受让
+        let transfer = table
+            .transfer(b, outsider, cny(100), cny(100), lapsed)
+            .unwrap();
+        table
+            .complete_transfer(transfer, lapsed + Duration::days(PREEMPTION_PERIOD_DAYS))
+            .unwrap();
+        assert_eq!(table.holding(outsider).unwrap().subscribed(), cny(100));
+        assert_eq!(table.subscribed_total(), cny(1000));
+    }
+}
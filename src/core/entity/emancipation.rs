@@ -0,0 +1,107 @@
+//! 以自己的劳动收入为主要生活来源的未成年人
+//! 对应《民法典》第十八条第二款：十六周岁以上的未成年人，以自己的劳动收入为主要生活来源的，
+//! 视为完全民事行为能力人。
+
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+
+/// 以劳动收入为主要生活来源的事实依据
+#[derive(Debug, Clone)]
+pub struct SelfSupportBasis {
+    income_source: String,            // 劳动收入来源，如用人单位或经营项目
+    evidence: Vec<String>,            // 证明材料，如劳动合同、工资流水
+    effective_from: DateTime<Utc>,    // 开始以劳动收入为主要生活来源的日期
+    ceased_at: Option<DateTime<Utc>>, // 不再以劳动收入为主要生活来源的日期
+}
+
+impl SelfSupportBasis {
+    pub fn new(
+        income_source: String,
+        evidence: Vec<String>,
+        effective_from: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            income_source,
+            evidence,
+            effective_from,
+            ceased_at: None,
+        }
+    }
+
+    /// 在指定时刻该事实是否存在
+    pub fn is_in_effect_at(&self, at: DateTime<Utc>) -> bool {
+        self.effective_from <= at && self.ceased_at.is_none_or(|ceased_at| at < ceased_at)
+    }
+
+    /// 记录该事实终止，例如失去工作重新依靠他人生活
+    pub(crate) fn cease(&mut self, ceased_at: DateTime<Utc>) -> FanResult<()> {
+        if self.ceased_at.is_some() || ceased_at < self.effective_from {
+            return Err(FanError::validation(
+                "劳动收入来源已终止或终止日期早于开始日期",
+                ValidationErrorType::OperationTimingWrong,
+                "cease",
+                "SelfSupportBasis",
+            ));
+        }
+        self.ceased_at = Some(ceased_at);
+        Ok(())
+    }
+
+    /// 获取劳动收入来源
+    pub fn income_source(&self) -> &str {
+        &self.income_source
+    }
+
+    /// 获取证明材料
+    pub fn evidence(&self) -> &[String] {
+        &self.evidence
+    }
+
+    /// 获取开始日期
+    pub fn effective_from(&self) -> DateTime<Utc> {
+        self.effective_from
+    }
+
+    /// 获取终止日期
+    pub fn ceased_at(&self) -> Option<DateTime<Utc>> {
+        self.ceased_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn basis() -> SelfSupportBasis {
+        SelfSupportBasis::new(
+            "某餐饮有限公司".to_string(),
+            vec!["劳动合同".to_string(), "工资流水".to_string()],
+            Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_basis_in_effect_from_start() {
+        let basis = basis();
+        let start = basis.effective_from();
+        assert!(!basis.is_in_effect_at(start - Duration::days(1)));
+        assert!(basis.is_in_effect_at(start));
+        assert!(basis.is_in_effect_at(start + Duration::days(3650)));
+    }
+
+    #[test]
+    fn test_cessation_ends_basis() {
+        let mut basis = basis();
+        let start = basis.effective_from();
+        assert!(basis.cease(start - Duration::days(1)).is_err());
+
+        let ceased = start + Duration::days(90);
+        basis.cease(ceased).unwrap();
+        assert_eq!(basis.ceased_at(), Some(ceased));
+        assert!(basis.is_in_effect_at(ceased - Duration::days(1)));
+        assert!(!basis.is_in_effect_at(ceased));
+        assert!(basis.cease(ceased + Duration::days(1)).is_err());
+    }
+}
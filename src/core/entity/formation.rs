@@ -0,0 +1,701 @@
+//! 设立中的法人
+//! 对应《民法典》第七十五条：设立人为设立法人从事的民事活动，其法律后果由法人承受；法人未成立的，其法律后果由设立人承受，
+//! 设立人为二人以上的，享有连带债权，承担连带债务。设立中的法人以设立人为代表签署意思表示，成立或者设立失败时
+//! 在 [`SuccessionRegistry`] 中登记；法人成立的，一并交由其承受的合同随即由其代替设立中的法人为当事人，
+//! 设立失败的，合同可经 [`SuccessionRegistry::rebind`] 由设立人代替。
+//! 设立程序结束后，设立中的法人不再具有行为能力；合同持有的 [`SyncLegalPersonInFormation`] 与原值共享设立进展。
+
+use crate::core::clock::{default_clock, Clock};
+use crate::core::entity::base::{
+    AuthorityScope, AuthorityStatus, BaseEntity, CapacityStatus, Entity, EntityType,
+};
+use crate::core::entity::legal_person::{LegalPerson, LegalPersonType};
+use crate::core::entity::lifecycle::LegalPersonPhase;
+use crate::core::entity::succession::{SuccessionAware, SuccessionKind, SuccessionRegistry};
+use crate::core::money::Money;
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 设立的进展
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormationStatus {
+    /// 设立中
+    InProgress,
+    /// 法人已经成立
+    Established {
+        legal_person: Uuid,
+        at: DateTime<Utc>,
+    },
+    /// 法人未能成立
+    Failed { at: DateTime<Utc> },
+}
+
+/// 设立中的法人
+///
+/// 不具有法人资格，只能由设立人签署，从事设立法人所必要的民事活动。
+#[derive(Debug, Clone)]
+pub struct LegalPersonInFormation {
+    base: BaseEntity,
+    legal_person_type: LegalPersonType, // 拟设立的法人类型
+    registered_capital: Money,          // 拟登记的注册资本
+    founders: Vec<Uuid>,                // 设立人
+    status: FormationStatus,            // 设立的进展
+}
+
+impl LegalPersonInFormation {
+    pub fn new(
+        legal_person_type: LegalPersonType,
+        registered_capital: Money,
+        founders: &[&dyn Entity],
+    ) -> FanResult<Self> {
+        Self::with_clock(
+            legal_person_type,
+            registered_capital,
+            founders,
+            default_clock(),
+        )
+    }
+
+    /// 使用指定时钟创建设立中的法人
+    pub fn with_clock(
+        legal_person_type: LegalPersonType,
+        registered_capital: Money,
+        founders: &[&dyn Entity],
+        clock: Arc<dyn Clock>,
+    ) -> FanResult<Self> {
+        let founders = check_founders(founders, clock.now())?;
+        Ok(Self {
+            base: new_base(clock),
+            legal_person_type,
+            registered_capital,
+            founders,
+            status: FormationStatus::InProgress,
+        })
+    }
+
+    /// 法人依法成立，由其承受设立中的法人的权利义务
+    ///
+    /// 成立的法人须与拟设立的法人类型一致并处于存续状态。登记后，`contracts` 中以设立中的法人为当事人的
+    /// 合同由成立后的法人代替其为当事人；合同不能代替的（如效力待定）返回错误，法人仍已成立，
+    /// 可在完成追认后经 [`SuccessionRegistry::rebind`] 代替。
+    pub fn establish(
+        &mut self,
+        legal_person: &LegalPerson,
+        registry: &mut SuccessionRegistry,
+        contracts: &mut [&mut dyn SuccessionAware],
+    ) -> FanResult<()> {
+        let at = self.base.now();
+        self.status = establish(
+            self.id(),
+            &self.legal_person_type,
+            &self.status,
+            legal_person,
+            registry,
+            at,
+        )?;
+        self.base.touch();
+        transfer_contracts(legal_person, registry, contracts)
+    }
+
+    /// 法人未能成立，由设立人承受设立中的法人的权利义务，设立人为二人以上的承担连带债务
+    pub fn fail(&mut self, registry: &mut SuccessionRegistry) -> FanResult<()> {
+        let at = self.base.now();
+        self.status = fail(self.id(), &self.founders, &self.status, registry, at)?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 获取拟设立的法人类型
+    pub fn legal_person_type(&self) -> &LegalPersonType {
+        &self.legal_person_type
+    }
+
+    /// 获取拟登记的注册资本
+    pub fn registered_capital(&self) -> Money {
+        self.registered_capital
+    }
+
+    /// 获取设立人
+    pub fn founders(&self) -> &[Uuid] {
+        &self.founders
+    }
+
+    /// 获取设立的进展
+    pub fn status(&self) -> &FormationStatus {
+        &self.status
+    }
+}
+
+impl Entity for LegalPersonInFormation {
+    fn id(&self) -> Uuid {
+        self.base.id
+    }
+    fn entity_type(&self) -> EntityType {
+        self.base.entity_type.clone()
+    }
+    fn capacity_status(&self) -> CapacityStatus {
+        self.base.capacity_status.clone()
+    }
+    fn created_at(&self) -> DateTime<Utc> {
+        self.base.created_at
+    }
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.base.updated_at
+    }
+
+    /// 设立程序结束后不能再以设立中的法人的名义从事民事活动
+    fn has_capacity(&self) -> bool {
+        self.has_capacity_at(self.base.now())
+    }
+    fn has_capacity_at(&self, at: DateTime<Utc>) -> bool {
+        in_progress_at(&self.status, at)
+    }
+    /// 设立人为设立法人从事民事活动（第七十五条）
+    fn representatives(&self) -> Vec<Uuid> {
+        self.founders.clone()
+    }
+}
+
+/// 线程安全版本的设立中的法人
+///
+/// 克隆后与原值共享设立进展，合同当事人持有的克隆在法人成立或者设立失败后随之失去行为能力。
+#[derive(Debug, Clone)]
+pub struct SyncLegalPersonInFormation {
+    base: Arc<RwLock<BaseEntity>>,
+    legal_person_type: LegalPersonType, // 不可变
+    registered_capital: Money,          // 不可变
+    founders: Vec<Uuid>,                // 不可变
+    status: Arc<RwLock<FormationStatus>>,
+}
+
+impl SyncLegalPersonInFormation {
+    pub fn new(
+        legal_person_type: LegalPersonType,
+        registered_capital: Money,
+        founders: &[&dyn Entity],
+    ) -> FanResult<Self> {
+        Self::with_clock(
+            legal_person_type,
+            registered_capital,
+            founders,
+            default_clock(),
+        )
+    }
+
+    /// 使用指定时钟创建设立中的法人
+    pub fn with_clock(
+        legal_person_type: LegalPersonType,
+        registered_capital: Money,
+        founders: &[&dyn Entity],
+        clock: Arc<dyn Clock>,
+    ) -> FanResult<Self> {
+        LegalPersonInFormation::with_clock(legal_person_type, registered_capital, founders, clock)
+            .map(Self::from_formation)
+    }
+
+    pub fn from_formation(formation: LegalPersonInFormation) -> Self {
+        Self {
+            base: Arc::new(RwLock::new(formation.base)),
+            legal_person_type: formation.legal_person_type,
+            registered_capital: formation.registered_capital,
+            founders: formation.founders,
+            status: Arc::new(RwLock::new(formation.status)),
+        }
+    }
+
+    pub fn establish(
+        &self,
+        legal_person: &LegalPerson,
+        registry: &mut SuccessionRegistry,
+        contracts: &mut [&mut dyn SuccessionAware],
+    ) -> FanResult<()> {
+        let at = self.base.read().now();
+        let mut status = self.status.write();
+        *status = establish(
+            self.id(),
+            &self.legal_person_type,
+            &status,
+            legal_person,
+            registry,
+            at,
+        )?;
+        drop(status); // 释放写锁
+        self.base.write().touch();
+        transfer_contracts(legal_person, registry, contracts)
+    }
+
+    pub fn fail(&self, registry: &mut SuccessionRegistry) -> FanResult<()> {
+        let at = self.base.read().now();
+        let mut status = self.status.write();
+        *status = fail(self.id(), &self.founders, &status, registry, at)?;
+        drop(status); // 释放写锁
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn legal_person_type(&self) -> &LegalPersonType {
+        &self.legal_person_type
+    }
+
+    pub fn registered_capital(&self) -> Money {
+        self.registered_capital
+    }
+
+    pub fn founders(&self) -> &[Uuid] {
+        &self.founders
+    }
+
+    pub fn status(&self) -> FormationStatus {
+        self.status.read().clone()
+    }
+}
+
+impl Entity for SyncLegalPersonInFormation {
+    fn id(&self) -> Uuid {
+        self.base.read().id
+    }
+
+    fn entity_type(&self) -> EntityType {
+        self.base.read().entity_type.clone()
+    }
+
+    fn capacity_status(&self) -> CapacityStatus {
+        self.base.read().capacity_status.clone()
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.base.read().created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.base.read().updated_at
+    }
+
+    fn has_capacity(&self) -> bool {
+        let now = self.base.read().now();
+        self.has_capacity_at(now)
+    }
+
+    fn has_capacity_at(&self, at: DateTime<Utc>) -> bool {
+        in_progress_at(&self.status.read(), at)
+    }
+
+    fn representatives(&self) -> Vec<Uuid> {
+        self.founders.clone()
+    }
+}
+
+/// 设立人须为一个以上具有相应民事行为能力的主体，且不得重复
+fn check_founders(founders: &[&dyn Entity], at: DateTime<Utc>) -> FanResult<Vec<Uuid>> {
+    let ids: Vec<Uuid> = founders.iter().map(|f| f.id()).collect();
+    let distinct = ids.iter().enumerate().all(|(i, id)| !ids[..i].contains(id));
+    if ids.is_empty() || !distinct || founders.iter().any(|f| !f.has_capacity_at(at)) {
+        return Err(FanError::validation(
+            "设立人须为一个以上具有相应民事行为能力的主体，且不得重复",
+            ValidationErrorType::EntityCapacityLacking,
+            "new",
+            "LegalPersonInFormation",
+        ));
+    }
+    Ok(ids)
+}
+
+/// 设立中的法人不具有法人资格，没有登记的经营范围，职权受限于设立所必要的民事活动
+fn new_base(clock: Arc<dyn Clock>) -> BaseEntity {
+    let authority_scope = AuthorityScope {
+        status: AuthorityStatus::Limited,
+        permitted_authorities: HashSet::new(),
+        restrictions: None,
+    };
+    BaseEntity::new(
+        EntityType::LegalPersonInFormation,
+        CapacityStatus::UnincorporatedOrg(authority_scope),
+        clock,
+    )
+}
+
+/// 在指定时刻设立程序是否尚未结束
+fn in_progress_at(status: &FormationStatus, at: DateTime<Utc>) -> bool {
+    match status {
+        FormationStatus::InProgress => true,
+        FormationStatus::Established { at: ended, .. } | FormationStatus::Failed { at: ended } => {
+            at < *ended
+        }
+    }
+}
+
+fn ensure_in_progress(status: &FormationStatus, operation: &str) -> FanResult<()> {
+    if *status != FormationStatus::InProgress {
+        return Err(FanError::validation(
+            "设立程序已经结束",
+            ValidationErrorType::OperationSequenceWrong,
+            operation,
+            "LegalPersonInFormation",
+        ));
+    }
+    Ok(())
+}
+
+/// 成立的法人须为拟设立的类型并处于存续状态，登记承受关系后返回新的设立进展
+fn establish(
+    id: Uuid,
+    legal_person_type: &LegalPersonType,
+    status: &FormationStatus,
+    legal_person: &LegalPerson,
+    registry: &mut SuccessionRegistry,
+    at: DateTime<Utc>,
+) -> FanResult<FormationStatus> {
+    ensure_in_progress(status, "establish")?;
+    if legal_person.legal_person_type() != legal_person_type
+        || legal_person.phase() != LegalPersonPhase::Active
+    {
+        return Err(FanError::validation_with_ref(
+            "成立的法人须为拟设立的类型，且处于存续状态",
+            ValidationErrorType::EntityRelationMalformed,
+            "《民法典》第七十五条",
+            "establish",
+            "LegalPersonInFormation",
+        ));
+    }
+    registry.record(
+        SuccessionKind::Establishment,
+        vec![id],
+        vec![legal_person.id()],
+        at,
+    )?;
+    Ok(FormationStatus::Established {
+        legal_person: legal_person.id(),
+        at,
+    })
+}
+
+/// 以设立中的法人为当事人的合同由成立后的法人代替其为当事人（第七十五条第一款）
+fn transfer_contracts(
+    legal_person: &LegalPerson,
+    registry: &SuccessionRegistry,
+    contracts: &mut [&mut dyn SuccessionAware],
+) -> FanResult<()> {
+    let established: Arc<dyn Entity> = Arc::new(legal_person.clone());
+    registry.rebind(contracts, &[established])
+}
+
+/// 法人未能成立的，由设立人承受，登记承受关系后返回新的设立进展
+fn fail(
+    id: Uuid,
+    founders: &[Uuid],
+    status: &FormationStatus,
+    registry: &mut SuccessionRegistry,
+    at: DateTime<Utc>,
+) -> FanResult<FormationStatus> {
+    ensure_in_progress(status, "fail")?;
+    registry.record(
+        SuccessionKind::FailedEstablishment,
+        vec![id],
+        founders.to_vec(),
+        at,
+    )?;
+    Ok(FormationStatus::Failed { at })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::agency::Signatory;
+    use crate::contract::base::{BaseContract, Contract};
+    use crate::contract::intent::content::{
+        BusinessActivity, IntentContent, Price, SubjectMatter, SubjectMatterType,
+    };
+    use crate::contract::intent::declaration::{DeclarationType, IntentDeclaration};
+    use crate::core::clock::ManualClock;
+    use crate::core::entity::{CompanyType, NaturalPerson};
+    use crate::testing::{adult, fixed_clock, now, person};
+    use crate::validate::business_scope::{check_business_activity, ScopeAssessment};
+    use chrono::Duration;
+    use rust_decimal::Decimal;
+
+    fn limited_company() -> LegalPersonType {
+        LegalPersonType::Company(CompanyType::Limited)
+    }
+
+    fn capital() -> Money {
+        Money::cny(Decimal::from(1_000_000))
+    }
+
+    fn company(
+        legal_person_type: LegalPersonType,
+        founder: Uuid,
+        clock: &Arc<dyn Clock>,
+    ) -> LegalPerson {
+        LegalPerson::with_clock(
+            legal_person_type,
+            capital(),
+            founder,
+            "上海市徐汇区xxx路".to_string(),
+            clock.now(),
+            clock.clone(),
+        )
+    }
+
+    /// 在拟成立之日核准登记的公司
+    fn registered(mut company: LegalPerson) -> LegalPerson {
+        let at = company.establishment_date();
+        company
+            .register(
+                "上海市徐汇区市场监督管理局".to_string(),
+                "91310104MA0000000X".to_string(),
+                at,
+            )
+            .unwrap();
+        company
+    }
+
+    fn lease_of_office() -> IntentContent {
+        IntentContent {
+            subject_matter: SubjectMatter::new(
+                Uuid::new_v4(),
+                SubjectMatterType::Service,
+                "办公场所租赁".to_string(),
+                None,
+            ),
+            price: Some(Price::new(
+                Money::cny(Decimal::from(20_000)),
+                "转账".to_string(),
+            )),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_founders_must_be_capable_and_distinct() {
+        let clock = fixed_clock();
+        let alice = Arc::new(adult(&clock));
+        let minor = person(2015);
+        let form = |founders: &[&dyn Entity]| {
+            LegalPersonInFormation::with_clock(
+                limited_company(),
+                capital(),
+                founders,
+                clock.clone(),
+            )
+        };
+        assert!(form(&[]).is_err());
+        assert!(form(&[alice.as_ref(), alice.as_ref()]).is_err());
+        assert!(form(&[alice.as_ref(), &minor]).is_err());
+        assert_eq!(
+            form(&[alice.as_ref()]).unwrap().representatives(),
+            vec![alice.id()]
+        );
+    }
+
+    #[test]
+    fn test_establishment_requires_registered_company_of_intended_type() {
+        let clock = fixed_clock();
+        let alice = Arc::new(adult(&clock));
+        let mut forming = LegalPersonInFormation::with_clock(
+            limited_company(),
+            capital(),
+            &[alice.as_ref()],
+            clock.clone(),
+        )
+        .unwrap();
+        let mut registry = SuccessionRegistry::new();
+        let joint_stock = registered(company(
+            LegalPersonType::Company(CompanyType::JointStock),
+            alice.id(),
+            &clock,
+        ));
+        assert!(forming
+            .establish(&joint_stock, &mut registry, &mut [])
+            .is_err());
+        // 公司经登记方才成立
+        let unregistered = company(limited_company(), alice.id(), &clock);
+        assert!(forming
+            .establish(&unregistered, &mut registry, &mut [])
+            .is_err());
+
+        let established = registered(unregistered);
+        forming
+            .establish(&established, &mut registry, &mut [])
+            .unwrap();
+        assert_eq!(
+            forming.status(),
+            &FormationStatus::Established {
+                legal_person: established.id(),
+                at: now()
+            }
+        );
+        assert!(!registry.is_jointly_liable(forming.id()));
+        assert!(forming
+            .establish(&established, &mut registry, &mut [])
+            .is_err());
+        assert!(forming.fail(&mut registry).is_err());
+    }
+
+    #[test]
+    fn test_forming_entity_acts_through_founders() {
+        let clock = fixed_clock();
+        let (alice, stranger, landlord) = (
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+            Arc::new(adult(&clock)),
+        );
+        let forming: Arc<dyn Entity> = Arc::new(
+            LegalPersonInFormation::with_clock(
+                limited_company(),
+                capital(),
+                &[alice.as_ref()],
+                clock.clone(),
+            )
+            .unwrap(),
+        );
+        let landlord: Arc<dyn Entity> = landlord;
+
+        // 设立中的法人不能自行作出意思表示
+        assert!(IntentDeclaration::with_clock(
+            DeclarationType::Offer,
+            forming.clone(),
+            Some(landlord.clone()),
+            lease_of_office(),
+            None,
+            clock.clone(),
+        )
+        .is_err());
+
+        // 设立人签署的对设立中的法人发生效力，设立人以外的人签署的须经追认
+        let signed_by = |person: &Arc<NaturalPerson>| {
+            IntentDeclaration::signed_by_with_clock(
+                DeclarationType::Offer,
+                forming.clone(),
+                Signatory::representative(person.clone()),
+                Some(landlord.clone()),
+                lease_of_office(),
+                None,
+                clock.clone(),
+            )
+            .unwrap()
+        };
+        assert!(!signed_by(&alice).is_pending_ratification());
+        assert!(signed_by(&stranger).is_pending_ratification());
+    }
+
+    #[test]
+    fn test_forming_entity_has_no_registered_scope() {
+        let clock = fixed_clock();
+        let alice = Arc::new(adult(&clock));
+        let forming = LegalPersonInFormation::with_clock(
+            limited_company(),
+            capital(),
+            &[alice.as_ref()],
+            clock.clone(),
+        )
+        .unwrap();
+        assert_eq!(forming.entity_type(), EntityType::LegalPersonInFormation);
+
+        // 没有登记的经营范围，不评估是否超越经营范围
+        let mut content = lease_of_office();
+        content.business_activity = Some(BusinessActivity::new(
+            forming.id(),
+            "应用软件开发".to_string(),
+        ));
+        assert_eq!(
+            check_business_activity(&forming, &content, now()).unwrap(),
+            ScopeAssessment::NotApplicable
+        );
+        // 许可经营的项目仍须取得许可证
+        content.business_activity =
+            Some(BusinessActivity::new(forming.id(), "药品零售".to_string()));
+        assert!(check_business_activity(&forming, &content, now()).is_err());
+    }
+
+    #[test]
+    fn test_contract_passes_to_established_company() {
+        let clock = Arc::new(ManualClock::new(now()));
+        let clock_dyn: Arc<dyn Clock> = clock.clone();
+        let (alice, bob, landlord) = (
+            Arc::new(adult(&clock_dyn)),
+            Arc::new(adult(&clock_dyn)),
+            Arc::new(adult(&clock_dyn)),
+        );
+        let forming = SyncLegalPersonInFormation::with_clock(
+            limited_company(),
+            capital(),
+            &[alice.as_ref(), bob.as_ref()],
+            clock_dyn.clone(),
+        )
+        .unwrap();
+        let (forming_dyn, landlord_dyn): (Arc<dyn Entity>, Arc<dyn Entity>) =
+            (Arc::new(forming.clone()), landlord.clone());
+        let declarations = vec![
+            IntentDeclaration::signed_by_with_clock(
+                DeclarationType::Offer,
+                forming_dyn.clone(),
+                Signatory::representative(alice.clone()),
+                Some(landlord_dyn.clone()),
+                lease_of_office(),
+                None,
+                clock_dyn.clone(),
+            )
+            .unwrap(),
+            IntentDeclaration::with_clock(
+                DeclarationType::Acceptance,
+                landlord_dyn.clone(),
+                Some(forming_dyn.clone()),
+                lease_of_office(),
+                None,
+                clock_dyn.clone(),
+            )
+            .unwrap(),
+        ];
+        let mut lease = BaseContract::with_clock(
+            vec![forming_dyn.clone(), landlord_dyn.clone()],
+            declarations,
+            vec![],
+            None,
+            clock_dyn.clone(),
+        );
+        lease.make_effective().unwrap();
+
+        // 公司成立后，合同所持的设立中的法人随之失去行为能力，合同由公司承受
+        clock.advance(Duration::days(30));
+        let mut registry = SuccessionRegistry::new();
+        let established = registered(company(limited_company(), alice.id(), &clock_dyn));
+        forming
+            .establish(&established, &mut registry, &mut [&mut lease])
+            .unwrap();
+        assert!(!forming_dyn.has_capacity());
+        assert!(forming_dyn.has_capacity_at(now()));
+        let parties: Vec<Uuid> = lease.parties().iter().map(|p| p.id()).collect();
+        assert_eq!(parties, vec![landlord.id(), established.id()]);
+        assert!(lease.validate().is_ok());
+    }
+
+    #[test]
+    fn test_founders_jointly_bear_failed_formation() {
+        let clock = fixed_clock();
+        let (alice, bob) = (Arc::new(adult(&clock)), Arc::new(adult(&clock)));
+        let mut registry = SuccessionRegistry::new();
+        let mut abandoned = LegalPersonInFormation::with_clock(
+            limited_company(),
+            capital(),
+            &[alice.as_ref(), bob.as_ref()],
+            clock.clone(),
+        )
+        .unwrap();
+        abandoned.fail(&mut registry).unwrap();
+        assert!(!abandoned.has_capacity());
+        assert_eq!(
+            registry.current_successors(abandoned.id()),
+            vec![alice.id(), bob.id()]
+        );
+        assert_eq!(
+            registry.liable_for(abandoned.id()),
+            vec![alice.id(), bob.id()]
+        );
+        assert!(registry.is_jointly_liable(abandoned.id()));
+    }
+}
@@ -0,0 +1,792 @@
+//! 监护制度
+//! 包括监护关系、监护范围，以及依《民法典》第二十七条、第二十八条确定监护人顺序、
+//! 依第三十一条、第三十二条由有关组织或人民法院指定监护人的流程，
+//! 以及依第三十六条、第三十九条终止监护关系。
+
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// 监护关系
+#[derive(Debug, Clone)]
+pub struct Guardianship {
+    guardian: Uuid,                        // 监护人ID
+    ward: Uuid,                            // 被监护人ID
+    scope: GuardianshipScope,              // 监护范围
+    basis: GuardianshipBasis,              // 担任监护人的依据
+    created_at: DateTime<Utc>,             // 监护关系建立时间
+    valid_until: Option<DateTime<Utc>>,    // 监护关系有效期
+    terminated_at: Option<DateTime<Utc>>,  // 监护关系终止时间
+    termination: Option<TerminationCause>, // 监护关系终止原因
+}
+
+/// 监护关系终止的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum TerminationCause {
+    /// 被监护人取得或者恢复完全民事行为能力（第三十九条第一款第一项）
+    WardGainedCapacity,
+    /// 监护人丧失监护能力（第三十九条第一款第二项）
+    GuardianLostCapacity,
+    /// 被监护人死亡（第三十九条第一款第三项）
+    WardDied,
+    /// 监护人死亡（第三十九条第一款第三项）
+    GuardianDied,
+    /// 人民法院撤销监护人资格（第三十六条）
+    Revoked(GuardianshipRuling),
+    /// 人民法院认定监护关系终止的其他情形（第三十九条第一款第四项）
+    CourtDetermined(GuardianshipRuling),
+}
+
+/// 人民法院就某一监护关系作出的判决
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuardianshipRuling {
+    court: String,           // 作出判决的人民法院
+    ward: Uuid,              // 被监护人ID
+    guardian: Uuid,          // 监护人ID
+    ruled_at: DateTime<Utc>, // 判决生效日期
+    reason: String,          // 判决理由
+}
+
+impl GuardianshipRuling {
+    /// 记录人民法院的判决，须载明法院和理由
+    pub fn new(
+        court: String,
+        ward: Uuid,
+        guardian: Uuid,
+        ruled_at: DateTime<Utc>,
+        reason: String,
+    ) -> FanResult<Self> {
+        if court.trim().is_empty() || reason.trim().is_empty() {
+            return Err(FanError::validation_with_ref(
+                "监护关系的判决须载明作出判决的人民法院和理由",
+                ValidationErrorType::EntityError,
+                "《民法典》第三十六条、第三十九条",
+                "new_guardianship_ruling",
+                "GuardianshipRuling",
+            ));
+        }
+        Ok(Self {
+            court,
+            ward,
+            guardian,
+            ruled_at,
+            reason,
+        })
+    }
+
+    /// 判决是否针对该监护关系，且在指定时刻已经生效
+    pub(crate) fn applies_to(&self, ward: Uuid, guardian: Uuid, at: DateTime<Utc>) -> bool {
+        self.ward == ward && self.guardian == guardian && self.ruled_at <= at
+    }
+
+    /// 获取作出判决的人民法院
+    pub fn court(&self) -> &str {
+        &self.court
+    }
+
+    /// 获取被监护人ID
+    pub fn ward(&self) -> Uuid {
+        self.ward
+    }
+
+    /// 获取监护人ID
+    pub fn guardian(&self) -> Uuid {
+        self.guardian
+    }
+
+    /// 获取判决生效日期
+    pub fn ruled_at(&self) -> DateTime<Utc> {
+        self.ruled_at
+    }
+
+    /// 获取判决理由
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Guardianship {
+    pub(crate) fn new(
+        basis: GuardianshipBasis,
+        scope: GuardianshipScope,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            guardian: basis.guardian,
+            ward: basis.ward,
+            scope,
+            valid_until: basis.valid_until,
+            basis,
+            created_at,
+            terminated_at: None,
+            termination: None,
+        }
+    }
+
+    /// 在指定时刻监护关系是否存续
+    ///
+    /// 已建立、未被终止且未超过有效期的监护关系为存续。
+    pub fn is_active_at(&self, at: DateTime<Utc>) -> bool {
+        self.created_at <= at
+            && self
+                .terminated_at
+                .is_none_or(|terminated_at| at < terminated_at)
+            && self.valid_until.is_none_or(|valid_until| at < valid_until)
+    }
+
+    /// 终止监护关系，终止前的历史记录仍然保留
+    pub(crate) fn terminate(
+        &mut self,
+        cause: TerminationCause,
+        terminated_at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        if !self.is_active_at(terminated_at) {
+            return Err(FanError::validation_with_ref(
+                "监护关系在终止时已不存续",
+                ValidationErrorType::OperationSequenceWrong,
+                "《民法典》第三十九条",
+                "terminate",
+                "Guardianship",
+            ));
+        }
+        self.terminated_at = Some(terminated_at);
+        self.termination = Some(cause);
+        Ok(())
+    }
+
+    /// 获取监护人ID
+    pub fn guardian(&self) -> Uuid {
+        self.guardian
+    }
+
+    /// 获取被监护人ID
+    pub fn ward(&self) -> Uuid {
+        self.ward
+    }
+
+    /// 获取监护范围
+    pub fn scope(&self) -> &GuardianshipScope {
+        &self.scope
+    }
+
+    /// 获取担任监护人的依据
+    pub fn basis(&self) -> &GuardianshipBasis {
+        &self.basis
+    }
+
+    /// 获取监护关系建立时间
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    /// 获取监护关系有效期
+    pub fn valid_until(&self) -> Option<DateTime<Utc>> {
+        self.valid_until
+    }
+
+    /// 获取监护关系终止时间
+    pub fn terminated_at(&self) -> Option<DateTime<Utc>> {
+        self.terminated_at
+    }
+
+    /// 获取监护关系终止原因
+    pub fn termination(&self) -> Option<&TerminationCause> {
+        self.termination.as_ref()
+    }
+}
+
+/// 监护人可以代理被监护人实施的行为类别
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GuardianshipAction {
+    PersonalCare,       // 人身照护
+    Education,          // 教育
+    MedicalDecision,    // 医疗决定
+    DailyTransaction,   // 日常生活所需的民事法律行为
+    PropertyManagement, // 财产管理，如收取租金、存款
+    PropertyDisposal,   // 处分被监护人的财产
+    Litigation,         // 代理诉讼
+}
+
+impl GuardianshipAction {
+    /// 是否属于处分被监护人财产的行为（第三十五条第一款）
+    pub fn disposes_property(&self) -> bool {
+        matches!(self, GuardianshipAction::PropertyDisposal)
+    }
+}
+
+/// 监护范围
+#[derive(Debug, Clone)]
+pub struct GuardianshipScope {
+    permitted_actions: HashSet<GuardianshipAction>, // 允许的行为类型
+}
+
+impl GuardianshipScope {
+    pub fn new(permitted_actions: impl IntoIterator<Item = GuardianshipAction>) -> Self {
+        Self {
+            permitted_actions: permitted_actions.into_iter().collect(),
+        }
+    }
+
+    /// 该行为是否在监护范围内
+    pub fn permits(&self, action: &GuardianshipAction) -> bool {
+        self.permitted_actions.contains(action)
+    }
+
+    /// 获取允许的行为类型
+    pub fn permitted_actions(&self) -> &HashSet<GuardianshipAction> {
+        &self.permitted_actions
+    }
+}
+
+/// 候选人与被监护人的关系
+#[derive(Debug, Clone, PartialEq)]
+pub enum Relationship {
+    Parent,      // 父母
+    Spouse,      // 配偶
+    Child,       // 子女
+    Grandparent, // 祖父母、外祖父母
+    Sibling,     // 兄弟姐妹
+    Grandchild,  // 孙子女、外孙子女
+    Other,       // 其他个人或者组织
+}
+
+/// 被监护人的类别
+#[derive(Debug, Clone, PartialEq)]
+pub enum WardCategory {
+    /// 未成年人（第二十七条）
+    Minor,
+    /// 无民事行为能力或者限制民事行为能力的成年人（第二十八条）
+    IncapacitatedAdult,
+}
+
+impl WardCategory {
+    /// 候选人在法定顺序中的位次，数字越小越优先
+    fn rank(&self, relationship: &Relationship) -> u8 {
+        match (self, relationship) {
+            (WardCategory::Minor, Relationship::Parent) => 1,
+            (WardCategory::Minor, Relationship::Grandparent) => 2,
+            (WardCategory::Minor, Relationship::Sibling) => 3,
+            (WardCategory::Minor, _) => 4,
+            (WardCategory::IncapacitatedAdult, Relationship::Spouse) => 1,
+            (WardCategory::IncapacitatedAdult, Relationship::Parent | Relationship::Child) => 2,
+            (
+                WardCategory::IncapacitatedAdult,
+                Relationship::Grandparent | Relationship::Sibling | Relationship::Grandchild,
+            ) => 3,
+            (WardCategory::IncapacitatedAdult, Relationship::Other) => 4,
+        }
+    }
+
+    fn article(&self) -> &'static str {
+        match self {
+            WardCategory::Minor => "《民法典》第二十七条",
+            WardCategory::IncapacitatedAdult => "《民法典》第二十八条",
+        }
+    }
+}
+
+/// 有权同意或指定监护人的组织
+#[derive(Debug, Clone, PartialEq)]
+pub enum GuardianshipAuthority {
+    ResidentsCommittee(Uuid),     // 居民委员会
+    VillagersCommittee(Uuid),     // 村民委员会
+    CivilAffairsDepartment(Uuid), // 民政部门
+    Court(String),                // 人民法院
+}
+
+impl GuardianshipAuthority {
+    /// 该组织自身能否担任监护人（第三十二条）
+    fn public_guardian_id(&self) -> Option<Uuid> {
+        match self {
+            GuardianshipAuthority::ResidentsCommittee(id)
+            | GuardianshipAuthority::VillagersCommittee(id)
+            | GuardianshipAuthority::CivilAffairsDepartment(id) => Some(*id),
+            GuardianshipAuthority::Court(_) => None,
+        }
+    }
+}
+
+/// 监护人候选人
+#[derive(Debug, Clone)]
+pub struct GuardianCandidate {
+    id: Uuid,                               // 候选人ID
+    relationship: Relationship,             // 与被监护人的关系
+    has_guardianship_ability: bool,         // 是否具有监护能力
+    consent: Option<GuardianshipAuthority>, // 居民委员会、村民委员会或者民政部门的同意
+}
+
+impl GuardianCandidate {
+    pub fn new(id: Uuid, relationship: Relationship, has_guardianship_ability: bool) -> Self {
+        Self {
+            id,
+            relationship,
+            has_guardianship_ability,
+            consent: None,
+        }
+    }
+
+    /// 愿意担任监护人的组织
+    pub fn organization(id: Uuid, has_guardianship_ability: bool) -> Self {
+        Self::new(id, Relationship::Other, has_guardianship_ability)
+    }
+
+    /// 附上被监护人住所地居民委员会、村民委员会或者民政部门的同意
+    pub fn with_consent(mut self, authority: GuardianshipAuthority) -> Self {
+        self.consent = Some(authority);
+        self
+    }
+
+    /// 获取候选人ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// 获取与被监护人的关系
+    pub fn relationship(&self) -> &Relationship {
+        &self.relationship
+    }
+}
+
+/// 候选人的评估结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum CandidateOutcome {
+    /// 依法定顺序担任监护人
+    Chosen,
+    /// 具有监护资格，但有在先顺序的监护人
+    Deferred,
+    /// 不具有监护资格
+    Rejected,
+}
+
+/// 对单个候选人的评估及理由
+#[derive(Debug, Clone)]
+pub struct CandidateAssessment {
+    candidate: Uuid,
+    relationship: Relationship,
+    rank: u8,
+    outcome: CandidateOutcome,
+    reason: String,
+}
+
+impl CandidateAssessment {
+    /// 获取候选人ID
+    pub fn candidate(&self) -> Uuid {
+        self.candidate
+    }
+
+    /// 获取与被监护人的关系
+    pub fn relationship(&self) -> &Relationship {
+        &self.relationship
+    }
+
+    /// 获取法定顺序中的位次
+    pub fn rank(&self) -> u8 {
+        self.rank
+    }
+
+    /// 获取评估结果
+    pub fn outcome(&self) -> &CandidateOutcome {
+        &self.outcome
+    }
+
+    /// 获取评估理由
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// 依法定顺序确定监护人的结果
+#[derive(Debug, Clone)]
+pub struct GuardianSelection {
+    ward: Uuid,
+    category: WardCategory,
+    assessments: Vec<CandidateAssessment>,
+}
+
+impl GuardianSelection {
+    /// 按法定顺序评估候选人
+    ///
+    /// 同一顺序中具有监护资格的候选人均被选定（如父母共同担任监护人），
+    /// 较后顺序的候选人仅在前一顺序无人具有监护资格时才被选定。
+    /// 第四顺序的其他个人或者组织须经居民委员会、村民委员会或者民政部门同意。
+    pub fn evaluate(
+        ward: Uuid,
+        category: WardCategory,
+        candidates: Vec<GuardianCandidate>,
+    ) -> Self {
+        let article = category.article();
+        let mut assessments: Vec<CandidateAssessment> = candidates
+            .into_iter()
+            .map(|c| {
+                let rank = category.rank(&c.relationship);
+                let (outcome, reason) = if c.id == ward {
+                    (CandidateOutcome::Rejected, "被监护人不能担任自己的监护人".to_string())
+                } else if !c.has_guardianship_ability {
+                    (
+                        CandidateOutcome::Rejected,
+                        format!("不具有监护能力（{}）", article),
+                    )
+                } else if rank == 4
+                    && c
+                        .consent
+                        .as_ref()
+                        .is_none_or(|a| a.public_guardian_id().is_none())
+                {
+                    (
+                        CandidateOutcome::Rejected,
+                        format!(
+                            "其他个人或者组织担任监护人须经居民委员会、村民委员会或者民政部门同意（{}）",
+                            article
+                        ),
+                    )
+                } else {
+                    (CandidateOutcome::Chosen, String::new())
+                };
+                CandidateAssessment {
+                    candidate: c.id,
+                    relationship: c.relationship,
+                    rank,
+                    outcome,
+                    reason,
+                }
+            })
+            .collect();
+
+        let top_rank = assessments
+            .iter()
+            .filter(|a| a.outcome == CandidateOutcome::Chosen)
+            .map(|a| a.rank)
+            .min();
+
+        for a in assessments
+            .iter_mut()
+            .filter(|a| a.outcome == CandidateOutcome::Chosen)
+        {
+            if Some(a.rank) == top_rank {
+                a.reason = format!("第{}顺序中具有监护资格的监护人（{}）", a.rank, article);
+            } else {
+                a.outcome = CandidateOutcome::Deferred;
+                a.reason = format!(
+                    "具有监护资格，但第{}顺序已有监护人（{}）",
+                    top_rank.unwrap_or_default(),
+                    article
+                );
+            }
+        }
+
+        Self {
+            ward,
+            category,
+            assessments,
+        }
+    }
+
+    /// 获取被监护人ID
+    pub fn ward(&self) -> Uuid {
+        self.ward
+    }
+
+    /// 获取被监护人类别
+    pub fn category(&self) -> &WardCategory {
+        &self.category
+    }
+
+    /// 获取全部候选人的评估
+    pub fn assessments(&self) -> &[CandidateAssessment] {
+        &self.assessments
+    }
+
+    /// 依法定顺序选定的监护人
+    pub fn chosen(&self) -> Vec<Uuid> {
+        self.assessments
+            .iter()
+            .filter(|a| a.outcome == CandidateOutcome::Chosen)
+            .map(|a| a.candidate)
+            .collect()
+    }
+
+    /// 是否无人具有监护资格，须由民政部门或居民委员会、村民委员会担任监护人（第三十二条）
+    pub fn requires_public_guardian(&self) -> bool {
+        self.assessments
+            .iter()
+            .all(|a| a.outcome == CandidateOutcome::Rejected)
+    }
+
+    /// 逐条说明每个候选人被选定或不被选定的理由
+    pub fn explain(&self) -> Vec<String> {
+        self.assessments
+            .iter()
+            .map(|a| {
+                format!(
+                    "{}（{:?}）：{:?}，{}",
+                    a.candidate, a.relationship, a.outcome, a.reason
+                )
+            })
+            .collect()
+    }
+
+    /// 为依法定顺序选定的监护人出具担任监护人的依据
+    pub fn basis_for(&self, guardian: Uuid) -> FanResult<GuardianshipBasis> {
+        let assessment = self
+            .assessments
+            .iter()
+            .find(|a| a.candidate == guardian && a.outcome == CandidateOutcome::Chosen)
+            .ok_or_else(|| {
+                FanError::validation_with_ref(
+                    "该候选人不是依法定顺序选定的监护人",
+                    ValidationErrorType::EntityRelationMalformed,
+                    self.category.article(),
+                    "basis_for",
+                    "GuardianSelection",
+                )
+            })?;
+
+        Ok(GuardianshipBasis {
+            ward: self.ward,
+            guardian,
+            source: BasisSource::Statutory(assessment.relationship.clone()),
+            valid_until: None,
+        })
+    }
+
+    /// 对监护人的确定有争议时，由有关组织或人民法院指定监护人（第三十一条）
+    ///
+    /// 须在依法具有监护资格的人中指定；无人具有监护资格时，
+    /// 民政部门或者居民委员会、村民委员会可以指定自身担任监护人（第三十二条）。
+    pub fn designate(
+        &self,
+        authority: GuardianshipAuthority,
+        designated: Uuid,
+        designated_at: DateTime<Utc>,
+        reason: String,
+    ) -> FanResult<Designation> {
+        let qualified = self
+            .assessments
+            .iter()
+            .any(|a| a.candidate == designated && a.outcome != CandidateOutcome::Rejected);
+        let public_guardian =
+            self.requires_public_guardian() && authority.public_guardian_id() == Some(designated);
+
+        if !qualified && !public_guardian {
+            return Err(FanError::validation_with_ref(
+                "只能在依法具有监护资格的人中指定监护人",
+                ValidationErrorType::EntityRelationMalformed,
+                "《民法典》第三十一条、第三十二条",
+                "designate",
+                "GuardianSelection",
+            ));
+        }
+
+        Ok(Designation {
+            ward: self.ward,
+            designated,
+            authority,
+            designated_at,
+            reason,
+        })
+    }
+}
+
+/// 监护人的指定
+#[derive(Debug, Clone)]
+pub struct Designation {
+    ward: Uuid,                       // 被监护人ID
+    designated: Uuid,                 // 被指定的监护人ID
+    authority: GuardianshipAuthority, // 作出指定的组织或法院
+    designated_at: DateTime<Utc>,     // 指定日期
+    reason: String,                   // 指定理由
+}
+
+impl Designation {
+    /// 获取被指定的监护人ID
+    pub fn designated(&self) -> Uuid {
+        self.designated
+    }
+
+    /// 获取作出指定的组织或法院
+    pub fn authority(&self) -> &GuardianshipAuthority {
+        &self.authority
+    }
+
+    /// 获取指定日期
+    pub fn designated_at(&self) -> DateTime<Utc> {
+        self.designated_at
+    }
+
+    /// 获取指定理由
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl From<Designation> for GuardianshipBasis {
+    fn from(designation: Designation) -> Self {
+        Self {
+            ward: designation.ward,
+            guardian: designation.designated,
+            source: BasisSource::Designated(designation),
+            valid_until: None,
+        }
+    }
+}
+
+/// 担任监护人的依据，只能经法定顺序评估或指定取得
+#[derive(Debug, Clone)]
+pub struct GuardianshipBasis {
+    ward: Uuid,
+    guardian: Uuid,
+    source: BasisSource,
+    valid_until: Option<DateTime<Utc>>,
+}
+
+/// 担任监护人的依据来源
+#[derive(Debug, Clone)]
+pub enum BasisSource {
+    /// 依法定顺序担任（第二十七条、第二十八条）
+    Statutory(Relationship),
+    /// 经有关组织或人民法院指定（第三十一条、第三十二条）
+    Designated(Designation),
+}
+
+impl GuardianshipBasis {
+    /// 获取被监护人ID
+    pub fn ward(&self) -> Uuid {
+        self.ward
+    }
+
+    /// 获取监护人ID
+    pub fn guardian(&self) -> Uuid {
+        self.guardian
+    }
+
+    /// 获取依据来源
+    pub fn source(&self) -> &BasisSource {
+        &self.source
+    }
+
+    /// 为监护关系设定期限，如指定临时监护人，期满后监护关系自动终止
+    pub fn until(mut self, valid_until: DateTime<Utc>) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+
+    /// 获取监护关系期限
+    pub fn valid_until(&self) -> Option<DateTime<Utc>> {
+        self.valid_until
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_minor_statutory_order() {
+        let ward = Uuid::new_v4();
+        let father = Uuid::new_v4();
+        let mother = Uuid::new_v4();
+        let grandmother = Uuid::new_v4();
+        let neighbour = Uuid::new_v4();
+
+        let selection = GuardianSelection::evaluate(
+            ward,
+            WardCategory::Minor,
+            vec![
+                GuardianCandidate::new(grandmother, Relationship::Grandparent, true),
+                GuardianCandidate::new(father, Relationship::Parent, true),
+                GuardianCandidate::new(mother, Relationship::Parent, false),
+                GuardianCandidate::new(neighbour, Relationship::Other, true),
+            ],
+        );
+
+        assert_eq!(selection.chosen(), vec![father]);
+        let outcome = |id: Uuid| {
+            selection
+                .assessments()
+                .iter()
+                .find(|a| a.candidate() == id)
+                .unwrap()
+                .outcome()
+                .clone()
+        };
+        assert_eq!(outcome(mother), CandidateOutcome::Rejected);
+        assert_eq!(outcome(grandmother), CandidateOutcome::Deferred);
+        assert_eq!(outcome(neighbour), CandidateOutcome::Rejected);
+        assert_eq!(selection.explain().len(), 4);
+        assert!(selection.basis_for(father).is_ok());
+        assert!(selection.basis_for(grandmother).is_err());
+    }
+
+    #[test]
+    fn test_adult_order_and_consent() {
+        let ward = Uuid::new_v4();
+        let child = Uuid::new_v4();
+        let friend = Uuid::new_v4();
+        let committee = Uuid::new_v4();
+
+        let selection = GuardianSelection::evaluate(
+            ward,
+            WardCategory::IncapacitatedAdult,
+            vec![
+                GuardianCandidate::new(child, Relationship::Child, false),
+                GuardianCandidate::new(friend, Relationship::Other, true)
+                    .with_consent(GuardianshipAuthority::ResidentsCommittee(committee)),
+            ],
+        );
+
+        assert_eq!(selection.chosen(), vec![friend]);
+        assert!(!selection.requires_public_guardian());
+    }
+
+    #[test]
+    fn test_designation() {
+        let at = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let ward = Uuid::new_v4();
+        let father = Uuid::new_v4();
+        let grandfather = Uuid::new_v4();
+        let stranger = Uuid::new_v4();
+        let court = GuardianshipAuthority::Court("北京市海淀区人民法院".to_string());
+
+        let selection = GuardianSelection::evaluate(
+            ward,
+            WardCategory::Minor,
+            vec![
+                GuardianCandidate::new(father, Relationship::Parent, true),
+                GuardianCandidate::new(grandfather, Relationship::Grandparent, true),
+            ],
+        );
+
+        // 可以在具有监护资格的人中指定顺序在后者
+        let designation = selection
+            .designate(
+                court.clone(),
+                grandfather,
+                at,
+                "最有利于被监护人".to_string(),
+            )
+            .unwrap();
+        let basis = GuardianshipBasis::from(designation);
+        assert_eq!(basis.guardian(), grandfather);
+        assert!(matches!(basis.source(), BasisSource::Designated(_)));
+
+        assert!(selection
+            .designate(court, stranger, at, String::new())
+            .is_err());
+
+        // 无人具有监护资格时，由民政部门担任监护人
+        let civil_affairs = Uuid::new_v4();
+        let empty = GuardianSelection::evaluate(ward, WardCategory::Minor, vec![]);
+        assert!(empty.requires_public_guardian());
+        assert!(empty
+            .designate(
+                GuardianshipAuthority::CivilAffairsDepartment(civil_affairs),
+                civil_affairs,
+                at,
+                "没有依法具有监护资格的人".to_string(),
+            )
+            .is_ok());
+    }
+}
@@ -0,0 +1,678 @@
+//! 个体工商户和农村承包经营户
+//! 对应《民法典》第五十四条至第五十六条：自然人从事工商业经营，经依法登记，为个体工商户，可以起字号；
+//! 农村集体经济组织的成员依法取得农村土地承包经营权，从事家庭承包经营的，为农村承包经营户。
+//! 二者仍是自然人，以经营者个人或者家庭的财产承担债务。
+//! 户以共享引用持有经营者，经营者此后的行为能力认定、宣告失踪或者宣告死亡均反映在户上。
+
+use crate::core::clock::{default_clock, Clock};
+use crate::core::entity::base::{BaseEntity, CapacityStatus, Entity, EntityType};
+use crate::core::entity::guardianship::Guardianship;
+use crate::core::entity::licence::{revoke_licence_in, Licence};
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 户的类型
+#[derive(Debug, Clone, PartialEq)]
+pub enum HouseholdType {
+    IndustrialCommercial, // 个体工商户
+    RuralContracting,     // 农村承包经营户
+}
+
+/// 经营方式，决定以谁的财产承担债务（第五十六条）
+#[derive(Debug, Clone, PartialEq)]
+pub enum OperationMode {
+    /// 个人经营的，以个人财产承担
+    Individual,
+    /// 家庭经营的，以家庭财产承担
+    Family,
+    /// 无法区分个人经营还是家庭经营的，以家庭财产承担
+    Indistinguishable,
+    /// 农村承包经营户事实上由农户部分成员经营的，以该部分成员的财产承担
+    PartOfHousehold(Vec<Uuid>),
+}
+
+/// 个体工商户或者农村承包经营户
+///
+/// 经营者须为具有完全民事行为能力的自然人，户以经营者的名义并通过经营者实施民事法律行为，
+/// 其行为能力即经营者的行为能力。
+#[derive(Debug, Clone)]
+pub struct Household {
+    base: BaseEntity,
+    household_type: HouseholdType,
+    trade_name: Option<String>,    // 字号（个体工商户特有）
+    operator: Arc<dyn Entity>,     // 经营者，农村承包经营户为承包方代表
+    family_members: Vec<Uuid>,     // 经营者以外的家庭成员
+    operation_mode: OperationMode, // 经营方式
+    licences: Vec<Licence>,        // 持有的许可证
+}
+
+impl Household {
+    pub fn new(
+        household_type: HouseholdType,
+        operator: Arc<dyn Entity>,
+        trade_name: Option<String>,
+    ) -> FanResult<Self> {
+        Self::with_clock(household_type, operator, trade_name, default_clock())
+    }
+
+    /// 使用指定时钟创建个体工商户或者农村承包经营户
+    pub fn with_clock(
+        household_type: HouseholdType,
+        operator: Arc<dyn Entity>,
+        trade_name: Option<String>,
+        clock: Arc<dyn Clock>,
+    ) -> FanResult<Self> {
+        check_household(&household_type, operator.as_ref(), &trade_name, clock.now())?;
+        let operation_mode = default_operation_mode(&household_type);
+        Ok(Self {
+            base: BaseEntity::new(EntityType::Household, operator.capacity_status(), clock),
+            household_type,
+            trade_name,
+            operator,
+            family_members: Vec::new(),
+            operation_mode,
+            licences: Vec::new(),
+        })
+    }
+
+    /// 添加家庭成员
+    pub fn add_family_member(&mut self, member: &dyn Entity) -> FanResult<()> {
+        check_family_member(self.operator.id(), &self.family_members, member)?;
+        self.family_members.push(member.id());
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 设置经营方式
+    pub fn set_operation_mode(&mut self, mode: OperationMode) -> FanResult<()> {
+        check_operation_mode(
+            &self.household_type,
+            self.operator.id(),
+            &self.family_members,
+            &mode,
+        )?;
+        self.operation_mode = mode;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 取得许可证
+    pub fn add_licence(&mut self, licence: Licence) -> FanResult<()> {
+        self.licences.push(licence);
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 许可证被吊销或撤销，此后不能再依该许可证从事许可经营的项目
+    pub fn revoke_licence(&mut self, number: &str, at: DateTime<Utc>) -> FanResult<()> {
+        revoke_licence_in(&mut self.licences, number, at)?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 获取户的类型
+    pub fn household_type(&self) -> &HouseholdType {
+        &self.household_type
+    }
+
+    /// 获取字号
+    pub fn trade_name(&self) -> Option<&str> {
+        self.trade_name.as_deref()
+    }
+
+    /// 获取经营者
+    pub fn operator(&self) -> Arc<dyn Entity> {
+        self.operator.clone()
+    }
+
+    /// 获取经营者以外的家庭成员
+    pub fn family_members(&self) -> &[Uuid] {
+        &self.family_members
+    }
+
+    /// 获取经营方式
+    pub fn operation_mode(&self) -> &OperationMode {
+        &self.operation_mode
+    }
+
+    /// 以其财产承担本户债务的自然人
+    pub fn debt_bearers(&self) -> Vec<Uuid> {
+        debt_bearers(
+            self.operator.id(),
+            &self.family_members,
+            &self.operation_mode,
+        )
+    }
+}
+
+impl Entity for Household {
+    fn id(&self) -> Uuid {
+        self.base.id
+    }
+    fn entity_type(&self) -> EntityType {
+        self.base.entity_type.clone()
+    }
+    fn capacity_status(&self) -> CapacityStatus {
+        self.operator.capacity_status()
+    }
+    fn created_at(&self) -> DateTime<Utc> {
+        self.base.created_at
+    }
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.base.updated_at
+    }
+
+    fn has_capacity(&self) -> bool {
+        self.operator.has_capacity()
+    }
+
+    fn capacity_status_at(&self, at: DateTime<Utc>) -> CapacityStatus {
+        self.operator.capacity_status_at(at)
+    }
+
+    fn has_capacity_at(&self, at: DateTime<Utc>) -> bool {
+        self.operator.has_capacity_at(at)
+    }
+
+    fn guardianships_at(&self, at: DateTime<Utc>) -> Vec<Guardianship> {
+        self.operator.guardianships_at(at)
+    }
+
+    fn is_presumed_dead_at(&self, at: DateTime<Utc>) -> bool {
+        self.operator.is_presumed_dead_at(at)
+    }
+
+    fn licences(&self) -> Vec<Licence> {
+        self.licences.clone()
+    }
+
+    fn liability_bearers(&self) -> Vec<Uuid> {
+        self.debt_bearers()
+    }
+}
+
+/// 线程安全版本的个体工商户或者农村承包经营户
+#[derive(Debug)]
+pub struct SyncHousehold {
+    base: Arc<RwLock<BaseEntity>>,
+    household_type: HouseholdType, // 不可变
+    trade_name: Option<String>,    // 不可变
+    operator: Arc<dyn Entity>,     // 不可变
+    family_members: Arc<RwLock<Vec<Uuid>>>,
+    operation_mode: Arc<RwLock<OperationMode>>,
+    licences: Arc<RwLock<Vec<Licence>>>, // 持有的许可证
+}
+
+impl SyncHousehold {
+    pub fn new(
+        household_type: HouseholdType,
+        operator: Arc<dyn Entity>,
+        trade_name: Option<String>,
+    ) -> FanResult<Self> {
+        Self::with_clock(household_type, operator, trade_name, default_clock())
+    }
+
+    /// 使用指定时钟创建个体工商户或者农村承包经营户
+    pub fn with_clock(
+        household_type: HouseholdType,
+        operator: Arc<dyn Entity>,
+        trade_name: Option<String>,
+        clock: Arc<dyn Clock>,
+    ) -> FanResult<Self> {
+        check_household(&household_type, operator.as_ref(), &trade_name, clock.now())?;
+        let operation_mode = default_operation_mode(&household_type);
+        Ok(Self {
+            base: Arc::new(RwLock::new(BaseEntity::new(
+                EntityType::Household,
+                operator.capacity_status(),
+                clock,
+            ))),
+            household_type,
+            trade_name,
+            operator,
+            family_members: Arc::new(RwLock::new(Vec::new())),
+            operation_mode: Arc::new(RwLock::new(operation_mode)),
+            licences: Arc::new(RwLock::new(Vec::new())),
+        })
+    }
+
+    pub fn from_household(household: Household) -> Self {
+        Self {
+            base: Arc::new(RwLock::new(household.base)),
+            household_type: household.household_type,
+            trade_name: household.trade_name,
+            operator: household.operator,
+            family_members: Arc::new(RwLock::new(household.family_members)),
+            operation_mode: Arc::new(RwLock::new(household.operation_mode)),
+            licences: Arc::new(RwLock::new(household.licences)),
+        }
+    }
+
+    pub fn add_family_member(&self, member: &dyn Entity) -> FanResult<()> {
+        let mut family_members = self.family_members.write();
+        check_family_member(self.operator.id(), &family_members, member)?;
+        family_members.push(member.id());
+        drop(family_members); // 释放写锁
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn set_operation_mode(&self, mode: OperationMode) -> FanResult<()> {
+        check_operation_mode(
+            &self.household_type,
+            self.operator.id(),
+            &self.family_members.read(),
+            &mode,
+        )?;
+        *self.operation_mode.write() = mode;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn add_licence(&self, licence: Licence) -> FanResult<()> {
+        self.licences.write().push(licence);
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn revoke_licence(&self, number: &str, at: DateTime<Utc>) -> FanResult<()> {
+        revoke_licence_in(&mut self.licences.write(), number, at)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn household_type(&self) -> &HouseholdType {
+        &self.household_type
+    }
+
+    pub fn trade_name(&self) -> Option<&str> {
+        self.trade_name.as_deref()
+    }
+
+    pub fn operator(&self) -> Arc<dyn Entity> {
+        self.operator.clone()
+    }
+
+    pub fn family_members(&self) -> Vec<Uuid> {
+        self.family_members.read().clone()
+    }
+
+    pub fn operation_mode(&self) -> OperationMode {
+        self.operation_mode.read().clone()
+    }
+
+    pub fn debt_bearers(&self) -> Vec<Uuid> {
+        debt_bearers(
+            self.operator.id(),
+            &self.family_members.read(),
+            &self.operation_mode.read(),
+        )
+    }
+}
+
+impl Entity for SyncHousehold {
+    fn id(&self) -> Uuid {
+        self.base.read().id
+    }
+
+    fn entity_type(&self) -> EntityType {
+        self.base.read().entity_type.clone()
+    }
+
+    fn capacity_status(&self) -> CapacityStatus {
+        self.operator.capacity_status()
+    }
+
+    fn created_at(&self) -> DateTime<Utc> {
+        self.base.read().created_at
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        self.base.read().updated_at
+    }
+
+    fn has_capacity(&self) -> bool {
+        self.operator.has_capacity()
+    }
+
+    fn capacity_status_at(&self, at: DateTime<Utc>) -> CapacityStatus {
+        self.operator.capacity_status_at(at)
+    }
+
+    fn has_capacity_at(&self, at: DateTime<Utc>) -> bool {
+        self.operator.has_capacity_at(at)
+    }
+
+    fn guardianships_at(&self, at: DateTime<Utc>) -> Vec<Guardianship> {
+        self.operator.guardianships_at(at)
+    }
+
+    fn is_presumed_dead_at(&self, at: DateTime<Utc>) -> bool {
+        self.operator.is_presumed_dead_at(at)
+    }
+
+    fn licences(&self) -> Vec<Licence> {
+        self.licences.read().clone()
+    }
+
+    fn liability_bearers(&self) -> Vec<Uuid> {
+        self.debt_bearers()
+    }
+}
+
+/// 经营者须为具有完全民事行为能力的自然人，只有个体工商户可以起字号
+fn check_household(
+    household_type: &HouseholdType,
+    operator: &dyn Entity,
+    trade_name: &Option<String>,
+    at: DateTime<Utc>,
+) -> FanResult<()> {
+    if operator.entity_type() != EntityType::NaturalPerson || !operator.has_capacity_at(at) {
+        return Err(FanError::validation_with_ref(
+            "经营者须为具有完全民事行为能力的自然人",
+            ValidationErrorType::EntityCapacityLacking,
+            "《民法典》第五十四条、第五十五条",
+            "new",
+            "Household",
+        ));
+    }
+    if trade_name.is_some() && *household_type != HouseholdType::IndustrialCommercial {
+        return Err(FanError::validation_with_ref(
+            "只有个体工商户可以起字号",
+            ValidationErrorType::EntityError,
+            "《民法典》第五十四条",
+            "new",
+            "Household",
+        ));
+    }
+    Ok(())
+}
+
+/// 个体工商户推定为个人经营，农村承包经营户以农户为单位经营
+fn default_operation_mode(household_type: &HouseholdType) -> OperationMode {
+    match household_type {
+        HouseholdType::IndustrialCommercial => OperationMode::Individual,
+        HouseholdType::RuralContracting => OperationMode::Family,
+    }
+}
+
+fn check_family_member(
+    operator: Uuid,
+    family_members: &[Uuid],
+    member: &dyn Entity,
+) -> FanResult<()> {
+    if member.entity_type() != EntityType::NaturalPerson
+        || member.id() == operator
+        || family_members.contains(&member.id())
+    {
+        return Err(FanError::validation(
+            "家庭成员须为经营者以外的自然人，且不得重复添加",
+            ValidationErrorType::EntityRelationMalformed,
+            "add_family_member",
+            "Household",
+        ));
+    }
+    Ok(())
+}
+
+fn check_operation_mode(
+    household_type: &HouseholdType,
+    operator: Uuid,
+    family_members: &[Uuid],
+    mode: &OperationMode,
+) -> FanResult<()> {
+    let valid = match (household_type, mode) {
+        (HouseholdType::IndustrialCommercial, OperationMode::PartOfHousehold(_)) => false,
+        (HouseholdType::RuralContracting, OperationMode::PartOfHousehold(members)) => {
+            !members.is_empty()
+                && members
+                    .iter()
+                    .all(|m| *m == operator || family_members.contains(m))
+        }
+        (HouseholdType::RuralContracting, OperationMode::Individual)
+        | (HouseholdType::RuralContracting, OperationMode::Indistinguishable) => false,
+        _ => true,
+    };
+    if !valid {
+        return Err(FanError::validation_with_ref(
+            "个体工商户为个人经营或者家庭经营，农村承包经营户由农户或者其部分成员经营",
+            ValidationErrorType::EntityRelationMalformed,
+            "《民法典》第五十六条",
+            "set_operation_mode",
+            "Household",
+        ));
+    }
+    Ok(())
+}
+
+/// 个人经营的以个人财产承担，家庭经营或者无法区分的以家庭财产承担，部分成员经营的以该部分成员的财产承担
+fn debt_bearers(operator: Uuid, family_members: &[Uuid], mode: &OperationMode) -> Vec<Uuid> {
+    match mode {
+        OperationMode::Individual => vec![operator],
+        OperationMode::Family | OperationMode::Indistinguishable => std::iter::once(operator)
+            .chain(family_members.iter().copied())
+            .collect(),
+        OperationMode::PartOfHousehold(members) => members.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::base::BaseContract;
+    use crate::core::entity::IndustryCode;
+    use crate::core::entity::SuccessionRegistry;
+    use crate::core::entity::{AdjudicationKind, Applicant, CapacityAdjudication};
+    use crate::core::entity::{CompanyType, LegalPerson, LegalPersonType, NaturalCapacity};
+    use crate::core::entity::{NaturalPerson, SyncNaturalPerson};
+    use crate::core::money::Money;
+    use crate::testing::{fixed_clock, person};
+    use chrono::{Duration, TimeZone};
+    use rust_decimal::Decimal;
+
+    fn shop(operator: &NaturalPerson) -> Household {
+        Household::with_clock(
+            HouseholdType::IndustrialCommercial,
+            Arc::new(operator.clone()),
+            Some("老王面馆".to_string()),
+            fixed_clock(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_operator_must_have_full_capacity() {
+        let minor = Arc::new(person(2013));
+        assert!(Household::with_clock(
+            HouseholdType::IndustrialCommercial,
+            minor,
+            None,
+            fixed_clock()
+        )
+        .is_err());
+
+        let company = LegalPerson::with_clock(
+            LegalPersonType::Company(CompanyType::Limited),
+            Money::cny(Decimal::from(1_000_000)),
+            Uuid::new_v4(),
+            "北京市朝阳区xxx路".to_string(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+            fixed_clock(),
+        );
+        assert!(Household::with_clock(
+            HouseholdType::IndustrialCommercial,
+            Arc::new(company),
+            None,
+            fixed_clock(),
+        )
+        .is_err());
+
+        let household = shop(&person(1985));
+        assert_eq!(household.entity_type(), EntityType::Household);
+        assert!(household.has_capacity());
+    }
+
+    #[test]
+    fn test_only_industrial_commercial_household_has_trade_name() {
+        assert!(Household::with_clock(
+            HouseholdType::RuralContracting,
+            Arc::new(person(1975)),
+            Some("字号".to_string()),
+            fixed_clock(),
+        )
+        .is_err());
+        assert_eq!(shop(&person(1985)).trade_name(), Some("老王面馆"));
+    }
+
+    #[test]
+    fn test_household_follows_operator_capacity() {
+        let operator = SyncNaturalPerson::from_natural_person(person(1985));
+        let farm = SyncHousehold::with_clock(
+            HouseholdType::RuralContracting,
+            Arc::new(operator.clone()),
+            None,
+            fixed_clock(),
+        )
+        .unwrap();
+        assert!(farm.has_capacity());
+
+        // 经营者此后被认定为无民事行为能力的，户随之丧失行为能力
+        operator
+            .adjudicate_capacity(
+                CapacityAdjudication::new(
+                    AdjudicationKind::Incapacity,
+                    Applicant::InterestedParty(Uuid::new_v4()),
+                    "北京市海淀区人民法院".to_string(),
+                    Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+                    NaturalCapacity::None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert!(!farm.has_capacity());
+        assert!(farm.has_capacity_at(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()));
+        assert!(!farm.operator().has_capacity());
+    }
+
+    #[test]
+    fn test_sync_household_licence_revocation() {
+        let household = SyncHousehold::from_household(shop(&person(1985)));
+        let catering = IndustryCode::new("62").unwrap();
+        household
+            .add_licence(
+                Licence::new(
+                    "食品经营许可证".to_string(),
+                    "北京市朝阳区市场监督管理局".to_string(),
+                    "JY11105000000000".to_string(),
+                    vec![catering.clone()],
+                    Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+                    None,
+                )
+                .unwrap(),
+            )
+            .unwrap();
+
+        // 吊销之日起不再许可从事该行业
+        let revoked = Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap();
+        assert!(household
+            .revoke_licence("JY11105000000001", revoked)
+            .is_err());
+        household
+            .revoke_licence("JY11105000000000", revoked)
+            .unwrap();
+        assert!(household.licences()[0].permits_at(&catering, revoked - Duration::days(1)));
+        assert!(!household.licences()[0].permits_at(&catering, revoked));
+    }
+
+    #[test]
+    fn test_family_members_are_other_natural_persons() {
+        let operator = person(1985);
+        let mut household = shop(&operator);
+        household.add_family_member(&person(1987)).unwrap();
+        assert!(household.add_family_member(&operator).is_err());
+        assert!(household.add_family_member(&household.clone()).is_err());
+    }
+
+    #[test]
+    fn test_individual_and_family_operation() {
+        let operator = person(1985);
+        let spouse = person(1987);
+        let mut household = shop(&operator);
+        household.add_family_member(&spouse).unwrap();
+
+        // 个人经营的以个人财产承担，家庭经营的以家庭财产承担
+        assert_eq!(household.debt_bearers(), vec![operator.id()]);
+        household.set_operation_mode(OperationMode::Family).unwrap();
+        assert_eq!(household.debt_bearers(), vec![operator.id(), spouse.id()]);
+        household
+            .set_operation_mode(OperationMode::Indistinguishable)
+            .unwrap();
+        assert_eq!(household.debt_bearers(), vec![operator.id(), spouse.id()]);
+        assert!(household
+            .set_operation_mode(OperationMode::PartOfHousehold(vec![spouse.id()]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_rural_household_operated_by_part_of_members() {
+        let son = person(2000);
+        let daughter = person(2005);
+        let farm = SyncHousehold::with_clock(
+            HouseholdType::RuralContracting,
+            Arc::new(person(1975)),
+            None,
+            fixed_clock(),
+        )
+        .unwrap();
+        farm.add_family_member(&son).unwrap();
+        farm.add_family_member(&daughter).unwrap();
+        assert_eq!(farm.debt_bearers().len(), 3);
+
+        // 事实上由部分成员经营的，以该部分成员的财产承担
+        farm.set_operation_mode(OperationMode::PartOfHousehold(vec![son.id()]))
+            .unwrap();
+        assert_eq!(farm.liability_bearers(), vec![son.id()]);
+        assert!(farm.set_operation_mode(OperationMode::Individual).is_err());
+        assert!(farm
+            .set_operation_mode(OperationMode::PartOfHousehold(vec![Uuid::new_v4()]))
+            .is_err());
+    }
+
+    #[test]
+    #[allow(clippy::arc_with_non_send_sync)] // 经营者以 Arc<dyn Entity> 共享，合同当事人同样不跨线程
+    fn test_household_as_contract_party() {
+        let operator = person(1985);
+        let spouse = person(1987);
+        let mut household = shop(&operator);
+        household.add_family_member(&spouse).unwrap();
+        household.set_operation_mode(OperationMode::Family).unwrap();
+
+        // 个体工商户可以作为合同当事人，其债务由经营者及家庭成员承担
+        let supplier = LegalPerson::with_clock(
+            LegalPersonType::Company(CompanyType::Limited),
+            Money::cny(Decimal::from(1_000_000)),
+            Uuid::new_v4(),
+            "北京市朝阳区xxx路".to_string(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+            fixed_clock(),
+        );
+        let household_id = household.id();
+        let contract = BaseContract::with_clock(
+            vec![Arc::new(household), Arc::new(supplier)],
+            vec![],
+            vec![],
+            None,
+            fixed_clock(),
+        );
+        assert_eq!(
+            contract
+                .liable_entities(household_id, &SuccessionRegistry::new())
+                .unwrap(),
+            vec![household_id, operator.id(), spouse.id()]
+        );
+    }
+}
@@ -0,0 +1,678 @@
+//! 国民经济行业分类
+//! 依《国民经济行业分类》（GB/T 4754—2017）以代码表示经营范围：门类以一个字母表示，大类、中类、小类分别以两位、三位、四位数字表示。
+//! 经营范围中登记的行业涵盖其下属的全部行业，如登记"软件开发"（651）即可从事"应用软件开发"（6513）。
+//! 行业还区分一般经营项目、许可经营项目和禁止经营项目，许可与禁止的规定同样适用于下属行业。
+
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// 大类所属的门类，按大类代码的区间划分
+const SECTIONS: &[(char, u8, u8)] = &[
+    ('A', 1, 5),
+    ('B', 6, 12),
+    ('C', 13, 43),
+    ('D', 44, 46),
+    ('E', 47, 50),
+    ('F', 51, 52),
+    ('G', 53, 60),
+    ('H', 61, 62),
+    ('I', 63, 65),
+    ('J', 66, 69),
+    ('K', 70, 70),
+    ('L', 71, 72),
+    ('M', 73, 75),
+    ('N', 76, 79),
+    ('O', 80, 82),
+    ('P', 83, 83),
+    ('Q', 84, 85),
+    ('R', 86, 90),
+    ('S', 91, 97),
+    ('T', 98, 98),
+];
+
+/// 标准目录收录的行业：代码、名称及常用的经营范围表述
+const STANDARD_ENTRIES: &[(&str, &str, &[&str])] = &[
+    ("A", "农、林、牧、渔业", &[]),
+    ("01", "农业", &["种植"]),
+    ("C", "制造业", &[]),
+    ("13", "农副食品加工业", &[]),
+    ("27", "医药制造业", &["药品生产"]),
+    ("39", "计算机、通信和其他电子设备制造业", &["电子产品制造"]),
+    ("E", "建筑业", &[]),
+    ("47", "房屋建筑业", &["房屋建筑"]),
+    ("F", "批发和零售业", &[]),
+    ("51", "批发业", &["批发"]),
+    ("517", "机械设备、五金产品及电子产品批发", &[]),
+    ("5176", "计算机、软件及辅助设备批发", &["软件批发"]),
+    ("52", "零售业", &["零售"]),
+    ("525", "医药及医疗器材专门零售", &[]),
+    ("5251", "西药零售", &["药品零售"]),
+    ("527", "家用电器及电子产品专门零售", &[]),
+    (
+        "5273",
+        "计算机、软件及辅助设备零售",
+        &["软件销售", "计算机销售"],
+    ),
+    ("G", "交通运输、仓储和邮政业", &[]),
+    ("54", "道路运输业", &["道路运输"]),
+    ("H", "住宿和餐饮业", &[]),
+    ("61", "住宿业", &["住宿服务"]),
+    ("62", "餐饮业", &["餐饮服务", "餐饮"]),
+    ("I", "信息传输、软件和信息技术服务业", &[]),
+    ("63", "电信、广播电视和卫星传输服务", &["电信业务"]),
+    ("64", "互联网和相关服务", &["互联网信息服务"]),
+    ("65", "软件和信息技术服务业", &[]),
+    ("651", "软件开发", &["计算机软件开发"]),
+    ("6511", "基础软件开发", &[]),
+    ("6512", "支撑软件开发", &[]),
+    ("6513", "应用软件开发", &[]),
+    ("6519", "其他软件开发", &[]),
+    ("652", "集成电路设计", &[]),
+    ("653", "信息系统集成和物联网技术服务", &["信息系统集成"]),
+    ("654", "运行维护服务", &[]),
+    ("656", "信息技术咨询服务", &["技术咨询"]),
+    ("J", "金融业", &[]),
+    ("66", "货币金融服务", &["吸收公众存款", "发放贷款"]),
+    ("67", "资本市场服务", &["证券经纪"]),
+    ("68", "保险业", &["保险业务"]),
+    ("K", "房地产业", &[]),
+    ("70", "房地产业", &[]),
+    ("701", "房地产开发经营", &["房地产开发"]),
+    ("702", "物业管理", &[]),
+    ("703", "房地产中介服务", &["房地产经纪"]),
+    ("L", "租赁和商务服务业", &[]),
+    ("72", "商务服务业", &[]),
+    ("721", "组织管理服务", &[]),
+    ("7212", "投资与资产管理", &["清理财产", "分配剩余财产"]),
+    ("723", "法律服务", &[]),
+    ("7231", "律师及相关法律服务", &["参与诉讼"]),
+    (
+        "7239",
+        "其他法律服务",
+        &["清算事务", "通知债权人", "清偿债务", "处理未了结业务"],
+    ),
+    ("724", "咨询与调查", &[]),
+    (
+        "7241",
+        "会计、审计及税务服务",
+        &["编制资产负债表", "清缴税款"],
+    ),
+    ("729", "其他商务服务业", &[]),
+    ("7295", "信用服务", &["催收债权"]),
+    ("M", "科学研究和技术服务业", &[]),
+    ("73", "研究和试验发展", &["技术开发"]),
+    ("P", "教育", &[]),
+    ("83", "教育", &["教育培训"]),
+    ("Q", "卫生和社会工作", &[]),
+    ("84", "卫生", &["医疗服务"]),
+    ("R", "文化、体育和娱乐业", &[]),
+    ("86", "新闻和出版业", &["出版物出版"]),
+    ("861", "新闻业", &["新闻采编"]),
+];
+
+/// 标准目录中须经许可才能经营的行业及所需的许可证
+const STANDARD_LICENCES: &[(&str, &str)] = &[
+    ("27", "药品生产许可证"),
+    ("5251", "药品经营许可证"),
+    ("54", "道路运输经营许可证"),
+    ("62", "食品经营许可证"),
+    ("63", "电信业务经营许可证"),
+    ("66", "金融许可证"),
+    ("67", "经营证券期货业务许可证"),
+    ("68", "保险许可证"),
+    ("701", "房地产开发企业资质证书"),
+    ("83", "办学许可证"),
+    ("84", "医疗机构执业许可证"),
+    ("86", "出版许可证"),
+];
+
+/// 标准目录中禁止经营的行业（市场准入负面清单中非公有资本禁止从事新闻采编播发业务）
+const STANDARD_PROHIBITIONS: &[&str] = &["861"];
+
+/// 经营范围中并列各分项的分隔符，较长的在前
+const SEPARATORS: &[&str] = &["以及", "、", "，", ",", "；", ";", "及", "和", "与"];
+
+/// 经营范围表述对应到行业代码的结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActivityResolution {
+    /// 表述所列的全部行业
+    Resolved(Vec<IndustryCode>),
+    /// `term` 可以对应多个互不涵盖的行业，须以更明确的表述或行业代码登记
+    Ambiguous {
+        term: String,
+        candidates: Vec<IndustryCode>,
+    },
+    /// `term` 无法对应到目录中的行业
+    Unrecognised { term: String },
+}
+
+impl ActivityResolution {
+    /// 表述所列的全部行业，无法确定的为空
+    pub fn codes(&self) -> Option<&[IndustryCode]> {
+        match self {
+            ActivityResolution::Resolved(codes) => Some(codes),
+            _ => None,
+        }
+    }
+}
+
+/// 行业的经营管理方式
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActivityRegulation {
+    /// 一般经营项目，依法自主开展
+    General,
+    /// 许可经营项目，须取得相应许可证，`licence` 为许可证的种类
+    LicenceRequired { licence: String },
+    /// 禁止经营项目
+    Prohibited,
+}
+
+/// 行业代码的层级
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndustryLevel {
+    Section,  // 门类
+    Division, // 大类
+    Group,    // 中类
+    Class,    // 小类
+}
+
+/// 国民经济行业代码
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IndustryCode(String);
+
+impl IndustryCode {
+    /// 解析行业代码：门类为 A 至 T 的一个字母，大类、中类、小类为以有效大类开头的二至四位数字
+    pub fn new(code: &str) -> FanResult<Self> {
+        let valid = match code.len() {
+            1 => SECTIONS.iter().any(|(s, _, _)| code.starts_with(*s)),
+            2..=4 => {
+                code.bytes().all(|b| b.is_ascii_digit())
+                    && section_of_division(&code[..2]).is_some()
+            }
+            _ => false,
+        };
+        if !valid {
+            return Err(FanError::validation(
+                format!("无效的国民经济行业代码：{}", code),
+                ValidationErrorType::EntityError,
+                "parse_industry_code",
+                "IndustryCode",
+            ));
+        }
+        Ok(Self(code.to_string()))
+    }
+
+    /// 获取代码
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// 代码的层级
+    pub fn level(&self) -> IndustryLevel {
+        match self.0.len() {
+            1 => IndustryLevel::Section,
+            2 => IndustryLevel::Division,
+            3 => IndustryLevel::Group,
+            _ => IndustryLevel::Class,
+        }
+    }
+
+    /// 上一级行业，门类没有上级
+    pub fn parent(&self) -> Option<IndustryCode> {
+        match self.0.len() {
+            1 => None,
+            2 => section_of_division(&self.0).map(|s| IndustryCode(s.to_string())),
+            len => Some(IndustryCode(self.0[..len - 1].to_string())),
+        }
+    }
+
+    /// 本行业及其全部上级行业，由近及远
+    pub fn ancestors(&self) -> Vec<IndustryCode> {
+        let mut chain = vec![self.clone()];
+        while let Some(parent) = chain.last().and_then(|c| c.parent()) {
+            chain.push(parent);
+        }
+        chain
+    }
+
+    /// 本行业是否涵盖另一行业，即为其本身或上级行业
+    pub fn covers(&self, other: &IndustryCode) -> bool {
+        other.ancestors().contains(self)
+    }
+}
+
+impl Display for IndustryCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn section_of_division(division: &str) -> Option<char> {
+    let number: u8 = division.parse().ok()?;
+    SECTIONS
+        .iter()
+        .find(|(_, from, to)| (*from..=*to).contains(&number))
+        .map(|(section, _, _)| *section)
+}
+
+/// 目录中的一个行业
+#[derive(Debug, Clone)]
+pub struct IndustryEntry {
+    code: IndustryCode,                     // 行业代码
+    name: String,                           // 行业名称
+    aliases: Vec<String>,                   // 经营范围中常用的表述
+    regulation: Option<ActivityRegulation>, // 对本行业的专门规定，未规定的适用上级行业的规定
+}
+
+impl IndustryEntry {
+    /// 获取行业代码
+    pub fn code(&self) -> &IndustryCode {
+        &self.code
+    }
+
+    /// 获取行业名称
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 获取常用表述
+    pub fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    /// 获取对本行业的专门规定
+    pub fn regulation(&self) -> Option<&ActivityRegulation> {
+        self.regulation.as_ref()
+    }
+}
+
+/// 行业分类目录，用于将经营范围的文字表述对应到行业代码
+#[derive(Debug, Clone, Default)]
+pub struct IndustryCatalogue {
+    entries: HashMap<IndustryCode, IndustryEntry>,
+}
+
+impl IndustryCatalogue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 标准目录，收录常见的门类、大类及其下属行业
+    pub fn standard() -> &'static IndustryCatalogue {
+        static STANDARD: OnceLock<IndustryCatalogue> = OnceLock::new();
+        STANDARD.get_or_init(|| {
+            let mut catalogue = IndustryCatalogue::new();
+            for (code, name, aliases) in STANDARD_ENTRIES {
+                catalogue
+                    .add_entry(
+                        IndustryCode::new(code).expect("标准目录的代码有效"),
+                        name.to_string(),
+                        aliases.iter().map(|a| a.to_string()).collect(),
+                    )
+                    .expect("标准目录的上级行业先于下级收录");
+            }
+            let licensed = STANDARD_LICENCES.iter().map(|(code, licence)| {
+                let regulation = ActivityRegulation::LicenceRequired {
+                    licence: licence.to_string(),
+                };
+                (*code, regulation)
+            });
+            let prohibited = STANDARD_PROHIBITIONS
+                .iter()
+                .map(|code| (*code, ActivityRegulation::Prohibited));
+            for (code, regulation) in licensed.chain(prohibited) {
+                catalogue
+                    .set_regulation(&IndustryCode(code.to_string()), regulation)
+                    .expect("标准目录的许可与禁止事项均已收录");
+            }
+            catalogue
+        })
+    }
+
+    /// 收录一个行业，其上级行业必须已经收录
+    pub fn add_entry(
+        &mut self,
+        code: IndustryCode,
+        name: String,
+        aliases: Vec<String>,
+    ) -> FanResult<()> {
+        if code
+            .parent()
+            .is_some_and(|p| !self.entries.contains_key(&p))
+        {
+            return Err(FanError::validation(
+                format!("行业 {} 的上级行业尚未收录", code),
+                ValidationErrorType::EntityRelationMalformed,
+                "add_entry",
+                "IndustryCatalogue",
+            ));
+        }
+        self.entries.insert(
+            code.clone(),
+            IndustryEntry {
+                code,
+                name,
+                aliases,
+                regulation: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// 规定某一行业的经营管理方式
+    pub fn set_regulation(
+        &mut self,
+        code: &IndustryCode,
+        regulation: ActivityRegulation,
+    ) -> FanResult<()> {
+        let entry = self.entries.get_mut(code).ok_or_else(|| {
+            FanError::validation(
+                format!("行业 {} 尚未收录", code),
+                ValidationErrorType::EntityRelationMalformed,
+                "set_regulation",
+                "IndustryCatalogue",
+            )
+        })?;
+        entry.regulation = Some(regulation);
+        Ok(())
+    }
+
+    /// 某一行业的经营管理方式，适用本行业或最近的上级行业的规定，均未规定的为一般经营项目
+    pub fn regulation(&self, code: &IndustryCode) -> ActivityRegulation {
+        code.ancestors()
+            .iter()
+            .find_map(|c| self.entries.get(c).and_then(|e| e.regulation.clone()))
+            .unwrap_or(ActivityRegulation::General)
+    }
+
+    /// 按代码查找行业
+    pub fn get(&self, code: &IndustryCode) -> Option<&IndustryEntry> {
+        self.entries.get(code)
+    }
+
+    /// 某一行业的直接下级行业
+    pub fn children(&self, code: &IndustryCode) -> Vec<&IndustryEntry> {
+        let mut children: Vec<&IndustryEntry> = self
+            .entries
+            .values()
+            .filter(|e| e.code.parent().as_ref() == Some(code))
+            .collect();
+        children.sort_by(|a, b| a.code.0.cmp(&b.code.0));
+        children
+    }
+
+    /// 将经营范围的表述对应到行业代码
+    ///
+    /// 整个表述为行业代码、行业名称或常用表述的，直接对应；否则按"、""及"等分隔符拆分为并列的分项，
+    /// 每一分项都须对应到行业。省略了宾语的分项沿用前一分项的宾语，如"计算机软件开发及销售"
+    /// 对应"计算机软件开发"（651）和"计算机销售"（5273）。任一分项无法对应或对应多个行业的，不作推测。
+    pub fn resolve(&self, activity: &str) -> ActivityResolution {
+        let activity = activity.trim();
+        if let Ok(code) = IndustryCode::new(activity) {
+            if self.entries.contains_key(&code) {
+                return ActivityResolution::Resolved(vec![code]);
+            }
+        }
+        if let Some(resolution) = self.resolve_term(activity, None) {
+            return resolution;
+        }
+
+        let mut codes: Vec<IndustryCode> = Vec::new();
+        let mut previous = None;
+        let mut rest = activity;
+        while !rest.is_empty() {
+            // 优先取目录中最长的完整表述，以免拆开本身含有分隔符的行业名称
+            let segment = self
+                .terms()
+                .map(|(term, _)| term)
+                .filter(|term| rest.starts_with(term) && at_boundary(&rest[term.len()..]))
+                .max_by_key(|term| term.len())
+                .unwrap_or_else(|| {
+                    let end = SEPARATORS
+                        .iter()
+                        .filter_map(|sep| rest.find(sep))
+                        .min()
+                        .unwrap_or(rest.len());
+                    &rest[..end]
+                });
+            if segment.is_empty() {
+                rest = strip_separator(rest);
+                continue;
+            }
+            match self.resolve_term(segment, previous) {
+                Some(ActivityResolution::Resolved(found)) => {
+                    for code in found {
+                        if !codes.contains(&code) {
+                            codes.push(code);
+                        }
+                    }
+                }
+                Some(unresolved) => return unresolved,
+                None => {
+                    return ActivityResolution::Unrecognised {
+                        term: segment.to_string(),
+                    }
+                }
+            }
+            previous = Some(segment);
+            rest = strip_separator(&rest[segment.len()..]);
+        }
+        if codes.is_empty() {
+            return ActivityResolution::Unrecognised {
+                term: activity.to_string(),
+            };
+        }
+        ActivityResolution::Resolved(codes)
+    }
+
+    /// 将经营范围的表述对应到行业代码，无法确定的返回错误
+    pub fn resolve_or_err(&self, activity: &str, operation: &str) -> FanResult<Vec<IndustryCode>> {
+        let message = match self.resolve(activity) {
+            ActivityResolution::Resolved(codes) => return Ok(codes),
+            ActivityResolution::Ambiguous { term, candidates } => {
+                let candidates: Vec<&str> = candidates.iter().map(|c| c.as_str()).collect();
+                format!(
+                    "经营范围表述 {} 可以对应多个行业（{}），请使用行业代码",
+                    term,
+                    candidates.join("、")
+                )
+            }
+            ActivityResolution::Unrecognised { term } => {
+                format!("经营范围表述无法对应到国民经济行业分类：{}", term)
+            }
+        };
+        Err(FanError::validation(
+            message,
+            ValidationErrorType::EntityError,
+            operation,
+            "IndustryCatalogue",
+        ))
+    }
+
+    /// 目录中全部的行业名称和常用表述
+    fn terms(&self) -> impl Iterator<Item = (&str, &IndustryCode)> {
+        self.entries.values().flat_map(|e| {
+            std::iter::once(e.name.as_str())
+                .chain(e.aliases.iter().map(String::as_str))
+                .map(move |term| (term, &e.code))
+        })
+    }
+
+    /// 对应单个分项；`previous` 为前一分项，本分项省略宾语的沿用其宾语，如"销售"在"计算机软件开发"之后
+    /// 指"计算机销售"。没有对应的行业的返回 `None`
+    fn resolve_term(&self, term: &str, previous: Option<&str>) -> Option<ActivityResolution> {
+        let elliptical: Vec<&IndustryCode> = previous
+            .map(|previous| {
+                self.terms()
+                    .filter(|(name, _)| {
+                        name.len() > term.len()
+                            && name.ends_with(term)
+                            && previous.contains(&name[..name.len() - term.len()])
+                    })
+                    .map(|(_, code)| code)
+                    .collect()
+            })
+            .unwrap_or_default();
+        let candidates = if elliptical.is_empty() {
+            self.terms()
+                .filter(|(name, _)| *name == term)
+                .map(|(_, code)| code)
+                .collect()
+        } else {
+            elliptical
+        };
+
+        // 同一表述对应上下级行业的取较细的行业，如门类与大类同名的"房地产业"
+        let mut finest: Vec<IndustryCode> = candidates
+            .iter()
+            .filter(|c| !candidates.iter().any(|o| o != *c && c.covers(o)))
+            .map(|c| (*c).clone())
+            .collect();
+        finest.sort_by(|a, b| a.0.cmp(&b.0));
+        finest.dedup();
+        match finest.len() {
+            0 => None,
+            1 => Some(ActivityResolution::Resolved(finest)),
+            _ => Some(ActivityResolution::Ambiguous {
+                term: term.to_string(),
+                candidates: finest,
+            }),
+        }
+    }
+}
+
+/// 表述在此处结束或者紧接着分隔符
+fn at_boundary(rest: &str) -> bool {
+    rest.is_empty() || SEPARATORS.iter().any(|sep| rest.starts_with(sep))
+}
+
+/// 去掉开头的分隔符
+fn strip_separator(rest: &str) -> &str {
+    SEPARATORS
+        .iter()
+        .find_map(|sep| rest.strip_prefix(sep))
+        .unwrap_or(rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(code: &str) -> IndustryCode {
+        IndustryCode::new(code).unwrap()
+    }
+
+    fn resolved(codes: &[&str]) -> ActivityResolution {
+        ActivityResolution::Resolved(codes.iter().map(|c| code(c)).collect())
+    }
+
+    #[test]
+    fn test_code_hierarchy() {
+        // 上下级关系：软件开发涵盖应用软件开发，属于门类 I
+        let software = code("651");
+        let application = code("6513");
+        assert!(software.covers(&application));
+        assert!(!application.covers(&software));
+        assert_eq!(application.level(), IndustryLevel::Class);
+        assert_eq!(application.ancestors().last(), Some(&code("I")));
+        assert_eq!(IndustryCatalogue::standard().children(&software).len(), 4);
+        assert!(IndustryCode::new("99").is_err());
+        assert!(IndustryCode::new("Z").is_err());
+    }
+
+    #[test]
+    fn test_resolve_single_term() {
+        let catalogue = IndustryCatalogue::standard();
+        assert_eq!(catalogue.resolve("软件开发"), resolved(&["651"]));
+        assert_eq!(catalogue.resolve("6513"), resolved(&["6513"]));
+        // 门类与大类同名的，取较细的大类
+        assert_eq!(catalogue.resolve("房地产业"), resolved(&["70"]));
+        // 本身含有分隔符的行业名称不拆分
+        assert_eq!(
+            catalogue.resolve("计算机、软件及辅助设备零售"),
+            resolved(&["5273"])
+        );
+    }
+
+    #[test]
+    fn test_resolve_compound_phrase() {
+        let catalogue = IndustryCatalogue::standard();
+        assert_eq!(
+            catalogue.resolve("计算机软件开发及销售"),
+            resolved(&["651", "5273"])
+        );
+        assert_eq!(
+            catalogue.resolve("软件开发、餐饮服务"),
+            resolved(&["651", "62"])
+        );
+        assert_eq!(
+            catalogue.resolve("软件开发及批发"),
+            resolved(&["651", "5176"])
+        );
+    }
+
+    #[test]
+    fn test_resolve_does_not_guess() {
+        let catalogue = IndustryCatalogue::standard();
+        // 仅包含目录中的表述而不能完整对应的，不取其中一部分
+        assert_eq!(
+            catalogue.resolve("软件开发培训"),
+            ActivityResolution::Unrecognised {
+                term: "软件开发培训".to_string()
+            }
+        );
+        assert_eq!(
+            catalogue.resolve("软件开发及星际旅行"),
+            ActivityResolution::Unrecognised {
+                term: "星际旅行".to_string()
+            }
+        );
+        assert!(catalogue.resolve_or_err("星际旅行", "test").is_err());
+
+        // 同一表述对应互不涵盖的行业的，返回全部候选
+        let mut custom = IndustryCatalogue::new();
+        custom
+            .add_entry(code("F"), "批发和零售业".into(), vec![])
+            .unwrap();
+        custom
+            .add_entry(code("51"), "批发业".into(), vec!["销售".into()])
+            .unwrap();
+        custom
+            .add_entry(code("52"), "零售业".into(), vec!["销售".into()])
+            .unwrap();
+        assert_eq!(
+            custom.resolve("销售"),
+            ActivityResolution::Ambiguous {
+                term: "销售".to_string(),
+                candidates: vec![code("51"), code("52")]
+            }
+        );
+        assert!(custom.resolve("销售").codes().is_none());
+        assert!(custom.resolve_or_err("销售", "test").is_err());
+    }
+
+    #[test]
+    fn test_regulation_applies_to_subclasses() {
+        let catalogue = IndustryCatalogue::standard();
+        assert_eq!(
+            catalogue.regulation(&code("5251")),
+            ActivityRegulation::LicenceRequired {
+                licence: "药品经营许可证".to_string()
+            }
+        );
+        assert_eq!(
+            catalogue.regulation(&code("6513")),
+            ActivityRegulation::General
+        );
+        assert_eq!(
+            catalogue
+                .resolve("新闻采编")
+                .codes()
+                .map(|c| catalogue.regulation(&c[0])),
+            Some(ActivityRegulation::Prohibited)
+        );
+    }
+}
@@ -1,6 +1,17 @@
+use crate::core::clock::{default_clock, Clock};
 use crate::core::entity::base::{
     BaseEntity, BusinessScope, BusinessStatus, CapacityStatus, Entity, EntityType,
 };
+use crate::core::entity::cap_table::{CapTable, ContributionDefault};
+use crate::core::entity::industry::IndustryCatalogue;
+use crate::core::entity::licence::{revoke_licence_in, Licence};
+use crate::core::entity::lifecycle::{
+    is_liquidation_activity, DissolutionCause, LegalPersonLifecycle, LegalPersonPhase, Registration,
+};
+use crate::core::entity::staff::{Personnel, StaffMember};
+use crate::core::entity::succession::{SuccessionKind, SuccessionRegistry};
+use crate::core::matter::SubjectMatterType;
+use crate::core::money::Money;
 use crate::FanResult;
 use crate::{FanError, ValidationErrorType};
 
@@ -13,10 +24,113 @@ use uuid::Uuid;
 /// 法人类型
 #[derive(Debug, Clone, PartialEq)]
 pub enum LegalPersonType {
-    Company(CompanyType), // 公司
-    Institution,          // 事业单位
-    SocialOrganization,   // 社会团体
-    Foundation,           // 基金会
+    Company(CompanyType),                        // 公司
+    OtherEnterprise,                             // 其他企业法人
+    Institution,                                 // 事业单位
+    SocialOrganization { public_welfare: bool }, // 社会团体，区分公益性与互益性
+    Foundation,                                  // 基金会
+    SocialServiceOrganization,                   // 社会服务机构
+    StateOrgan,                                  // 机关法人
+    RuralCollectiveEconomicOrg,                  // 农村集体经济组织法人
+    Cooperative,                                 // 城镇农村的合作经济组织法人
+    GrassrootsAutonomousOrg,                     // 基层群众性自治组织法人
+}
+
+/// 《民法典》对法人的分类
+#[derive(Debug, Clone, PartialEq)]
+pub enum LegalPersonCategory {
+    /// 营利法人：以取得利润并分配给股东等出资人为目的成立（第七十六条）
+    ForProfit,
+    /// 非营利法人：为公益目的或者其他非营利目的成立，不向出资人、设立人或者会员分配所取得利润（第八十七条）
+    NonProfit,
+    /// 特别法人（第九十六条）
+    Special,
+}
+
+impl LegalPersonType {
+    /// 所属的法人分类
+    pub fn category(&self) -> LegalPersonCategory {
+        match self {
+            LegalPersonType::Company(_) | LegalPersonType::OtherEnterprise => {
+                LegalPersonCategory::ForProfit
+            }
+            LegalPersonType::Institution
+            | LegalPersonType::SocialOrganization { .. }
+            | LegalPersonType::Foundation
+            | LegalPersonType::SocialServiceOrganization => LegalPersonCategory::NonProfit,
+            LegalPersonType::StateOrgan
+            | LegalPersonType::RuralCollectiveEconomicOrg
+            | LegalPersonType::Cooperative
+            | LegalPersonType::GrassrootsAutonomousOrg => LegalPersonCategory::Special,
+        }
+    }
+
+    /// 是否须经登记成立
+    ///
+    /// 营利法人经依法登记成立（第七十七条），社会团体、基金会、社会服务机构经依法登记成立
+    /// （第九十条、第九十二条）；事业单位、特别法人依法不需要办理法人登记的，从成立之日起具有法人资格。
+    pub fn requires_registration(&self) -> bool {
+        matches!(
+            self,
+            LegalPersonType::Company(_)
+                | LegalPersonType::OtherEnterprise
+                | LegalPersonType::SocialOrganization { .. }
+                | LegalPersonType::Foundation
+                | LegalPersonType::SocialServiceOrganization
+        )
+    }
+
+    /// 是否为以公益为目的的非营利法人
+    ///
+    /// 事业单位提供公益服务（第八十八条），基金会、社会服务机构以公益为目的（第九十二条），
+    /// 社会团体视其宗旨而定。
+    pub fn is_public_welfare(&self) -> bool {
+        match self {
+            LegalPersonType::Institution
+            | LegalPersonType::Foundation
+            | LegalPersonType::SocialServiceOrganization => true,
+            LegalPersonType::SocialOrganization { public_welfare } => *public_welfare,
+            _ => false,
+        }
+    }
+
+    /// 能否向出资人、设立人或者成员分配利润
+    ///
+    /// 非营利法人不得分配（第八十七条），机关法人和基层群众性自治组织法人没有可分配的利润；
+    /// 农村集体经济组织和合作经济组织可以向其成员分配收益。
+    pub fn may_distribute_profits(&self) -> bool {
+        matches!(
+            self,
+            LegalPersonType::Company(_)
+                | LegalPersonType::OtherEnterprise
+                | LegalPersonType::RuralCollectiveEconomicOrg
+                | LegalPersonType::Cooperative
+        )
+    }
+
+    /// 能否作为保证人
+    ///
+    /// 机关法人不得为保证人，但是经国务院批准为使用外国政府或者国际经济组织贷款进行转贷的除外；
+    /// 以公益为目的的非营利法人不得为保证人（第六百八十三条）。居民委员会、村民委员会提供的保证无效
+    /// （《最高人民法院关于适用〈中华人民共和国民法典〉有关担保制度的解释》第五条）。
+    pub fn can_be_guarantor(&self) -> bool {
+        !(self.is_public_welfare()
+            || matches!(
+                self,
+                LegalPersonType::StateOrgan | LegalPersonType::GrassrootsAutonomousOrg
+            ))
+    }
+
+    /// 能否成为普通合伙人
+    ///
+    /// 国有独资公司、国有企业、上市公司以及公益性的事业单位、社会团体不得成为普通合伙人（《合伙企业法》第三条）。
+    pub fn may_be_general_partner(&self) -> bool {
+        !(self.is_public_welfare()
+            || matches!(
+                self,
+                LegalPersonType::Company(CompanyType::StateOwned | CompanyType::Listed)
+            ))
+    }
 }
 
 /// 公司类型
@@ -26,6 +140,14 @@ pub enum CompanyType {
     JointStock,      // 股份有限公司
     ForeignInvested, // 外商投资企业
     StateOwned,      // 国有企业
+    Listed,          // 上市公司
+}
+
+impl CompanyType {
+    /// 是否为股份有限公司，上市公司是其股票在证券交易所上市交易的股份有限公司
+    pub fn is_joint_stock(&self) -> bool {
+        matches!(self, CompanyType::JointStock | CompanyType::Listed)
+    }
 }
 
 /// 法人
@@ -33,21 +155,42 @@ pub enum CompanyType {
 pub struct LegalPerson {
     base: BaseEntity,
     legal_person_type: LegalPersonType,
-    registered_capital: f64,
+    registered_capital: Money,
     legal_representative: Uuid, // 法定代表人ID
     registered_address: String,
-    establishment_date: DateTime<Utc>,
+    lifecycle: LegalPersonLifecycle, // 成立、登记、解散、清算与注销
+    cap_table: Option<CapTable>,     // 股东名册，仅公司有
+    licences: Vec<Licence>,          // 持有的许可证
+    personnel: Personnel,            // 执行工作任务的人员名册
 }
 
 impl LegalPerson {
     pub fn new(
         legal_person_type: LegalPersonType,
-        registered_capital: f64,
+        registered_capital: Money,
+        legal_representative: Uuid,
+        registered_address: String,
+        establishment_date: DateTime<Utc>,
+    ) -> Self {
+        Self::with_clock(
+            legal_person_type,
+            registered_capital,
+            legal_representative,
+            registered_address,
+            establishment_date,
+            default_clock(),
+        )
+    }
+
+    /// 使用指定时钟创建法人
+    pub fn with_clock(
+        legal_person_type: LegalPersonType,
+        registered_capital: Money,
         legal_representative: Uuid,
         registered_address: String,
         establishment_date: DateTime<Utc>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
-        let now = Utc::now();
         // 创建默认的经营范围
         let business_scope = BusinessScope {
             status: BusinessStatus::Normal,
@@ -55,27 +198,36 @@ impl LegalPerson {
             restrictions: None,
         };
 
+        let cap_table = new_cap_table(&legal_person_type, &registered_capital);
+        let lifecycle = LegalPersonLifecycle::new(
+            establishment_date,
+            legal_person_type.requires_registration(),
+        );
+
         Self {
-            base: BaseEntity {
-                id: Uuid::new_v4(),
-                entity_type: EntityType::LegalPerson,
-                capacity_status: CapacityStatus::LegalPerson(business_scope),
-                created_at: now,
-                updated_at: now,
-            },
+            base: BaseEntity::new(
+                EntityType::LegalPerson,
+                CapacityStatus::LegalPerson(business_scope),
+                clock,
+            ),
             legal_person_type,
             registered_capital,
             legal_representative,
             registered_address,
-            establishment_date,
+            lifecycle,
+            cap_table,
+            licences: Vec::new(),
+            personnel: Personnel::default(),
         }
     }
 
-    /// 添加经营范围
+    /// 添加经营范围，可以是经营范围的表述或行业代码，按标准行业分类目录对应到其所列的全部行业代码
     pub fn add_permitted_activity(&mut self, activity: String) -> FanResult<()> {
+        let codes =
+            IndustryCatalogue::standard().resolve_or_err(&activity, "add_permitted_activity")?;
         if let CapacityStatus::LegalPerson(scope) = &mut self.base.capacity_status {
-            scope.permitted_activities.insert(activity);
-            self.base.updated_at = Utc::now();
+            scope.permitted_activities.extend(codes);
+            self.base.touch();
             Ok(())
         } else {
             Err(FanError::validation(
@@ -87,16 +239,18 @@ impl LegalPerson {
         }
     }
 
-    /// 添加经营限制
+    /// 添加经营限制，限制涵盖该行业的全部下属行业
     pub fn add_restriction(&mut self, restriction: String) -> FanResult<()> {
+        let codes =
+            IndustryCatalogue::standard().resolve_or_err(&restriction, "add_restriction")?;
         if let CapacityStatus::LegalPerson(scope) = &mut self.base.capacity_status {
             if scope.restrictions.is_none() {
                 scope.restrictions = Some(Vec::new());
             }
             if let Some(restrictions) = &mut scope.restrictions {
-                restrictions.push(restriction);
+                restrictions.extend(codes);
             }
-            self.base.updated_at = Utc::now();
+            self.base.touch();
             Ok(())
         } else {
             Err(FanError::validation(
@@ -112,7 +266,7 @@ impl LegalPerson {
     pub fn update_business_status(&mut self, new_status: BusinessStatus) -> FanResult<()> {
         if let CapacityStatus::LegalPerson(scope) = &mut self.base.capacity_status {
             scope.status = new_status;
-            self.base.updated_at = Utc::now();
+            self.base.touch();
             Ok(())
         } else {
             Err(FanError::validation(
@@ -125,36 +279,335 @@ impl LegalPerson {
     }
 
     /// 检查是否可以进行特定活动
+    ///
+    /// 存续期间以经营范围为准；清算期间只能从事与清算有关的活动（第七十二条第一款）。
     pub fn can_perform_activity(&self, activity: &str) -> bool {
-        if let CapacityStatus::LegalPerson(scope) = &self.base.capacity_status {
-            match scope.status {
-                BusinessStatus::Normal => {
-                    scope.permitted_activities.contains(activity)
-                        && !scope
-                            .restrictions
-                            .as_ref()
-                            .map_or(false, |r| r.contains(&activity.to_string()))
-                }
-                BusinessStatus::Restricted => {
-                    scope.permitted_activities.contains(activity)
-                        && !scope
-                            .restrictions
-                            .as_ref()
-                            .map_or(false, |r| r.contains(&activity.to_string()))
-                }
-                BusinessStatus::Suspended => false,
-            }
+        permits_activity(
+            &self.base.capacity_status,
+            &self.lifecycle,
+            self.base.now(),
+            activity,
+        )
+    }
+
+    /// 记录法人登记，须经登记成立的法人自登记之日起成立
+    ///
+    /// `registered_at` 为登记机关核准登记之日，可以早于记录之时，但不能晚于记录之时。
+    pub fn register(
+        &mut self,
+        authority: String,
+        number: String,
+        registered_at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        check_registered_at(registered_at, self.base.now())?;
+        let registration = Registration::new(authority, number, registered_at);
+        self.lifecycle.register(registration)?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 法人解散，进入清算期间
+    pub fn dissolve(&mut self, cause: DissolutionCause, liquidators: Vec<Uuid>) -> FanResult<()> {
+        self.lifecycle
+            .dissolve(cause, liquidators, self.base.now())?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 清算结束
+    pub fn complete_liquidation(&mut self) -> FanResult<()> {
+        self.lifecycle.complete_liquidation(self.base.now())?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 注销登记，法人终止
+    pub fn deregister(&mut self) -> FanResult<()> {
+        self.lifecycle.deregister(self.base.now())?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 当前所处的阶段
+    pub fn phase(&self) -> LegalPersonPhase {
+        self.lifecycle.phase_at(self.base.now())
+    }
+
+    /// 获取登记、解散、清算与注销的记录
+    pub fn lifecycle(&self) -> &LegalPersonLifecycle {
+        &self.lifecycle
+    }
+
+    /// 吸收合并另一法人，被吸收的法人不经清算而注销，其权利义务由本法人承继（第六十七条第一款）
+    pub fn absorb(
+        &mut self,
+        absorbed: &mut LegalPerson,
+        registry: &mut SuccessionRegistry,
+    ) -> FanResult<()> {
+        ensure_active([self.phase(), absorbed.phase()], "absorb")?;
+        registry.record(
+            SuccessionKind::MergerByAbsorption,
+            vec![self.id(), absorbed.id()],
+            vec![self.id()],
+            self.base.now(),
+        )?;
+        absorbed.retire()?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 新设合并，合并各方注销，其权利义务由新设立的法人承继（第六十七条第一款）
+    pub fn consolidate(
+        merging: Vec<&mut LegalPerson>,
+        into: &LegalPerson,
+        registry: &mut SuccessionRegistry,
+    ) -> FanResult<()> {
+        ensure_active(
+            merging.iter().map(|p| p.phase()).chain([into.phase()]),
+            "consolidate",
+        )?;
+        registry.record(
+            SuccessionKind::MergerByConsolidation,
+            merging.iter().map(|p| p.id()).collect(),
+            vec![into.id()],
+            into.base.now(),
+        )?;
+        for person in merging {
+            person.retire()?;
+        }
+        Ok(())
+    }
+
+    /// 分立为新设立的法人；`survives` 为真时本法人存续（派生分立），否则注销（新设分立）
+    ///
+    /// 分立后的法人享有连带债权，承担连带债务（第六十七条第二款）。
+    pub fn divide(
+        &mut self,
+        new_entities: &[&LegalPerson],
+        survives: bool,
+        registry: &mut SuccessionRegistry,
+    ) -> FanResult<()> {
+        ensure_active(
+            [self.phase()]
+                .into_iter()
+                .chain(new_entities.iter().map(|p| p.phase())),
+            "divide",
+        )?;
+        let (kind, mut successors) = if survives {
+            (SuccessionKind::DivisionBySpinOff, vec![self.id()])
+        } else {
+            (SuccessionKind::DivisionByDissolution, Vec::new())
+        };
+        successors.extend(new_entities.iter().map(|p| p.id()));
+        registry.record(kind, vec![self.id()], successors, self.base.now())?;
+        if survives {
+            self.base.touch();
+            Ok(())
         } else {
-            false
+            self.retire()
         }
     }
 
+    /// 因合并或者分立解散，不经清算直接注销
+    fn retire(&mut self) -> FanResult<()> {
+        self.dissolve(DissolutionCause::MergerOrDivision, Vec::new())?;
+        self.deregister()
+    }
+
+    /// 取得许可证
+    pub fn add_licence(&mut self, licence: Licence) -> FanResult<()> {
+        self.licences.push(licence);
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 许可证被吊销或撤销，此后不能再依该许可证从事许可经营的项目
+    pub fn revoke_licence(&mut self, number: &str, at: DateTime<Utc>) -> FanResult<()> {
+        revoke_licence_in(&mut self.licences, number, at)?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 获取持有的许可证
+    pub fn licences(&self) -> &[Licence] {
+        &self.licences
+    }
+
+    /// 任命执行工作任务的人员，并记载其职权范围内的事项
+    pub fn appoint_staff(
+        &mut self,
+        person: &dyn Entity,
+        duties: Vec<SubjectMatterType>,
+    ) -> FanResult<()> {
+        self.personnel.appoint(person, duties, self.base.now())?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 人员离职
+    pub fn dismiss_staff(&mut self, person: Uuid) -> FanResult<()> {
+        self.personnel.dismiss(person, self.base.now())?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 以章程、决议或者内部规定限制法定代表人、负责人或者工作人员的权限，取代此前对该人员的限制
+    pub fn limit_authority(
+        &mut self,
+        person: Uuid,
+        matters: Option<Vec<SubjectMatterType>>,
+        price_limit: Option<Money>,
+    ) -> FanResult<()> {
+        let representatives = self.representatives();
+        self.personnel.limit(
+            &representatives,
+            person,
+            matters,
+            price_limit,
+            self.base.now(),
+        )?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 将对某一人员权限的限制告知相对人，此后该相对人知道该限制（第五百零四条）
+    pub fn notify_internal_limit(&mut self, person: Uuid, counterparty: Uuid) -> FanResult<()> {
+        self.personnel
+            .notify_limit(person, counterparty, self.base.now())?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 获取人员名册
+    pub fn staff(&self) -> &[StaffMember] {
+        self.personnel.staff()
+    }
+
+    /// 获取股东名册，非公司法人没有股东名册
+    pub fn cap_table(&self) -> Option<&CapTable> {
+        self.cap_table.as_ref()
+    }
+
+    /// 股东认缴出资，认缴出资总额不得超过注册资本
+    pub fn subscribe_capital(
+        &mut self,
+        shareholder: Uuid,
+        amount: Money,
+        due: DateTime<Utc>,
+    ) -> FanResult<()> {
+        let capital = self.registered_capital;
+        let table = cap_table_mut(&mut self.cap_table, "subscribe_capital", "LegalPerson")?;
+        check_subscription(table, &capital, &amount, "LegalPerson")?;
+        table.subscribe(shareholder, amount, due)?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 股东实缴出资
+    pub fn pay_in_capital(&mut self, shareholder: Uuid, amount: Money) -> FanResult<()> {
+        cap_table_mut(&mut self.cap_table, "pay_in_capital", "LegalPerson")?
+            .pay(shareholder, amount)?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 股东转让股权，返回转让ID；有限责任公司对外转让的，须待其他股东行使或放弃优先购买权
+    pub fn transfer_equity(
+        &mut self,
+        transferor: Uuid,
+        transferee: Uuid,
+        amount: Money,
+        price: Money,
+    ) -> FanResult<Uuid> {
+        let now = self.base.now();
+        let id = cap_table_mut(&mut self.cap_table, "transfer_equity", "LegalPerson")?
+            .transfer(transferor, transferee, amount, price, now)?;
+        self.base.touch();
+        Ok(id)
+    }
+
+    /// 其他股东行使优先购买权
+    pub fn exercise_preemption(&mut self, transfer_id: Uuid, shareholder: Uuid) -> FanResult<()> {
+        let now = self.base.now();
+        cap_table_mut(&mut self.cap_table, "exercise_preemption", "LegalPerson")?
+            .exercise_preemption(transfer_id, shareholder, now)
+    }
+
+    /// 其他股东放弃优先购买权
+    pub fn waive_preemption(&mut self, transfer_id: Uuid, shareholder: Uuid) -> FanResult<()> {
+        let now = self.base.now();
+        cap_table_mut(&mut self.cap_table, "waive_preemption", "LegalPerson")?.waive_preemption(
+            transfer_id,
+            shareholder,
+            now,
+        )
+    }
+
+    /// 完成股权转让
+    pub fn complete_equity_transfer(&mut self, transfer_id: Uuid) -> FanResult<()> {
+        let now = self.base.now();
+        cap_table_mut(
+            &mut self.cap_table,
+            "complete_equity_transfer",
+            "LegalPerson",
+        )?
+        .complete_transfer(transfer_id, now)?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 当前未按期足额缴纳出资的股东
+    pub fn defaulting_shareholders(&self) -> Vec<ContributionDefault> {
+        self.cap_table
+            .as_ref()
+            .map(|t| t.defaulters_at(self.base.now()))
+            .unwrap_or_default()
+    }
+
+    /// 变更注册资本，须为正数、与原注册资本币种一致，且不低于全体股东已认缴的出资额
+    pub fn update_registered_capital(&mut self, new_capital: Money) -> FanResult<()> {
+        check_capital(
+            &self.registered_capital,
+            &new_capital,
+            self.cap_table.as_ref(),
+            "LegalPerson",
+        )?;
+        self.registered_capital = new_capital;
+        self.base.touch();
+        Ok(())
+    }
+
     /// 更改法定代表人
     pub fn change_legal_representative(&mut self, new_representative: Uuid) -> FanResult<()> {
         self.legal_representative = new_representative;
-        self.base.updated_at = Utc::now();
+        self.base.touch();
         Ok(())
     }
+
+    /// 获取法人类型
+    pub fn legal_person_type(&self) -> &LegalPersonType {
+        &self.legal_person_type
+    }
+
+    /// 获取注册资本
+    pub fn registered_capital(&self) -> Money {
+        self.registered_capital
+    }
+
+    /// 获取法定代表人ID
+    pub fn legal_representative(&self) -> Uuid {
+        self.legal_representative
+    }
+
+    /// 获取注册地址
+    pub fn registered_address(&self) -> &str {
+        &self.registered_address
+    }
+
+    /// 获取成立日期，须经登记成立的以登记之日为准，尚未登记的为拟成立的日期
+    pub fn establishment_date(&self) -> DateTime<Utc> {
+        self.lifecycle.established_at()
+    }
 }
 
 impl Entity for LegalPerson {
@@ -174,11 +627,31 @@ impl Entity for LegalPerson {
         self.base.updated_at
     }
 
+    fn legal_form(&self) -> Option<LegalPersonType> {
+        Some(self.legal_person_type.clone())
+    }
+
+    fn licences(&self) -> Vec<Licence> {
+        self.licences.clone()
+    }
+
+    fn personnel(&self) -> Option<Personnel> {
+        Some(self.personnel.clone())
+    }
+
+    /// 依照法律或者法人章程的规定，代表法人从事民事活动的负责人为法人的法定代表人（第六十一条）
+    fn representatives(&self) -> Vec<Uuid> {
+        vec![self.legal_representative]
+    }
+
     fn has_capacity(&self) -> bool {
-        match self.base.capacity_status {
-            CapacityStatus::LegalPerson(_) => true,
-            _ => false,
-        }
+        self.has_capacity_at(self.base.now())
+    }
+
+    /// 法人的民事行为能力从成立时产生，到终止时消灭（第五十九条）
+    fn has_capacity_at(&self, at: DateTime<Utc>) -> bool {
+        matches!(self.base.capacity_status, CapacityStatus::LegalPerson(_))
+            && self.lifecycle.exists_at(at)
     }
 }
 
@@ -187,69 +660,257 @@ impl Entity for LegalPerson {
 pub struct SyncLegalPerson {
     base: Arc<RwLock<BaseEntity>>,
     legal_person_type: LegalPersonType,      // 不可变，不需要锁
-    registered_capital: Arc<RwLock<f64>>,    // 注册资本可能变更
+    registered_capital: Arc<RwLock<Money>>,  // 注册资本可能变更
     legal_representative: Arc<RwLock<Uuid>>, // 法定代表人可能变更
     registered_address: Arc<RwLock<String>>, // 注册地址可能变更
-    establishment_date: DateTime<Utc>,       // 不可变，不需要锁
+    lifecycle: Arc<RwLock<LegalPersonLifecycle>>, // 成立、登记、解散、清算与注销
+    cap_table: Arc<RwLock<Option<CapTable>>>, // 股东名册，仅公司有
+    licences: Arc<RwLock<Vec<Licence>>>,     // 持有的许可证
+    personnel: Arc<RwLock<Personnel>>,       // 执行工作任务的人员名册
 }
 
 impl SyncLegalPerson {
     pub fn new(
         legal_person_type: LegalPersonType,
-        registered_capital: f64,
+        registered_capital: Money,
+        legal_representative: Uuid,
+        registered_address: String,
+        establishment_date: DateTime<Utc>,
+    ) -> Self {
+        Self::with_clock(
+            legal_person_type,
+            registered_capital,
+            legal_representative,
+            registered_address,
+            establishment_date,
+            default_clock(),
+        )
+    }
+
+    /// 使用指定时钟创建法人
+    pub fn with_clock(
+        legal_person_type: LegalPersonType,
+        registered_capital: Money,
         legal_representative: Uuid,
         registered_address: String,
         establishment_date: DateTime<Utc>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
-        let now = Utc::now();
         let business_scope = BusinessScope {
             status: BusinessStatus::Normal,
             permitted_activities: HashSet::new(),
             restrictions: None,
         };
 
+        let cap_table = new_cap_table(&legal_person_type, &registered_capital);
+        let lifecycle = LegalPersonLifecycle::new(
+            establishment_date,
+            legal_person_type.requires_registration(),
+        );
+
         Self {
-            base: Arc::new(RwLock::new(BaseEntity {
-                id: Uuid::new_v4(),
-                entity_type: EntityType::LegalPerson,
-                capacity_status: CapacityStatus::LegalPerson(business_scope),
-                created_at: now,
-                updated_at: now,
-            })),
+            base: Arc::new(RwLock::new(BaseEntity::new(
+                EntityType::LegalPerson,
+                CapacityStatus::LegalPerson(business_scope),
+                clock,
+            ))),
             legal_person_type,
             registered_capital: Arc::new(RwLock::new(registered_capital)),
             legal_representative: Arc::new(RwLock::new(legal_representative)),
             registered_address: Arc::new(RwLock::new(registered_address)),
-            establishment_date,
+            lifecycle: Arc::new(RwLock::new(lifecycle)),
+            cap_table: Arc::new(RwLock::new(cap_table)),
+            licences: Arc::new(RwLock::new(Vec::new())),
+            personnel: Arc::new(RwLock::new(Personnel::default())),
         }
     }
 
-    pub fn update_registered_capital(&self, new_capital: f64) -> FanResult<()> {
-        if new_capital <= 0.0 {
-            return Err(FanError::validation(
-                "Invalid registered capital value",
-                ValidationErrorType::EntityError,
-                "update_registered_capital",
-                "SyncLegalPerson",
-            ));
-        }
+    /// 取得许可证
+    pub fn add_licence(&self, licence: Licence) -> FanResult<()> {
+        self.licences.write().push(licence);
+        self.base.write().touch();
+        Ok(())
+    }
+
+    /// 许可证被吊销或撤销
+    pub fn revoke_licence(&self, number: &str, at: DateTime<Utc>) -> FanResult<()> {
+        revoke_licence_in(&mut self.licences.write(), number, at)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn appoint_staff(
+        &self,
+        person: &dyn Entity,
+        duties: Vec<SubjectMatterType>,
+    ) -> FanResult<()> {
+        let now = self.base.read().now();
+        self.personnel.write().appoint(person, duties, now)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn dismiss_staff(&self, person: Uuid) -> FanResult<()> {
+        let now = self.base.read().now();
+        self.personnel.write().dismiss(person, now)?;
+        self.base.write().touch();
+        Ok(())
+    }
 
-        *self.registered_capital.write() = new_capital;
-        self.base.write().updated_at = Utc::now();
+    /// 以章程、决议或者内部规定限制法定代表人、负责人或者工作人员的权限，取代此前对该人员的限制
+    pub fn limit_authority(
+        &self,
+        person: Uuid,
+        matters: Option<Vec<SubjectMatterType>>,
+        price_limit: Option<Money>,
+    ) -> FanResult<()> {
+        let representatives = self.representatives();
+        let now = self.base.read().now();
+        self.personnel
+            .write()
+            .limit(&representatives, person, matters, price_limit, now)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    /// 将对某一人员权限的限制告知相对人，此后该相对人知道该限制（第五百零四条）
+    pub fn notify_internal_limit(&self, person: Uuid, counterparty: Uuid) -> FanResult<()> {
+        let now = self.base.read().now();
+        self.personnel
+            .write()
+            .notify_limit(person, counterparty, now)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn staff(&self) -> Vec<StaffMember> {
+        self.personnel.read().staff().to_vec()
+    }
+
+    /// 获取股东名册，非公司法人没有股东名册
+    pub fn cap_table(&self) -> Option<CapTable> {
+        self.cap_table.read().clone()
+    }
+
+    /// 股东认缴出资，认缴出资总额不得超过注册资本
+    pub fn subscribe_capital(
+        &self,
+        shareholder: Uuid,
+        amount: Money,
+        due: DateTime<Utc>,
+    ) -> FanResult<()> {
+        let capital = *self.registered_capital.read();
+        let mut cap_table = self.cap_table.write();
+        let table = cap_table_mut(&mut cap_table, "subscribe_capital", "SyncLegalPerson")?;
+        check_subscription(table, &capital, &amount, "SyncLegalPerson")?;
+        table.subscribe(shareholder, amount, due)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    /// 股东实缴出资
+    pub fn pay_in_capital(&self, shareholder: Uuid, amount: Money) -> FanResult<()> {
+        cap_table_mut(
+            &mut self.cap_table.write(),
+            "pay_in_capital",
+            "SyncLegalPerson",
+        )?
+        .pay(shareholder, amount)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    /// 股东转让股权，返回转让ID；有限责任公司对外转让的，须待其他股东行使或放弃优先购买权
+    pub fn transfer_equity(
+        &self,
+        transferor: Uuid,
+        transferee: Uuid,
+        amount: Money,
+        price: Money,
+    ) -> FanResult<Uuid> {
+        let mut base = self.base.write();
+        let id = cap_table_mut(
+            &mut self.cap_table.write(),
+            "transfer_equity",
+            "SyncLegalPerson",
+        )?
+        .transfer(transferor, transferee, amount, price, base.now())?;
+        base.touch();
+        Ok(id)
+    }
+
+    /// 其他股东行使优先购买权
+    pub fn exercise_preemption(&self, transfer_id: Uuid, shareholder: Uuid) -> FanResult<()> {
+        let now = self.base.read().now();
+        cap_table_mut(
+            &mut self.cap_table.write(),
+            "exercise_preemption",
+            "SyncLegalPerson",
+        )?
+        .exercise_preemption(transfer_id, shareholder, now)
+    }
+
+    /// 其他股东放弃优先购买权
+    pub fn waive_preemption(&self, transfer_id: Uuid, shareholder: Uuid) -> FanResult<()> {
+        let now = self.base.read().now();
+        cap_table_mut(
+            &mut self.cap_table.write(),
+            "waive_preemption",
+            "SyncLegalPerson",
+        )?
+        .waive_preemption(transfer_id, shareholder, now)
+    }
+
+    /// 完成股权转让
+    pub fn complete_equity_transfer(&self, transfer_id: Uuid) -> FanResult<()> {
+        let mut base = self.base.write();
+        cap_table_mut(
+            &mut self.cap_table.write(),
+            "complete_equity_transfer",
+            "SyncLegalPerson",
+        )?
+        .complete_transfer(transfer_id, base.now())?;
+        base.touch();
+        Ok(())
+    }
+
+    /// 当前未按期足额缴纳出资的股东
+    pub fn defaulting_shareholders(&self) -> Vec<ContributionDefault> {
+        let now = self.base.read().now();
+        self.cap_table
+            .read()
+            .as_ref()
+            .map(|t| t.defaulters_at(now))
+            .unwrap_or_default()
+    }
+
+    /// 变更注册资本，须为正数、与原注册资本币种一致，且不低于全体股东已认缴的出资额
+    pub fn update_registered_capital(&self, new_capital: Money) -> FanResult<()> {
+        let mut capital = self.registered_capital.write();
+        check_capital(
+            &capital,
+            &new_capital,
+            self.cap_table.read().as_ref(),
+            "SyncLegalPerson",
+        )?;
+        *capital = new_capital;
+        self.base.write().touch();
         Ok(())
     }
 
     pub fn change_legal_representative(&self, new_representative: Uuid) -> FanResult<()> {
         *self.legal_representative.write() = new_representative;
-        self.base.write().updated_at = Utc::now();
+        self.base.write().touch();
         Ok(())
     }
 
     pub fn add_permitted_activity(&self, activity: String) -> FanResult<()> {
+        let codes =
+            IndustryCatalogue::standard().resolve_or_err(&activity, "add_permitted_activity")?;
         let mut base = self.base.write();
         if let CapacityStatus::LegalPerson(scope) = &mut base.capacity_status {
-            scope.permitted_activities.insert(activity);
-            base.updated_at = Utc::now();
+            scope.permitted_activities.extend(codes);
+            base.touch();
             Ok(())
         } else {
             Err(FanError::validation(
@@ -263,29 +924,165 @@ impl SyncLegalPerson {
 
     pub fn can_perform_activity(&self, activity: &str) -> bool {
         let base = self.base.read();
-        if let CapacityStatus::LegalPerson(scope) = &base.capacity_status {
-            match scope.status {
-                BusinessStatus::Normal => {
-                    scope.permitted_activities.contains(activity)
-                        && !scope
-                            .restrictions
-                            .as_ref()
-                            .map_or(false, |r| r.contains(&activity.to_string()))
-                }
-                BusinessStatus::Restricted => {
-                    scope.permitted_activities.contains(activity)
-                        && !scope
-                            .restrictions
-                            .as_ref()
-                            .map_or(false, |r| r.contains(&activity.to_string()))
-                }
-                BusinessStatus::Suspended => false,
-            }
+        permits_activity(
+            &base.capacity_status,
+            &self.lifecycle.read(),
+            base.now(),
+            activity,
+        )
+    }
+
+    /// 记录法人登记
+    pub fn register(
+        &self,
+        authority: String,
+        number: String,
+        registered_at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        let mut base = self.base.write();
+        check_registered_at(registered_at, base.now())?;
+        let registration = Registration::new(authority, number, registered_at);
+        self.lifecycle.write().register(registration)?;
+        base.touch();
+        Ok(())
+    }
+
+    /// 法人解散，进入清算期间
+    pub fn dissolve(&self, cause: DissolutionCause, liquidators: Vec<Uuid>) -> FanResult<()> {
+        let mut base = self.base.write();
+        self.lifecycle
+            .write()
+            .dissolve(cause, liquidators, base.now())?;
+        base.touch();
+        Ok(())
+    }
+
+    /// 清算结束
+    pub fn complete_liquidation(&self) -> FanResult<()> {
+        let mut base = self.base.write();
+        self.lifecycle.write().complete_liquidation(base.now())?;
+        base.touch();
+        Ok(())
+    }
+
+    /// 注销登记，法人终止
+    pub fn deregister(&self) -> FanResult<()> {
+        let mut base = self.base.write();
+        self.lifecycle.write().deregister(base.now())?;
+        base.touch();
+        Ok(())
+    }
+
+    /// 当前所处的阶段
+    pub fn phase(&self) -> LegalPersonPhase {
+        let now = self.base.read().now();
+        self.lifecycle.read().phase_at(now)
+    }
+
+    /// 获取登记、解散、清算与注销的记录
+    pub fn lifecycle(&self) -> LegalPersonLifecycle {
+        self.lifecycle.read().clone()
+    }
+
+    /// 吸收合并另一法人，被吸收的法人不经清算而注销，其权利义务由本法人承继（第六十七条第一款）
+    pub fn absorb(
+        &self,
+        absorbed: &SyncLegalPerson,
+        registry: &mut SuccessionRegistry,
+    ) -> FanResult<()> {
+        ensure_active([self.phase(), absorbed.phase()], "absorb")?;
+        let now = self.base.read().now();
+        registry.record(
+            SuccessionKind::MergerByAbsorption,
+            vec![self.id(), absorbed.id()],
+            vec![self.id()],
+            now,
+        )?;
+        absorbed.retire()?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    /// 新设合并，合并各方注销，其权利义务由新设立的法人承继（第六十七条第一款）
+    pub fn consolidate(
+        merging: &[&SyncLegalPerson],
+        into: &SyncLegalPerson,
+        registry: &mut SuccessionRegistry,
+    ) -> FanResult<()> {
+        ensure_active(
+            merging.iter().map(|p| p.phase()).chain([into.phase()]),
+            "consolidate",
+        )?;
+        let now = into.base.read().now();
+        registry.record(
+            SuccessionKind::MergerByConsolidation,
+            merging.iter().map(|p| p.id()).collect(),
+            vec![into.id()],
+            now,
+        )?;
+        for person in merging {
+            person.retire()?;
+        }
+        Ok(())
+    }
+
+    /// 分立为新设立的法人；`survives` 为真时本法人存续（派生分立），否则注销（新设分立）
+    ///
+    /// 分立后的法人享有连带债权，承担连带债务（第六十七条第二款）。
+    pub fn divide(
+        &self,
+        new_entities: &[&SyncLegalPerson],
+        survives: bool,
+        registry: &mut SuccessionRegistry,
+    ) -> FanResult<()> {
+        ensure_active(
+            [self.phase()]
+                .into_iter()
+                .chain(new_entities.iter().map(|p| p.phase())),
+            "divide",
+        )?;
+        let (kind, mut successors) = if survives {
+            (SuccessionKind::DivisionBySpinOff, vec![self.id()])
+        } else {
+            (SuccessionKind::DivisionByDissolution, Vec::new())
+        };
+        successors.extend(new_entities.iter().map(|p| p.id()));
+        let now = self.base.read().now();
+        registry.record(kind, vec![self.id()], successors, now)?;
+        if survives {
+            self.base.write().touch();
+            Ok(())
         } else {
-            false
+            self.retire()
         }
     }
 
+    /// 因合并或者分立解散，不经清算直接注销
+    fn retire(&self) -> FanResult<()> {
+        self.dissolve(DissolutionCause::MergerOrDivision, Vec::new())?;
+        self.deregister()
+    }
+
+    pub fn legal_person_type(&self) -> &LegalPersonType {
+        &self.legal_person_type
+    }
+
+    pub fn registered_capital(&self) -> Money {
+        *self.registered_capital.read()
+    }
+
+    pub fn legal_representative(&self) -> Uuid {
+        *self.legal_representative.read()
+    }
+
+    pub fn registered_address(&self) -> String {
+        self.registered_address.read().clone()
+    }
+
+    pub fn establishment_date(&self) -> DateTime<Utc> {
+        self.lifecycle.read().established_at()
+    }
+
     pub fn from_legal_person(person: LegalPerson) -> Self {
         Self {
             base: Arc::new(RwLock::new(person.base)),
@@ -293,7 +1090,10 @@ impl SyncLegalPerson {
             registered_capital: Arc::new(RwLock::new(person.registered_capital)),
             legal_representative: Arc::new(RwLock::new(person.legal_representative)),
             registered_address: Arc::new(RwLock::new(person.registered_address)),
-            establishment_date: person.establishment_date,
+            lifecycle: Arc::new(RwLock::new(person.lifecycle)),
+            cap_table: Arc::new(RwLock::new(person.cap_table)),
+            licences: Arc::new(RwLock::new(person.licences)),
+            personnel: Arc::new(RwLock::new(person.personnel)),
         }
     }
 }
@@ -319,36 +1119,218 @@ impl Entity for SyncLegalPerson {
         self.base.read().updated_at
     }
 
+    fn legal_form(&self) -> Option<LegalPersonType> {
+        Some(self.legal_person_type.clone())
+    }
+
+    fn licences(&self) -> Vec<Licence> {
+        self.licences.read().clone()
+    }
+
+    fn personnel(&self) -> Option<Personnel> {
+        Some(self.personnel.read().clone())
+    }
+
+    fn representatives(&self) -> Vec<Uuid> {
+        vec![self.legal_representative()]
+    }
+
     fn has_capacity(&self) -> bool {
-        match self.base.read().capacity_status {
-            CapacityStatus::LegalPerson(_) => true,
-            _ => false,
+        let now = self.base.read().now();
+        self.has_capacity_at(now)
+    }
+
+    fn has_capacity_at(&self, at: DateTime<Utc>) -> bool {
+        matches!(
+            self.base.read().capacity_status,
+            CapacityStatus::LegalPerson(_)
+        ) && self.lifecycle.read().exists_at(at)
+    }
+}
+
+/// 公司设立时建立股东名册，有限责任公司的股东对外转让股权时其他股东享有优先购买权
+fn new_cap_table(legal_person_type: &LegalPersonType, capital: &Money) -> Option<CapTable> {
+    match legal_person_type {
+        LegalPersonType::Company(company_type) => Some(CapTable::new(
+            capital.currency(),
+            !company_type.is_joint_stock(),
+        )),
+        _ => None,
+    }
+}
+
+fn cap_table_mut<'a>(
+    cap_table: &'a mut Option<CapTable>,
+    operation: &str,
+    location: &str,
+) -> FanResult<&'a mut CapTable> {
+    cap_table.as_mut().ok_or_else(|| {
+        FanError::validation(
+            "只有公司才有股东名册",
+            ValidationErrorType::EntityError,
+            operation,
+            location,
+        )
+    })
+}
+
+/// 全体股东认缴的出资额不得超过注册资本（《公司法》第四十七条）
+fn check_subscription(
+    table: &CapTable,
+    capital: &Money,
+    amount: &Money,
+    location: &str,
+) -> FanResult<()> {
+    if table
+        .subscribed_total()
+        .checked_add(amount)?
+        .checked_cmp(capital)?
+        .is_gt()
+    {
+        return Err(FanError::validation_with_ref(
+            "全体股东认缴的出资额超过注册资本",
+            ValidationErrorType::EntityError,
+            "《公司法》第四十七条",
+            "subscribe_capital",
+            location,
+        ));
+    }
+    Ok(())
+}
+
+/// 校验变更后的注册资本；公司减少注册资本的，不得低于全体股东已认缴的出资额（《公司法》第四十七条）
+fn check_capital(
+    current: &Money,
+    new_capital: &Money,
+    cap_table: Option<&CapTable>,
+    location: &str,
+) -> FanResult<()> {
+    if !new_capital.is_positive() {
+        return Err(FanError::validation(
+            "注册资本必须大于零",
+            ValidationErrorType::EntityError,
+            "update_registered_capital",
+            location,
+        ));
+    }
+    current.checked_cmp(new_capital)?;
+    if let Some(table) = cap_table {
+        if table.subscribed_total().checked_cmp(new_capital)?.is_gt() {
+            return Err(FanError::validation_with_ref(
+                "注册资本不得低于全体股东认缴的出资额",
+                ValidationErrorType::EntityError,
+                "《公司法》第四十七条",
+                "update_registered_capital",
+                location,
+            ));
         }
     }
+    Ok(())
+}
+
+/// 登记日期不能晚于记录登记之时
+fn check_registered_at(registered_at: DateTime<Utc>, now: DateTime<Utc>) -> FanResult<()> {
+    if registered_at > now {
+        return Err(FanError::validation(
+            "登记日期不能晚于记录登记之时",
+            ValidationErrorType::OperationSequenceWrong,
+            "register",
+            "LegalPerson",
+        ));
+    }
+    Ok(())
+}
+
+/// 参与合并或分立的法人都必须处于存续状态
+fn ensure_active(
+    phases: impl IntoIterator<Item = LegalPersonPhase>,
+    operation: &str,
+) -> FanResult<()> {
+    if phases.into_iter().any(|p| p != LegalPersonPhase::Active) {
+        return Err(FanError::validation_with_ref(
+            "参与合并或分立的法人必须处于存续状态",
+            ValidationErrorType::EntityStatusIllegal,
+            "《民法典》第六十七条",
+            operation,
+            "LegalPerson",
+        ));
+    }
+    Ok(())
+}
+
+/// 按法人所处的阶段判断能否从事某项活动
+fn permits_activity(
+    capacity_status: &CapacityStatus,
+    lifecycle: &LegalPersonLifecycle,
+    at: DateTime<Utc>,
+    activity: &str,
+) -> bool {
+    let CapacityStatus::LegalPerson(scope) = capacity_status else {
+        return false;
+    };
+    let resolution = IndustryCatalogue::standard().resolve(activity);
+    let Some(codes) = resolution.codes() else {
+        return false;
+    };
+    match lifecycle.phase_at(at) {
+        LegalPersonPhase::Active => match scope.status {
+            BusinessStatus::Normal | BusinessStatus::Restricted => {
+                codes.iter().all(|c| scope.permits(c))
+            }
+            BusinessStatus::Suspended => false,
+        },
+        LegalPersonPhase::Liquidating => codes.iter().all(is_liquidation_activity),
+        LegalPersonPhase::NotEstablished | LegalPersonPhase::Terminated => false,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::clock::{FixedClock, ManualClock};
+    use crate::core::money::Currency;
+    use chrono::{Duration, TimeZone};
+    use rust_decimal::Decimal;
 
     #[test]
     fn test_legal_person_activities() {
         let mut company = LegalPerson::new(
             LegalPersonType::Company(CompanyType::Limited),
-            1_000_000.0,
+            Money::cny(Decimal::from(1_000_000)),
             Uuid::new_v4(),
             "北京市朝阳区xxx街道".to_string(),
             Utc::now(),
         );
+        company
+            .register(
+                "北京市朝阳区市场监督管理局".to_string(),
+                "91110105MA00000000".to_string(),
+                Utc::now(),
+            )
+            .unwrap();
 
         // 添加经营范围
         company
             .add_permitted_activity("软件开发".to_string())
             .unwrap();
         assert!(company.can_perform_activity("软件开发"));
+        assert!(company.can_perform_activity("计算机软件开发"));
+        // 只登记了软件开发的，不能从事软件开发及销售
+        assert!(!company.can_perform_activity("计算机软件开发及销售"));
+        company
+            .add_permitted_activity("计算机软件开发及销售".to_string())
+            .unwrap();
+        assert!(company.can_perform_activity("计算机软件开发及销售"));
+        assert!(company
+            .add_permitted_activity("软件开发培训".to_string())
+            .is_err());
+        assert!(company.can_perform_activity("应用软件开发"));
         assert!(!company.can_perform_activity("房地产开发"));
 
-        // 添加限制
+        // 添加限制，限制涵盖下属行业
+        company.add_restriction("6513".to_string()).unwrap();
+        assert!(!company.can_perform_activity("应用软件开发"));
+        assert!(company.can_perform_activity("基础软件开发"));
         company.add_restriction("软件开发".to_string()).unwrap();
         assert!(!company.can_perform_activity("软件开发"));
     }
@@ -357,11 +1339,18 @@ mod tests {
     fn test_business_status_change() {
         let mut company = LegalPerson::new(
             LegalPersonType::Company(CompanyType::Limited),
-            1_000_000.0,
+            Money::cny(Decimal::from(1_000_000)),
             Uuid::new_v4(),
             "北京市朝阳区xxx街道".to_string(),
             Utc::now(),
         );
+        company
+            .register(
+                "北京市朝阳区市场监督管理局".to_string(),
+                "91110105MA00000000".to_string(),
+                Utc::now(),
+            )
+            .unwrap();
 
         company
             .add_permitted_activity("软件开发".to_string())
@@ -374,4 +1363,226 @@ mod tests {
             .unwrap();
         assert!(!company.can_perform_activity("软件开发"));
     }
+
+    #[test]
+    fn test_dissolution_and_liquidation() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+        ));
+        let mut company = LegalPerson::with_clock(
+            LegalPersonType::Company(CompanyType::Limited),
+            Money::cny(Decimal::from(1_000_000)),
+            Uuid::new_v4(),
+            "北京市朝阳区xxx街道".to_string(),
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+            clock.clone(),
+        );
+        company
+            .add_permitted_activity("软件开发".to_string())
+            .unwrap();
+
+        // 成立之前不具有民事行为能力
+        assert_eq!(company.phase(), LegalPersonPhase::NotEstablished);
+        assert!(!company.has_capacity());
+
+        // 公司经登记成立，未登记的即使到了拟成立的日期也尚未成立
+        clock.set(Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap());
+        assert_eq!(company.phase(), LegalPersonPhase::NotEstablished);
+        let register = |company: &mut LegalPerson, number: &str, at| {
+            company.register(
+                "北京市朝阳区市场监督管理局".to_string(),
+                number.to_string(),
+                at,
+            )
+        };
+        // 登记日期不能晚于记录之时；事后记录的，自核准登记之日起成立
+        assert!(register(
+            &mut company,
+            "91110105MA00000000",
+            Utc.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap()
+        )
+        .is_err());
+        let registered = Utc.with_ymd_and_hms(2024, 3, 5, 0, 0, 0).unwrap();
+        register(&mut company, "91110105MA00000000", registered).unwrap();
+        assert_eq!(company.phase(), LegalPersonPhase::Active);
+        assert_eq!(company.establishment_date(), registered);
+        assert!(!company.has_capacity_at(registered - Duration::days(1)));
+        assert!(company.can_perform_activity("软件开发"));
+        assert!(register(&mut company, "91110105MA00000001", clock.now()).is_err());
+
+        // 解散须组成清算组；清算期间只能从事与清算有关的活动
+        let resolution = DissolutionCause::Resolution {
+            resolution: "股东会决议解散".to_string(),
+        };
+        assert!(company.dissolve(resolution.clone(), vec![]).is_err());
+        clock.advance(Duration::days(365));
+        company.dissolve(resolution, vec![Uuid::new_v4()]).unwrap();
+        assert_eq!(company.phase(), LegalPersonPhase::Liquidating);
+        assert!(company.has_capacity());
+        assert!(!company.can_perform_activity("软件开发"));
+        assert!(company.can_perform_activity("清偿债务"));
+
+        // 清算结束并完成注销登记时，法人终止
+        assert!(company.deregister().is_err());
+        clock.advance(Duration::days(90));
+        company.complete_liquidation().unwrap();
+        company.deregister().unwrap();
+        assert_eq!(company.phase(), LegalPersonPhase::Terminated);
+        assert!(!company.has_capacity());
+        assert!(!company.can_perform_activity("清偿债务"));
+        assert!(company.has_capacity_at(Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_sync_merger_and_division() {
+        let clock: Arc<dyn Clock> = Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let company = || {
+            let company = SyncLegalPerson::with_clock(
+                LegalPersonType::Company(CompanyType::Limited),
+                Money::cny(Decimal::from(1_000_000)),
+                Uuid::new_v4(),
+                "北京市朝阳区xxx街道".to_string(),
+                Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+                clock.clone(),
+            );
+            company
+                .register(
+                    "北京市朝阳区市场监督管理局".to_string(),
+                    "91110105MA00000000".to_string(),
+                    Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+                )
+                .unwrap();
+            company
+        };
+        let (a, b, merged) = (company(), company(), company());
+        let mut registry = SuccessionRegistry::new();
+
+        SyncLegalPerson::consolidate(&[&a, &b], &merged, &mut registry).unwrap();
+        assert_eq!(a.phase(), LegalPersonPhase::Terminated);
+        assert!(!b.has_capacity());
+        assert_eq!(registry.current_successors(a.id()), vec![merged.id()]);
+
+        // 已注销的法人不能再合并
+        assert!(merged.absorb(&a, &mut registry).is_err());
+
+        let (east, west) = (company(), company());
+        // 新设分立中本法人注销，不能同时作为分立后的法人
+        assert!(merged
+            .divide(&[&merged, &east], false, &mut registry)
+            .is_err());
+        assert_eq!(merged.phase(), LegalPersonPhase::Active);
+        merged
+            .divide(&[&east, &west], false, &mut registry)
+            .unwrap();
+        assert_eq!(merged.phase(), LegalPersonPhase::Terminated);
+        assert_eq!(
+            registry.current_successors(b.id()),
+            vec![east.id(), west.id()]
+        );
+        assert!(registry.is_jointly_liable(b.id()));
+    }
+
+    #[test]
+    fn test_legal_person_classification() {
+        let company = LegalPersonType::Company(CompanyType::Limited);
+        assert_eq!(company.category(), LegalPersonCategory::ForProfit);
+        assert!(company.may_distribute_profits() && company.can_be_guarantor());
+
+        let foundation = LegalPersonType::Foundation;
+        assert_eq!(foundation.category(), LegalPersonCategory::NonProfit);
+        assert!(!foundation.may_distribute_profits() && !foundation.can_be_guarantor());
+
+        let cooperative = LegalPersonType::Cooperative;
+        assert_eq!(cooperative.category(), LegalPersonCategory::Special);
+        assert!(cooperative.may_distribute_profits());
+        assert!(!LegalPersonType::StateOrgan.can_be_guarantor());
+    }
+
+    #[test]
+    fn test_company_cap_table() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2024, 7, 1, 0, 0, 0).unwrap(),
+        ));
+        let company = SyncLegalPerson::with_clock(
+            LegalPersonType::Company(CompanyType::Limited),
+            Money::cny(Decimal::from(1_000_000)),
+            Uuid::new_v4(),
+            "北京市朝阳区xxx街道".to_string(),
+            clock.now(),
+            clock.clone(),
+        );
+        let (founder, investor) = (Uuid::new_v4(), Uuid::new_v4());
+        let due = clock.now() + Duration::days(180);
+        company
+            .subscribe_capital(founder, Money::cny(Decimal::from(800_000)), due)
+            .unwrap();
+        assert!(company
+            .subscribe_capital(investor, Money::cny(Decimal::from(300_000)), due)
+            .is_err());
+        company
+            .subscribe_capital(investor, Money::cny(Decimal::from(200_000)), due)
+            .unwrap();
+        company
+            .pay_in_capital(founder, Money::cny(Decimal::from(800_000)))
+            .unwrap();
+
+        clock.advance(Duration::days(181));
+        let defaulters = company.defaulting_shareholders();
+        assert_eq!(defaulters.len(), 1);
+        assert_eq!(defaulters[0].shareholder, investor);
+
+        // 股东之间转让股权无需其他股东同意
+        company
+            .transfer_equity(
+                investor,
+                founder,
+                Money::cny(Decimal::from(200_000)),
+                Money::cny(Decimal::from(1)),
+            )
+            .unwrap();
+        let table = company.cap_table().unwrap();
+        assert_eq!(table.equity_ratio(founder), Decimal::ONE);
+        assert!(company.defaulting_shareholders()[0].shareholder == founder);
+
+        let foundation = LegalPerson::new(
+            LegalPersonType::Foundation,
+            Money::cny(Decimal::from(8_000_000)),
+            Uuid::new_v4(),
+            "北京市海淀区xxx街道".to_string(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+        );
+        assert!(foundation.cap_table().is_none());
+    }
+
+    #[test]
+    fn test_capital_reduction_respects_subscriptions() {
+        let mut company = LegalPerson::new(
+            LegalPersonType::Company(CompanyType::Limited),
+            Money::cny(Decimal::from(1_000_000)),
+            Uuid::new_v4(),
+            "北京市朝阳区xxx街道".to_string(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+        );
+        let due = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        company
+            .subscribe_capital(Uuid::new_v4(), Money::cny(Decimal::from(600_000)), due)
+            .unwrap();
+
+        // 减资后的注册资本不得低于已认缴的出资额
+        assert!(company
+            .update_registered_capital(Money::cny(Decimal::from(500_000)))
+            .is_err());
+        assert!(company
+            .update_registered_capital(Money::new(Decimal::from(800_000), Currency::USD))
+            .is_err());
+        company
+            .update_registered_capital(Money::cny(Decimal::from(600_000)))
+            .unwrap();
+        assert_eq!(
+            company.registered_capital(),
+            Money::cny(Decimal::from(600_000))
+        );
+    }
 }
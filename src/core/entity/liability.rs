@@ -0,0 +1,420 @@
+//! 非法人组织的债务承担
+//! 对应《民法典》第一百零四条：非法人组织的财产不足以清偿债务的，其出资人或者设立人承担无限责任。
+//! 合伙企业中，普通合伙人对合伙企业债务承担无限连带责任，有限合伙人以其认缴的出资额为限承担责任（《合伙企业法》第二条）；
+//! 特殊普通合伙企业的合伙人在执业活动中因故意或者重大过失造成的债务，由该合伙人承担无限责任或者无限连带责任，
+//! 其他合伙人以其在合伙企业中的财产份额为限承担责任（第五十七条）。
+
+use crate::core::entity::partnership::{liability_for_debt, PartnerChange};
+use crate::core::entity::unincorporated::{
+    LiabilityType, Partner, PartnershipType, UnincorporatedOrgType,
+};
+use crate::core::money::Money;
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+/// 债务的成因
+#[derive(Debug, Clone, PartialEq)]
+pub enum DebtCause {
+    /// 一般债务
+    Ordinary,
+    /// 特殊普通合伙企业的合伙人在执业活动中因故意或者重大过失造成的债务
+    PracticeMisconduct { culpable: Vec<Uuid> },
+}
+
+/// 非法人组织的一笔债务
+#[derive(Debug, Clone)]
+pub struct Debt {
+    amount: Money,              // 债务数额
+    incurred_at: DateTime<Utc>, // 发生之日
+    cause: DebtCause,           // 成因
+}
+
+impl Debt {
+    pub fn new(amount: Money, incurred_at: DateTime<Utc>, cause: DebtCause) -> FanResult<Self> {
+        if !amount.is_positive() {
+            return Err(FanError::validation(
+                "债务数额必须大于零",
+                ValidationErrorType::EntityError,
+                "new",
+                "Debt",
+            ));
+        }
+        Ok(Self {
+            amount,
+            incurred_at,
+            cause,
+        })
+    }
+
+    /// 获取债务数额
+    pub fn amount(&self) -> Money {
+        self.amount
+    }
+
+    /// 获取发生之日
+    pub fn incurred_at(&self) -> DateTime<Utc> {
+        self.incurred_at
+    }
+
+    /// 获取成因
+    pub fn cause(&self) -> &DebtCause {
+        &self.cause
+    }
+}
+
+/// 成员对组织财产不足清偿部分的责任
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemberExposure {
+    pub member: Uuid,                  // 成员ID
+    pub liability_type: LiabilityType, // 对该笔债务的责任方式
+    pub exposure: Money,               // 债权人可以向其请求清偿的最高数额
+    pub share: Money,                  // 成员之间内部分担的数额
+}
+
+/// 一笔债务在组织及其成员之间的分担
+#[derive(Debug, Clone)]
+pub struct LiabilityAllocation {
+    debt: Money,                    // 债务数额
+    paid_by_org: Money,             // 以组织财产清偿的数额
+    shortfall: Money,               // 组织财产不足清偿的数额
+    exposures: Vec<MemberExposure>, // 各成员的责任
+    unallocated: Money,             // 无人分担的数额
+}
+
+impl LiabilityAllocation {
+    /// 获取债务数额
+    pub fn debt(&self) -> Money {
+        self.debt
+    }
+
+    /// 获取以组织财产清偿的数额
+    pub fn paid_by_org(&self) -> Money {
+        self.paid_by_org
+    }
+
+    /// 获取组织财产不足清偿的数额
+    pub fn shortfall(&self) -> Money {
+        self.shortfall
+    }
+
+    /// 获取各成员的责任
+    pub fn exposures(&self) -> &[MemberExposure] {
+        &self.exposures
+    }
+
+    /// 获取某一成员的责任
+    pub fn exposure_of(&self, member: Uuid) -> Option<&MemberExposure> {
+        self.exposures.iter().find(|e| e.member == member)
+    }
+
+    /// 获取内部分担后仍无人承担的数额，如有限合伙人的出资额不足且没有普通合伙人
+    pub fn unallocated(&self) -> Money {
+        self.unallocated
+    }
+}
+
+/// 分担一笔债务
+///
+/// 先以组织的财产清偿；不足部分，个人独资企业由投资人承担无限责任，分支机构由其所属的法人或者非法人组织承担
+/// （《民法典》第七十四条），合伙企业的合伙人按其对该笔债务的责任方式承担：
+/// 承担无限责任的，债权人可以请求其清偿全部不足部分；承担有限责任的，以出资额为限。合伙人之间按利润分配比例分担，
+/// 有限合伙人超出其出资额的部分由承担无限责任的合伙人按比例分担。退伙人对其退伙前发生的债务承担责任。
+pub(crate) fn allocate(
+    org_type: &UnincorporatedOrgType,
+    bearer: Option<Uuid>,
+    members: &[Partner],
+    changes: &[PartnerChange],
+    assets: Money,
+    debt: &Debt,
+) -> FanResult<LiabilityAllocation> {
+    let currency = debt.amount.currency();
+    let zero = Money::zero(currency);
+    let available = if assets.is_positive() { assets } else { zero };
+    let paid_by_org = match available.checked_cmp(&debt.amount)? {
+        Ordering::Less => available,
+        _ => debt.amount,
+    };
+    let shortfall = debt.amount.checked_sub(&paid_by_org)?;
+
+    let mut exposures = Vec::new();
+    let mut unallocated = shortfall;
+    match org_type {
+        UnincorporatedOrgType::Partnership(partnership_type) if shortfall.is_positive() => {
+            let culpable = match (&debt.cause, partnership_type) {
+                (DebtCause::PracticeMisconduct { culpable }, PartnershipType::Special) => {
+                    Some(culpable.as_slice())
+                }
+                _ => None,
+            };
+            exposures = allocate_among_partners(
+                liable_partners(members, changes, debt.incurred_at, culpable),
+                shortfall,
+                culpable.is_some(),
+            )?;
+            let shares: Vec<Money> = exposures.iter().map(|e| e.share).collect();
+            unallocated = shortfall.checked_sub(&Money::sum(&shares, currency)?)?;
+        }
+        UnincorporatedOrgType::IndividualBusiness | UnincorporatedOrgType::Branch
+            if shortfall.is_positive() =>
+        {
+            if let Some(bearer) = bearer {
+                exposures.push(MemberExposure {
+                    member: bearer,
+                    liability_type: LiabilityType::Unlimited,
+                    exposure: shortfall,
+                    share: shortfall,
+                });
+                unallocated = zero;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(LiabilityAllocation {
+        debt: debt.amount,
+        paid_by_org,
+        shortfall,
+        exposures,
+        unallocated,
+    })
+}
+
+/// 对该笔债务承担责任的合伙人及其责任方式，包括债务发生后退伙或被除名的合伙人
+fn liable_partners<'a>(
+    members: &'a [Partner],
+    changes: &'a [PartnerChange],
+    incurred_at: DateTime<Utc>,
+    culpable: Option<&[Uuid]>,
+) -> Vec<(&'a Partner, LiabilityType)> {
+    let mut candidates: Vec<&Partner> = members.iter().collect();
+    for change in changes.iter().rev().filter(|c| c.ends_membership()) {
+        if candidates.iter().all(|p| p.id() != change.partner().id()) {
+            candidates.push(change.partner());
+        }
+    }
+    candidates
+        .into_iter()
+        .filter_map(|partner| {
+            let liability = liability_for_debt(members, changes, partner.id(), incurred_at)?;
+            Some(match culpable {
+                // 有过错的合伙人承担无限连带责任，其他合伙人的责任以其在合伙企业中的财产份额为限
+                Some(culpable) if culpable.contains(&partner.id()) => {
+                    (partner, LiabilityType::Unlimited)
+                }
+                Some(_) => (partner, LiabilityType::Limited),
+                None => (partner, liability),
+            })
+        })
+        .collect()
+}
+
+fn allocate_among_partners(
+    liable: Vec<(&Partner, LiabilityType)>,
+    shortfall: Money,
+    misconduct: bool,
+) -> FanResult<Vec<MemberExposure>> {
+    let currency = shortfall.currency();
+    let zero = Money::zero(currency);
+    let ratio = |partner: &Partner| {
+        Decimal::try_from(partner.profit_sharing_ratio()).unwrap_or(Decimal::ZERO)
+    };
+    // 特殊普通合伙企业中无过错的合伙人，其财产份额已计入合伙企业的财产
+    let cap = |partner: &Partner, liability: &LiabilityType| match liability {
+        LiabilityType::Unlimited => None,
+        LiabilityType::Limited if misconduct => Some(zero),
+        LiabilityType::Limited => Some(partner.contribution()),
+    };
+
+    let total_ratio: Decimal = liable.iter().map(|(p, _)| ratio(p)).sum();
+    let mut exposures = Vec::with_capacity(liable.len());
+    let mut excess = zero;
+    for (partner, liability) in &liable {
+        let cap = cap(partner, liability);
+        let proportional = if total_ratio.is_zero() {
+            shortfall.checked_mul(Decimal::ONE / Decimal::from(liable.len()))?
+        } else {
+            shortfall.checked_mul(ratio(partner) / total_ratio)?
+        };
+        let (exposure, share) = match cap {
+            None => (shortfall, proportional),
+            Some(cap) => {
+                let exposure = min(cap, shortfall)?;
+                let share = min(cap, proportional)?;
+                excess = excess.checked_add(&proportional.checked_sub(&share)?)?;
+                (exposure, share)
+            }
+        };
+        exposures.push(MemberExposure {
+            member: partner.id(),
+            liability_type: liability.clone(),
+            exposure,
+            share,
+        });
+    }
+
+    // 承担有限责任的合伙人超出其责任限额的部分，由承担无限责任的合伙人按利润分配比例分担
+    let unlimited: Vec<(usize, Decimal)> = liable
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, l))| *l == LiabilityType::Unlimited)
+        .map(|(i, (p, _))| (i, ratio(p)))
+        .collect();
+    let unlimited_ratio: Decimal = unlimited.iter().map(|(_, r)| *r).sum();
+    if excess.is_positive() {
+        for (i, r) in &unlimited {
+            let portion = if unlimited_ratio.is_zero() {
+                Decimal::ONE / Decimal::from(unlimited.len())
+            } else {
+                *r / unlimited_ratio
+            };
+            exposures[*i].share = exposures[*i]
+                .share
+                .checked_add(&excess.checked_mul(portion)?)?;
+        }
+    }
+    Ok(exposures)
+}
+
+fn min(a: Money, b: Money) -> FanResult<Money> {
+    Ok(match a.checked_cmp(&b)? {
+        Ordering::Greater => b,
+        _ => a,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clock::{Clock, ManualClock};
+    use crate::core::entity::unincorporated::PartnerType;
+    use crate::core::entity::{Entity, UnincorporatedOrg};
+    use crate::testing::{adult, fixed_clock, now};
+    use chrono::Duration;
+    use std::sync::Arc;
+
+    fn cny(amount: i64) -> Money {
+        Money::cny(Decimal::from(amount))
+    }
+
+    #[test]
+    fn test_liability_allocation() {
+        let clock = fixed_clock();
+        let mut fund = UnincorporatedOrg::with_clock(
+            UnincorporatedOrgType::Partnership(PartnershipType::Limited),
+            "上海市浦东新区xxx路".to_string(),
+            clock.now(),
+            clock.clone(),
+        );
+        let (gp, lp) = (adult(&fixed_clock()), adult(&fixed_clock()));
+        fund.add_partner(
+            Partner::new(
+                gp.id(),
+                PartnerType::GeneralPartner,
+                cny(100_000),
+                0.2,
+                LiabilityType::Unlimited,
+            ),
+            &gp,
+        )
+        .unwrap();
+        fund.add_partner(
+            Partner::new(
+                lp.id(),
+                PartnerType::LimitedPartner,
+                cny(200_000),
+                0.8,
+                LiabilityType::Limited,
+            ),
+            &lp,
+        )
+        .unwrap();
+
+        // 合伙企业的财产足以清偿的，合伙人不承担责任
+        let small = Debt::new(cny(100_000), clock.now(), DebtCause::Ordinary).unwrap();
+        let allocation = fund.allocate_liability(cny(300_000), &small).unwrap();
+        assert!(allocation.shortfall().is_zero());
+        assert!(allocation.exposures().is_empty());
+
+        // 有限合伙人以出资额为限，超出部分由普通合伙人承担
+        let large = Debt::new(cny(1_300_000), clock.now(), DebtCause::Ordinary).unwrap();
+        let allocation = fund.allocate_liability(cny(300_000), &large).unwrap();
+        assert_eq!(allocation.shortfall(), cny(1_000_000));
+        let general = allocation.exposure_of(gp.id()).unwrap();
+        assert_eq!(general.liability_type, LiabilityType::Unlimited);
+        assert_eq!(general.exposure, cny(1_000_000));
+        assert_eq!(general.share, cny(800_000));
+        let limited = allocation.exposure_of(lp.id()).unwrap();
+        assert_eq!(limited.exposure, cny(200_000));
+        assert_eq!(limited.share, cny(200_000));
+        assert!(allocation.unallocated().is_zero());
+    }
+
+    #[test]
+    fn test_special_partnership_misconduct() {
+        let clock = Arc::new(ManualClock::new(now()));
+        let mut firm = UnincorporatedOrg::with_clock(
+            UnincorporatedOrgType::Partnership(PartnershipType::Special),
+            "北京市朝阳区xxx大厦".to_string(),
+            clock.now(),
+            clock.clone(),
+        );
+        let (careless, careful) = (adult(&fixed_clock()), adult(&fixed_clock()));
+        for partner in [&careless, &careful] {
+            firm.add_partner(
+                Partner::new(
+                    partner.id(),
+                    PartnerType::GeneralPartner,
+                    cny(500_000),
+                    0.5,
+                    LiabilityType::Unlimited,
+                ),
+                partner,
+            )
+            .unwrap();
+        }
+
+        // 一般债务由全体合伙人承担无限连带责任
+        let ordinary = Debt::new(cny(2_000_000), clock.now(), DebtCause::Ordinary).unwrap();
+        let allocation = firm.allocate_liability(cny(1_000_000), &ordinary).unwrap();
+        assert_eq!(
+            allocation.exposure_of(careful.id()).unwrap().exposure,
+            cny(1_000_000)
+        );
+
+        // 执业过错造成的债务由有过错的合伙人承担无限责任，其他合伙人以其财产份额为限
+        let misconduct = Debt::new(
+            cny(2_000_000),
+            clock.now(),
+            DebtCause::PracticeMisconduct {
+                culpable: vec![careless.id()],
+            },
+        )
+        .unwrap();
+        let allocation = firm
+            .allocate_liability(cny(1_000_000), &misconduct)
+            .unwrap();
+        let culpable = allocation.exposure_of(careless.id()).unwrap();
+        assert_eq!(culpable.exposure, cny(1_000_000));
+        assert_eq!(culpable.share, cny(1_000_000));
+        let innocent = allocation.exposure_of(careful.id()).unwrap();
+        assert_eq!(innocent.liability_type, LiabilityType::Limited);
+        assert!(innocent.exposure.is_zero());
+
+        // 退伙人对其退伙前发生的债务仍承担无限连带责任
+        clock.advance(Duration::days(1));
+        firm.withdraw_partner(careful.id()).unwrap();
+        clock.advance(Duration::days(1));
+        let allocation = firm.allocate_liability(cny(1_000_000), &ordinary).unwrap();
+        assert_eq!(
+            allocation.exposure_of(careful.id()).unwrap().liability_type,
+            LiabilityType::Unlimited
+        );
+        let later = Debt::new(cny(2_000_000), clock.now(), DebtCause::Ordinary).unwrap();
+        let allocation = firm.allocate_liability(cny(1_000_000), &later).unwrap();
+        assert!(allocation.exposure_of(careful.id()).is_none());
+    }
+}
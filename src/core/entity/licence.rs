@@ -0,0 +1,198 @@
+//! 行政许可
+//! 从事许可经营项目的，应当依法取得相应的许可证，并在许可的范围和有效期内经营。
+
+use crate::core::entity::industry::IndustryCode;
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+
+/// 许可证
+#[derive(Debug, Clone)]
+pub struct Licence {
+    kind: String,                       // 许可证种类，如"食品经营许可证"
+    issuer: String,                     // 发证机关
+    number: String,                     // 许可证编号
+    activities: Vec<IndustryCode>,      // 许可经营的行业
+    valid_from: DateTime<Utc>,          // 有效期起始
+    valid_until: Option<DateTime<Utc>>, // 有效期届满，空为长期有效
+    revoked_at: Option<DateTime<Utc>>,  // 被吊销或撤销之日
+}
+
+impl Licence {
+    pub fn new(
+        kind: String,
+        issuer: String,
+        number: String,
+        activities: Vec<IndustryCode>,
+        valid_from: DateTime<Utc>,
+        valid_until: Option<DateTime<Utc>>,
+    ) -> FanResult<Self> {
+        if activities.is_empty() || valid_until.is_some_and(|until| until <= valid_from) {
+            return Err(FanError::validation(
+                "许可证须载明许可经营的行业，有效期届满之日须晚于起始之日",
+                ValidationErrorType::EntityError,
+                "new",
+                "Licence",
+            ));
+        }
+        Ok(Self {
+            kind,
+            issuer,
+            number,
+            activities,
+            valid_from,
+            valid_until,
+            revoked_at: None,
+        })
+    }
+
+    /// 许可证被吊销或撤销
+    pub fn revoke(&mut self, at: DateTime<Utc>) -> FanResult<()> {
+        if self.revoked_at.is_some() {
+            return Err(FanError::validation(
+                "许可证已被吊销",
+                ValidationErrorType::OperationSequenceWrong,
+                "revoke",
+                "Licence",
+            ));
+        }
+        self.revoked_at = Some(at);
+        Ok(())
+    }
+
+    /// 在指定时刻是否有效
+    pub fn is_valid_at(&self, at: DateTime<Utc>) -> bool {
+        self.valid_from <= at
+            && self.valid_until.is_none_or(|until| at < until)
+            && self.revoked_at.is_none_or(|revoked| at < revoked)
+    }
+
+    /// 在指定时刻是否许可从事某一行业
+    pub fn permits_at(&self, activity: &IndustryCode, at: DateTime<Utc>) -> bool {
+        self.is_valid_at(at) && self.activities.iter().any(|a| a.covers(activity))
+    }
+
+    /// 获取许可证种类
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// 获取发证机关
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// 获取许可证编号
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    /// 获取许可经营的行业
+    pub fn activities(&self) -> &[IndustryCode] {
+        &self.activities
+    }
+
+    /// 获取有效期起始
+    pub fn valid_from(&self) -> DateTime<Utc> {
+        self.valid_from
+    }
+
+    /// 获取有效期届满之日
+    pub fn valid_until(&self) -> Option<DateTime<Utc>> {
+        self.valid_until
+    }
+
+    /// 获取被吊销之日
+    pub fn revoked_at(&self) -> Option<DateTime<Utc>> {
+        self.revoked_at
+    }
+}
+
+/// 在持有的许可证中查找并吊销某一编号的许可证
+pub(crate) fn revoke_licence_in(
+    licences: &mut [Licence],
+    number: &str,
+    revoked_at: DateTime<Utc>,
+) -> FanResult<()> {
+    licences
+        .iter_mut()
+        .find(|l| l.number == number)
+        .ok_or_else(|| {
+            FanError::validation(
+                "未持有该编号的许可证",
+                ValidationErrorType::EntityError,
+                "revoke_licence",
+                "Licence",
+            )
+        })?
+        .revoke(revoked_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn date(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn drug_licence() -> Licence {
+        Licence::new(
+            "药品经营许可证".to_string(),
+            "北京市药品监督管理局".to_string(),
+            "京AA0000000".to_string(),
+            vec![IndustryCode::new("525").unwrap()],
+            date(2020, 1, 1),
+            Some(date(2025, 1, 1)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_licence_requires_activities_and_valid_term() {
+        let codes = vec![IndustryCode::new("525").unwrap()];
+        let licence = |activities, until| {
+            Licence::new(
+                "药品经营许可证".to_string(),
+                "北京市药品监督管理局".to_string(),
+                "京AA0000000".to_string(),
+                activities,
+                date(2020, 1, 1),
+                until,
+            )
+        };
+        assert!(licence(Vec::new(), None).is_err());
+        assert!(licence(codes.clone(), Some(date(2020, 1, 1))).is_err());
+        assert!(licence(codes, None).unwrap().is_valid_at(date(2099, 1, 1)));
+    }
+
+    #[test]
+    fn test_licence_valid_within_term() {
+        let licence = drug_licence();
+        assert!(!licence.is_valid_at(date(2019, 12, 31)));
+        assert!(licence.is_valid_at(date(2020, 1, 1)));
+        assert!(licence.is_valid_at(date(2024, 12, 31)));
+        assert!(!licence.is_valid_at(date(2025, 1, 1)));
+    }
+
+    #[test]
+    fn test_licence_covers_subclasses_only() {
+        let licence = drug_licence();
+        let at = date(2024, 6, 1);
+        assert!(licence.permits_at(&IndustryCode::new("5251").unwrap(), at));
+        assert!(licence.permits_at(&IndustryCode::new("525").unwrap(), at));
+        assert!(!licence.permits_at(&IndustryCode::new("52").unwrap(), at));
+        assert!(!licence.permits_at(&IndustryCode::new("5273").unwrap(), at));
+    }
+
+    #[test]
+    fn test_revoked_licence_lapses() {
+        let mut licence = drug_licence();
+        licence.revoke(date(2023, 1, 1)).unwrap();
+        assert!(licence.revoke(date(2023, 2, 1)).is_err());
+        assert!(licence.is_valid_at(date(2022, 12, 31)));
+        assert!(!licence.permits_at(&IndustryCode::new("5251").unwrap(), date(2023, 1, 1)));
+        assert_eq!(licence.revoked_at(), Some(date(2023, 1, 1)));
+    }
+}
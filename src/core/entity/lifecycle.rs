@@ -0,0 +1,431 @@
+//! 法人的设立、解散、清算与终止
+//! 对应《民法典》第五十七条至第七十五条：法人的民事权利能力和民事行为能力，从法人成立时产生，
+//! 到法人终止时消灭（第五十九条）。法人解散的，除合并或者分立的情形外，清算义务人应当及时组成清算组进行清算（第七十条）；
+//! 清算期间法人存续，但是不得从事与清算无关的活动（第七十二条）。清算结束并完成法人注销登记时，法人终止。
+//! 须经登记成立的法人，自登记之日起成立（第七十七条、第七十八条、第八十八条、第九十条、第九十二条）。
+
+use crate::core::entity::industry::IndustryCode;
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 清算期间可以从事的与清算有关的活动，以国民经济行业代码表示
+///
+/// 清理财产、分配剩余财产属于资产管理（7212），参与诉讼属于法律服务（7231），
+/// 通知债权人、清偿债务、处理未了结业务等清算事务属于其他法律服务（7239），
+/// 编制资产负债表、清缴税款属于会计、审计及税务服务（7241），催收债权属于信用服务（7295）。
+pub const LIQUIDATION_ACTIVITIES: &[&str] = &["7212", "7231", "7239", "7241", "7295"];
+
+/// 是否为与清算有关的活动，涵盖上述行业的下属行业
+pub fn is_liquidation_activity(code: &IndustryCode) -> bool {
+    LIQUIDATION_ACTIVITIES
+        .iter()
+        .filter_map(|c| IndustryCode::new(c).ok())
+        .any(|c| c.covers(code))
+}
+
+/// 法人所处的阶段
+#[derive(Debug, Clone, PartialEq)]
+pub enum LegalPersonPhase {
+    /// 尚未成立
+    NotEstablished,
+    /// 存续，可以在其经营范围内从事活动
+    Active,
+    /// 已解散，处于清算期间，只能从事与清算有关的活动（第七十二条第一款）
+    Liquidating,
+    /// 已注销登记，法人终止（第七十二条第三款）
+    Terminated,
+}
+
+/// 法人解散或终止的原因
+#[derive(Debug, Clone, PartialEq)]
+pub enum DissolutionCause {
+    /// 章程规定的存续期间届满或者章程规定的其他解散事由出现（第六十九条第一项）
+    ArticlesOfAssociation { reason: String },
+    /// 法人的权力机构决议解散（第六十九条第二项）
+    Resolution { resolution: String },
+    /// 因法人合并或者分立需要解散（第六十九条第三项），不经清算
+    MergerOrDivision,
+    /// 依法被吊销营业执照、登记证书，被责令关闭或者被撤销（第六十九条第四项）
+    LicenceRevoked { authority: String },
+    /// 被宣告破产，依法进行破产清算（第七十三条）
+    Bankruptcy { court: String },
+    /// 法律规定的其他情形（第六十九条第五项）
+    Other(String),
+}
+
+impl DissolutionCause {
+    /// 该原因是否须经清算，合并或者分立的由继受主体承继权利义务（第六十七条、第七十条）
+    pub fn requires_liquidation(&self) -> bool {
+        !matches!(self, DissolutionCause::MergerOrDivision)
+    }
+}
+
+/// 法人登记
+#[derive(Debug, Clone)]
+pub struct Registration {
+    authority: String,            // 登记机关
+    number: String,               // 统一社会信用代码或登记证号
+    registered_at: DateTime<Utc>, // 登记日期
+}
+
+impl Registration {
+    pub fn new(authority: String, number: String, registered_at: DateTime<Utc>) -> Self {
+        Self {
+            authority,
+            number,
+            registered_at,
+        }
+    }
+
+    /// 获取登记机关
+    pub fn authority(&self) -> &str {
+        &self.authority
+    }
+
+    /// 获取登记证号
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+
+    /// 获取登记日期
+    pub fn registered_at(&self) -> DateTime<Utc> {
+        self.registered_at
+    }
+}
+
+/// 法人的解散及清算
+#[derive(Debug, Clone)]
+pub struct Dissolution {
+    cause: DissolutionCause,                         // 解散原因
+    dissolved_at: DateTime<Utc>,                     // 解散事由出现之日
+    liquidators: Vec<Uuid>,                          // 清算组成员（清算义务人）
+    liquidation_completed_at: Option<DateTime<Utc>>, // 清算结束之日
+}
+
+impl Dissolution {
+    /// 获取解散原因
+    pub fn cause(&self) -> &DissolutionCause {
+        &self.cause
+    }
+
+    /// 获取解散日期
+    pub fn dissolved_at(&self) -> DateTime<Utc> {
+        self.dissolved_at
+    }
+
+    /// 获取清算组成员
+    pub fn liquidators(&self) -> &[Uuid] {
+        &self.liquidators
+    }
+
+    /// 获取清算结束之日
+    pub fn liquidation_completed_at(&self) -> Option<DateTime<Utc>> {
+        self.liquidation_completed_at
+    }
+}
+
+/// 法人从成立到终止的记录
+#[derive(Debug, Clone)]
+pub struct LegalPersonLifecycle {
+    established_at: DateTime<Utc>, // 成立日期，须经登记成立的以登记之日为准
+    requires_registration: bool,   // 是否须经登记成立
+    registration: Option<Registration>, // 法人登记
+    dissolution: Option<Dissolution>, // 解散及清算
+    deregistered_at: Option<DateTime<Utc>>, // 注销登记之日
+}
+
+impl LegalPersonLifecycle {
+    pub(crate) fn new(established_at: DateTime<Utc>, requires_registration: bool) -> Self {
+        Self {
+            established_at,
+            requires_registration,
+            registration: None,
+            dissolution: None,
+            deregistered_at: None,
+        }
+    }
+
+    /// 在指定时刻所处的阶段
+    ///
+    /// 须经登记成立的法人，登记之前尚未成立。
+    pub fn phase_at(&self, at: DateTime<Utc>) -> LegalPersonPhase {
+        if self.deregistered_at.is_some_and(|d| d <= at) {
+            LegalPersonPhase::Terminated
+        } else if self
+            .dissolution
+            .as_ref()
+            .is_some_and(|d| d.dissolved_at <= at)
+        {
+            LegalPersonPhase::Liquidating
+        } else if self.established_by(at) {
+            LegalPersonPhase::Active
+        } else {
+            LegalPersonPhase::NotEstablished
+        }
+    }
+
+    /// 在指定时刻是否已经成立
+    fn established_by(&self, at: DateTime<Utc>) -> bool {
+        if self.requires_registration {
+            self.registration
+                .as_ref()
+                .is_some_and(|r| r.registered_at <= at)
+        } else {
+            self.established_at <= at
+        }
+    }
+
+    /// 在指定时刻是否存续，清算期间法人仍然存续
+    pub fn exists_at(&self, at: DateTime<Utc>) -> bool {
+        matches!(
+            self.phase_at(at),
+            LegalPersonPhase::Active | LegalPersonPhase::Liquidating
+        )
+    }
+
+    /// 记录法人登记（第五十八条第二款），须经登记成立的法人自登记之日起成立
+    ///
+    /// 法人只能办理一次设立登记，已经登记的不得重复登记。
+    pub(crate) fn register(&mut self, registration: Registration) -> FanResult<()> {
+        if self.registration.is_some() {
+            return Err(FanError::validation(
+                "法人已经办理登记，不得重复登记",
+                ValidationErrorType::OperationSequenceWrong,
+                "register",
+                "LegalPerson",
+            ));
+        }
+        if self.phase_at(registration.registered_at) == LegalPersonPhase::Terminated {
+            return Err(FanError::validation(
+                "已终止的法人不能办理登记",
+                ValidationErrorType::EntityStatusIllegal,
+                "register",
+                "LegalPerson",
+            ));
+        }
+        if self.requires_registration {
+            self.established_at = registration.registered_at;
+        }
+        self.registration = Some(registration);
+        Ok(())
+    }
+
+    /// 解散事由出现，进入清算期间（第六十九条、第七十条）
+    pub(crate) fn dissolve(
+        &mut self,
+        cause: DissolutionCause,
+        liquidators: Vec<Uuid>,
+        at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        if self.dissolution.is_some() || self.phase_at(at) != LegalPersonPhase::Active {
+            return Err(FanError::validation_with_ref(
+                "只有存续的法人才能解散",
+                ValidationErrorType::OperationSequenceWrong,
+                "《民法典》第六十九条",
+                "dissolve",
+                "LegalPerson",
+            ));
+        }
+        if cause.requires_liquidation() && liquidators.is_empty() {
+            return Err(FanError::validation_with_ref(
+                "法人解散的，清算义务人应当及时组成清算组进行清算",
+                ValidationErrorType::EntityRelationMalformed,
+                "《民法典》第七十条",
+                "dissolve",
+                "LegalPerson",
+            ));
+        }
+        self.dissolution = Some(Dissolution {
+            cause,
+            dissolved_at: at,
+            liquidators,
+            liquidation_completed_at: None,
+        });
+        Ok(())
+    }
+
+    /// 清算结束（第七十一条）
+    pub(crate) fn complete_liquidation(&mut self, at: DateTime<Utc>) -> FanResult<()> {
+        let phase = self.phase_at(at);
+        match &mut self.dissolution {
+            Some(dissolution)
+                if phase == LegalPersonPhase::Liquidating
+                    && dissolution.liquidation_completed_at.is_none() =>
+            {
+                dissolution.liquidation_completed_at = Some(at);
+                Ok(())
+            }
+            _ => Err(FanError::validation(
+                "法人不处于清算期间",
+                ValidationErrorType::OperationSequenceWrong,
+                "complete_liquidation",
+                "LegalPerson",
+            )),
+        }
+    }
+
+    /// 注销登记，法人终止（第七十二条第三款、第七十三条）
+    ///
+    /// 须经清算的，清算结束后才能注销；因合并或者分立解散的可以直接注销。
+    pub(crate) fn deregister(&mut self, at: DateTime<Utc>) -> FanResult<()> {
+        let ready = self.phase_at(at) == LegalPersonPhase::Liquidating
+            && self.dissolution.as_ref().is_some_and(|d| {
+                !d.cause.requires_liquidation() || d.liquidation_completed_at.is_some()
+            });
+        if !ready {
+            return Err(FanError::validation_with_ref(
+                "清算结束后才能办理法人注销登记",
+                ValidationErrorType::OperationSequenceWrong,
+                "《民法典》第七十二条第三款",
+                "deregister",
+                "LegalPerson",
+            ));
+        }
+        self.deregistered_at = Some(at);
+        Ok(())
+    }
+
+    /// 获取成立日期，须经登记成立而尚未登记的为拟成立的日期
+    pub fn established_at(&self) -> DateTime<Utc> {
+        self.established_at
+    }
+
+    /// 是否须经登记成立
+    pub fn requires_registration(&self) -> bool {
+        self.requires_registration
+    }
+
+    /// 获取法人登记
+    pub fn registration(&self) -> Option<&Registration> {
+        self.registration.as_ref()
+    }
+
+    /// 获取解散及清算记录
+    pub fn dissolution(&self) -> Option<&Dissolution> {
+        self.dissolution.as_ref()
+    }
+
+    /// 获取注销登记之日
+    pub fn deregistered_at(&self) -> Option<DateTime<Utc>> {
+        self.deregistered_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::entity::industry::IndustryCatalogue;
+    use chrono::{Duration, TimeZone};
+
+    fn date(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+    }
+
+    fn registration(at: DateTime<Utc>) -> Registration {
+        Registration::new(
+            "北京市朝阳区市场监督管理局".to_string(),
+            "91110105MA00000000".to_string(),
+            at,
+        )
+    }
+
+    #[test]
+    fn test_registration_establishes_legal_person() {
+        let planned = date(2024, 3, 1);
+        let mut lifecycle = LegalPersonLifecycle::new(planned, true);
+        assert_eq!(
+            lifecycle.phase_at(planned + Duration::days(30)),
+            LegalPersonPhase::NotEstablished
+        );
+
+        // 自登记之日起成立
+        let registered = date(2024, 3, 15);
+        lifecycle.register(registration(registered)).unwrap();
+        assert_eq!(lifecycle.established_at(), registered);
+        assert_eq!(
+            lifecycle.phase_at(registered - Duration::days(1)),
+            LegalPersonPhase::NotEstablished
+        );
+        assert_eq!(lifecycle.phase_at(registered), LegalPersonPhase::Active);
+
+        // 不得重复登记
+        assert!(lifecycle
+            .register(registration(registered + Duration::days(1)))
+            .is_err());
+        assert_eq!(
+            lifecycle.registration().unwrap().registered_at(),
+            registered
+        );
+    }
+
+    #[test]
+    fn test_established_without_registration() {
+        let established = date(2024, 3, 1);
+        let mut lifecycle = LegalPersonLifecycle::new(established, false);
+        assert_eq!(lifecycle.phase_at(established), LegalPersonPhase::Active);
+
+        lifecycle.register(registration(date(2024, 5, 1))).unwrap();
+        assert_eq!(lifecycle.established_at(), established);
+    }
+
+    #[test]
+    fn test_liquidation_sequence() {
+        let established = date(2020, 1, 1);
+        let mut lifecycle = LegalPersonLifecycle::new(established, false);
+        let dissolved = date(2024, 1, 1);
+
+        // 须组成清算组，清算结束后才能注销
+        let resolution = DissolutionCause::Resolution {
+            resolution: "股东会决议解散".to_string(),
+        };
+        assert!(lifecycle
+            .dissolve(resolution.clone(), vec![], dissolved)
+            .is_err());
+        lifecycle
+            .dissolve(resolution, vec![Uuid::new_v4()], dissolved)
+            .unwrap();
+        assert_eq!(lifecycle.phase_at(dissolved), LegalPersonPhase::Liquidating);
+        assert!(lifecycle.exists_at(dissolved));
+        assert!(lifecycle.deregister(dissolved).is_err());
+
+        let completed = dissolved + Duration::days(90);
+        lifecycle.complete_liquidation(completed).unwrap();
+        lifecycle.deregister(completed).unwrap();
+        assert_eq!(lifecycle.phase_at(completed), LegalPersonPhase::Terminated);
+        assert!(lifecycle.exists_at(completed - Duration::days(1)));
+        assert!(lifecycle.register(registration(completed)).is_err());
+    }
+
+    #[test]
+    fn test_merger_skips_liquidation() {
+        let mut lifecycle = LegalPersonLifecycle::new(date(2020, 1, 1), false);
+        let at = date(2024, 1, 1);
+        lifecycle
+            .dissolve(DissolutionCause::MergerOrDivision, vec![], at)
+            .unwrap();
+        lifecycle.deregister(at).unwrap();
+        assert_eq!(lifecycle.phase_at(at), LegalPersonPhase::Terminated);
+    }
+
+    #[test]
+    fn test_liquidation_activities_by_industry_code() {
+        let catalogue = IndustryCatalogue::standard();
+        for activity in [
+            "清偿债务",
+            "参与诉讼",
+            "清缴税款",
+            "催收债权",
+            "分配剩余财产",
+        ] {
+            let codes = catalogue.resolve_or_err(activity, "test").unwrap();
+            assert!(codes.iter().all(is_liquidation_activity), "{activity}");
+        }
+        assert!(is_liquidation_activity(&IndustryCode::new("7239").unwrap()));
+        assert!(!is_liquidation_activity(
+            &catalogue.resolve_or_err("软件开发", "test").unwrap()[0]
+        ));
+        assert!(!is_liquidation_activity(&IndustryCode::new("72").unwrap()));
+    }
+}
@@ -1,13 +1,65 @@
+mod absence;
+mod adjudication;
 mod base;
+mod cap_table;
+mod emancipation;
+mod formation;
+mod guardianship;
+mod household;
+mod industry;
 mod legal_person;
+mod liability;
+mod licence;
+mod lifecycle;
 mod natural_person;
+mod partnership;
+mod resolution;
+mod staff;
+mod succession;
 mod unincorporated;
+pub use absence::{AbsenceDeclaration, AbsenceKind, CivilStatus, DisappearanceCause};
+pub use adjudication::{AdjudicationKind, Applicant, CapacityAdjudication};
 pub use base::{
     AuthorityScope, AuthorityStatus, BaseEntity, BusinessScope, BusinessStatus, CapacityStatus,
     Entity, EntityType, NaturalCapacity,
 };
-pub use legal_person::LegalPerson;
-pub use legal_person::{CompanyType, LegalPersonType};
-pub use natural_person::NaturalPerson;
-pub use natural_person::{Guardianship, GuardianshipScope, MentalStatus};
-pub use unincorporated::UnincorporatedOrg;
+pub use cap_table::{
+    CapTable, ContributionDefault, ShareTransfer, Shareholding, Tranche, TransferStatus,
+    PREEMPTION_PERIOD_DAYS,
+};
+pub use emancipation::SelfSupportBasis;
+pub use formation::{FormationStatus, LegalPersonInFormation, SyncLegalPersonInFormation};
+pub use guardianship::{
+    BasisSource, CandidateAssessment, CandidateOutcome, Designation, GuardianCandidate,
+    GuardianSelection, Guardianship, GuardianshipAction, GuardianshipAuthority, GuardianshipBasis,
+    GuardianshipRuling, GuardianshipScope, Relationship, TerminationCause, WardCategory,
+};
+pub use household::{Household, HouseholdType, OperationMode, SyncHousehold};
+pub use industry::{
+    ActivityRegulation, ActivityResolution, IndustryCatalogue, IndustryCode, IndustryEntry,
+    IndustryLevel,
+};
+pub use legal_person::{CompanyType, LegalPersonCategory, LegalPersonType};
+pub use legal_person::{LegalPerson, SyncLegalPerson};
+pub use liability::{Debt, DebtCause, LiabilityAllocation, MemberExposure};
+pub use licence::Licence;
+pub use lifecycle::{
+    is_liquidation_activity, Dissolution, DissolutionCause, LegalPersonLifecycle, LegalPersonPhase,
+    Registration, LIQUIDATION_ACTIVITIES,
+};
+pub use natural_person::{MentalStatus, NaturalPerson, SyncNaturalPerson};
+pub use partnership::{
+    check_general_partner, CompositionFollowUp, ExpulsionGround, PartnerChange, PartnerChangeKind,
+    PartnerExit, MAX_LIMITED_PARTNERSHIP_MEMBERS, REPLENISH_PERIOD_DAYS,
+};
+pub use resolution::{
+    ContentDefect, Electorate, GoverningBody, Meeting, MeetingType, Resolution, ResolutionDefect,
+    ResolutionReport, ResolutionSubject, ResolutionValidity, Threshold, VoteBase, VoteChoice,
+    VotingRules, REVOCATION_PERIOD_DAYS,
+};
+pub use staff::{InternalLimit, Personnel, StaffMember};
+pub use succession::{Succession, SuccessionAware, SuccessionKind, SuccessionRegistry};
+pub use unincorporated::{
+    LiabilityType, Partner, PartnerType, PartnershipType, UnincorporatedOrgType,
+};
+pub use unincorporated::{SyncUnincorporatedOrg, UnincorporatedOrg};
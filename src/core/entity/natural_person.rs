@@ -1,10 +1,21 @@
+use crate::core::clock::{default_clock, Clock};
+use crate::core::entity::absence::{
+    check_absence, civil_status_at, find_in, revoke_absence_in, AbsenceDeclaration, CivilStatus,
+};
+use crate::core::entity::adjudication::{
+    adjudication_in_force_at, AdjudicationKind, CapacityAdjudication,
+};
 use crate::core::entity::base::{BaseEntity, CapacityStatus, Entity, EntityType, NaturalCapacity};
+use crate::core::entity::emancipation::SelfSupportBasis;
+use crate::core::entity::guardianship::{
+    GuardianCandidate, GuardianSelection, Guardianship, GuardianshipBasis, GuardianshipScope,
+    Relationship, TerminationCause, WardCategory,
+};
 use crate::FanResult;
 use crate::{FanError, ValidationErrorType};
 
 use chrono::prelude::*;
-use parking_lot::{Mutex, RwLock};
-use std::collections::HashSet;
+use parking_lot::{Mutex, MutexGuard, RwLock};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -19,22 +30,6 @@ pub enum MentalStatus {
     SeverelyImpaired,  // 严重受损
 }
 
-/// 监护关系
-#[derive(Debug, Clone)]
-pub struct Guardianship {
-    guardian: Uuid,                     // 监护人ID
-    ward: Uuid,                         // 被监护人ID
-    scope: GuardianshipScope,           // 监护范围
-    created_at: DateTime<Utc>,          // 监护关系建立时间
-    valid_until: Option<DateTime<Utc>>, // 监护关系有效期
-}
-
-/// 监护范围
-#[derive(Debug, Clone)]
-pub struct GuardianshipScope {
-    permitted_actions: HashSet<String>, // 允许的行为类型
-}
-
 /// 自然人
 ///
 /// 该结构体表示一个自然人，包含了自然人的基本信息及其与监护人之间的关系。
@@ -43,16 +38,63 @@ pub struct GuardianshipScope {
 /// 字段:
 /// - `base`: 基本实体信息，如姓名、性别等。
 /// - `birth_date`: 出生日期，用于计算年龄。
-/// - `mental_status`: 精神状态，表示自然人的心理健康状况。
-/// - `guardian`: 可选的监护人信息，如果自然人为未成年人或因精神状态需要监护，则该字段存在。
-/// - `is_guardian`: 表示当前自然人是否为监护人的标志。
+/// - `mental_status`: 精神状态，表示自然人的心理健康状况。成年人的行为能力不因此直接改变，须经法院认定。
+/// - `adjudications`: 法院对其行为能力作出的认定及恢复认定记录，按作出顺序保留全部历史。
+/// - `self_support`: 以自己的劳动收入为主要生活来源的事实依据（第十八条第二款）。
+/// - `absences`: 宣告失踪、宣告死亡的记录及其撤销情况（第四十条至第五十三条）。
+/// - `relatives`: 已登记的近亲属及其与本人的关系，用于确定法定监护人顺序。
+/// - `guardianships`: 本人作为被监护人的监护关系，可由多名监护人共同担任，终止后仍保留历史。
 #[derive(Debug, Clone)]
 pub struct NaturalPerson {
     base: BaseEntity,
     birth_date: DateTime<Utc>,
     mental_status: MentalStatus,
-    guardian: Option<Guardianship>,
-    is_guardian: bool,
+    adjudications: Vec<CapacityAdjudication>,
+    self_support: Option<SelfSupportBasis>,
+    absences: Vec<AbsenceDeclaration>,
+    relatives: Vec<(Uuid, Relationship)>,
+    guardianships: Vec<Guardianship>,
+}
+
+/// 评估自然人行为能力所依据的事实
+struct CapacityBasis<'a> {
+    birth_date: &'a DateTime<Utc>,
+    mental_status: &'a MentalStatus,
+    adjudications: &'a [CapacityAdjudication],
+    self_support: Option<&'a SelfSupportBasis>,
+}
+
+impl CapacityBasis<'_> {
+    /// 评估在指定时刻的行为能力
+    ///
+    /// - 不满八周岁的，为无民事行为能力人（第二十条）
+    /// - 八周岁以上的未成年人为限制民事行为能力人（第十九条），不能辨认自己行为的为无民事行为能力人（第二十一条第二款）
+    /// - 十六周岁以上、以自己的劳动收入为主要生活来源的未成年人，视为完全民事行为能力人（第十八条第二款）
+    /// - 成年人推定为完全民事行为能力人（第十八条），仅能经法院认定改变（第二十四条）
+    fn evaluate(&self, at: DateTime<Utc>) -> NaturalCapacity {
+        let age = full_years(self.birth_date, &at);
+
+        if age < 8 {
+            return NaturalCapacity::None;
+        }
+
+        if age < 18 {
+            return match self.mental_status {
+                MentalStatus::SeverelyImpaired => NaturalCapacity::None,
+                MentalStatus::Normal if age >= 16 && self.is_self_supporting_at(at) => {
+                    NaturalCapacity::Full
+                }
+                _ => NaturalCapacity::Limited,
+            };
+        }
+
+        adjudication_in_force_at(self.adjudications, at)
+            .map_or(NaturalCapacity::Full, |a| a.resulting_capacity().clone())
+    }
+
+    fn is_self_supporting_at(&self, at: DateTime<Utc>) -> bool {
+        self.self_support.is_some_and(|b| b.is_in_effect_at(at))
+    }
 }
 
 impl NaturalPerson {
@@ -67,76 +109,233 @@ impl NaturalPerson {
     ///
     /// 返回一个新的自然人实体，包含基本属性和根据出生日期与心智状态评估的行为能力
     pub fn new(birth_date: DateTime<Utc>, mental_status: MentalStatus) -> Self {
-        // 获取当前UTC时间，用于设置创建和更新时间戳
-        let now = Utc::now();
+        Self::with_clock(birth_date, mental_status, default_clock())
+    }
 
+    /// 使用指定时钟创建一个新的自然人实体
+    ///
+    /// 年龄、行为能力及各类时间戳均以该时钟为准。
+    pub fn with_clock(
+        birth_date: DateTime<Utc>,
+        mental_status: MentalStatus,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
         // 根据出生日期和心智状态评估行为能力
-        let capacity = Self::evaluate_capacity(&birth_date, &mental_status);
+        let capacity = CapacityBasis {
+            birth_date: &birth_date,
+            mental_status: &mental_status,
+            adjudications: &[],
+            self_support: None,
+        }
+        .evaluate(clock.now());
 
         // 构建并返回一个新的自然人实体
         Self {
-            base: BaseEntity {
-                // 生成唯一的实体ID
-                id: Uuid::new_v4(),
-                // 设置实体类型为自然人
-                entity_type: EntityType::NaturalPerson,
-                // 设置行为能力状态
-                capacity_status: CapacityStatus::NaturalPerson(capacity),
-                // 设置创建时间戳
-                created_at: now,
-                // 设置更新时间戳
-                updated_at: now,
-            },
+            base: BaseEntity::new(
+                EntityType::NaturalPerson,
+                CapacityStatus::NaturalPerson(capacity),
+                clock,
+            ),
             // 设置出生日期
             birth_date,
             // 设置心智状态
             mental_status,
-            // 初始化法定监护人为空
-            guardian: None,
-            // 初始化是否为监护人的状态为否
-            is_guardian: false,
+            // 初始化行为能力认定记录为空
+            adjudications: Vec::new(),
+            // 初始化劳动收入依据为空
+            self_support: None,
+            // 初始化宣告失踪、宣告死亡记录为空
+            absences: Vec::new(),
+            // 初始化近亲属为空
+            relatives: Vec::new(),
+            // 初始化监护关系为空
+            guardianships: Vec::new(),
         }
     }
 
-    /// 计算年龄
+    /// 计算当前年龄
     pub fn age(&self) -> u8 {
-        let now = Utc::now();
-        let age = now.year() - self.birth_date.year();
-        age as u8 // 简化的计算，实际应该考虑月份和日期
-    }
-
-    /// 评估行为能力
-    fn evaluate_capacity(
-        birth_date: &DateTime<Utc>,
-        mental_status: &MentalStatus,
-    ) -> NaturalCapacity {
-        let now: DateTime<Utc> = Utc::now();
-        let age = (now.year() - birth_date.year()) as u8;
-
-        match (age, mental_status) {
-            (age, MentalStatus::Normal) if age >= 18 => NaturalCapacity::Full,
-            (age, MentalStatus::Normal) if age >= 8 => NaturalCapacity::Limited,
-            (_, MentalStatus::PartiallyImpaired) => NaturalCapacity::Limited,
-            (_, MentalStatus::SeverelyImpaired) => NaturalCapacity::None,
-            _ => NaturalCapacity::None,
+        self.age_at(self.base.now())
+    }
+
+    /// 计算在指定时刻已满的周岁
+    pub fn age_at(&self, at: DateTime<Utc>) -> u8 {
+        full_years(&self.birth_date, &at)
+    }
+
+    /// 获取出生日期
+    pub fn birth_date(&self) -> DateTime<Utc> {
+        self.birth_date
+    }
+
+    /// 评估在指定时刻的行为能力
+    ///
+    /// 用于回溯判断某一法律行为（如意思表示）作出时表意人的行为能力。
+    pub fn capacity_at(&self, at: DateTime<Utc>) -> NaturalCapacity {
+        self.capacity_basis().evaluate(at)
+    }
+
+    fn capacity_basis(&self) -> CapacityBasis<'_> {
+        CapacityBasis {
+            birth_date: &self.birth_date,
+            mental_status: &self.mental_status,
+            adjudications: &self.adjudications,
+            self_support: self.self_support.as_ref(),
         }
     }
 
-    /// 更新精神状态并重新评估行为能力
+    /// 刷新缓存的行为能力状态
+    fn refresh_capacity(&mut self) {
+        let capacity = self.capacity_at(self.base.now());
+        self.base.capacity_status = CapacityStatus::NaturalPerson(capacity);
+        self.base.touch();
+    }
+
+    /// 更新精神状态
+    ///
+    /// 精神状态仅记录医学事实：对未成年人直接影响其行为能力（第二十一条第二款），
+    /// 成年人的行为能力须通过 [`NaturalPerson::adjudicate_capacity`] 经法院认定后才发生变化。
     pub fn update_mental_status(&mut self, new_status: MentalStatus) -> FanResult<()> {
         self.mental_status = new_status;
-        if let CapacityStatus::NaturalPerson(_) = &mut self.base.capacity_status {
-            self.base.capacity_status = CapacityStatus::NaturalPerson(Self::evaluate_capacity(
-                &self.birth_date,
-                &self.mental_status,
-            ));
-        }
-        self.base.updated_at = Utc::now();
+        self.refresh_capacity();
+        Ok(())
+    }
+
+    /// 记录法院对其行为能力作出的认定或恢复认定（第二十四条）
+    pub fn adjudicate_capacity(&mut self, adjudication: CapacityAdjudication) -> FanResult<()> {
+        check_adjudication(&self.capacity_basis(), &adjudication)?;
+        self.adjudications.push(adjudication);
+        self.refresh_capacity();
+        Ok(())
+    }
+
+    /// 撤销一项认定，历史记录予以保留
+    pub fn revoke_adjudication(
+        &mut self,
+        adjudication_id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        revoke_in(&mut self.adjudications, adjudication_id, revoked_at)?;
+        self.refresh_capacity();
+        Ok(())
+    }
+
+    /// 获取全部行为能力认定记录
+    pub fn adjudications(&self) -> &[CapacityAdjudication] {
+        &self.adjudications
+    }
+
+    /// 记录其以自己的劳动收入为主要生活来源的事实（第十八条第二款）
+    pub fn record_self_support(&mut self, basis: SelfSupportBasis) -> FanResult<()> {
+        check_self_support(&self.birth_date, &basis)?;
+        self.self_support = Some(basis);
+        self.refresh_capacity();
+        Ok(())
+    }
+
+    /// 记录其不再以劳动收入为主要生活来源
+    pub fn cease_self_support(&mut self, ceased_at: DateTime<Utc>) -> FanResult<()> {
+        cease_in(self.self_support.as_mut(), ceased_at)?;
+        self.refresh_capacity();
+        Ok(())
+    }
+
+    /// 获取劳动收入依据
+    pub fn self_support(&self) -> Option<&SelfSupportBasis> {
+        self.self_support.as_ref()
+    }
+
+    /// 记录人民法院作出的宣告失踪或宣告死亡判决
+    ///
+    /// 被宣告死亡的，不能担任监护人，其尚未被承诺的要约失效，但其实际生存期间的行为能力不受影响（第四十九条）；
+    /// 被宣告失踪的，由财产代管人代管其财产，亦不能履行监护职责。
+    pub fn record_absence(&mut self, declaration: AbsenceDeclaration) -> FanResult<()> {
+        check_absence(&self.absences, &declaration)?;
+        self.absences.push(declaration);
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 本人重新出现，撤销宣告（第四十五条、第五十条）
+    ///
+    /// 撤销后，宣告期间本人实施的民事法律行为依其当时的行为能力判断（第四十九条）。
+    pub fn revoke_absence(
+        &mut self,
+        declaration_id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        revoke_absence_in(&mut self.absences, declaration_id, revoked_at)?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 变更失踪人的财产代管人（第四十四条）
+    pub fn change_custodian(&mut self, declaration_id: Uuid, custodian: Uuid) -> FanResult<()> {
+        find_in(&mut self.absences, declaration_id)?.change_custodian(custodian)?;
+        self.base.touch();
         Ok(())
     }
 
+    /// 获取宣告失踪、宣告死亡的全部记录
+    pub fn absences(&self) -> &[AbsenceDeclaration] {
+        &self.absences
+    }
+
+    /// 获取当前的宣告状态
+    pub fn civil_status(&self) -> CivilStatus {
+        self.civil_status_at(self.base.now())
+    }
+
+    /// 获取在指定时刻的宣告状态
+    pub fn civil_status_at(&self, at: DateTime<Utc>) -> CivilStatus {
+        civil_status_at(&self.absences, at)
+    }
+
+    /// 登记一名近亲属，`relationship` 为该亲属相对于本人的身份，如 `Parent` 表示对方是本人的父母
+    pub fn add_relative(&mut self, relative: Uuid, relationship: Relationship) {
+        self.relatives.retain(|(id, _)| *id != relative);
+        self.relatives.push((relative, relationship));
+        self.base.touch();
+    }
+
+    /// 获取某人相对于本人的身份，未登记的视为其他个人
+    pub fn relationship_to(&self, other: Uuid) -> Relationship {
+        relationship_in(&self.relatives, other)
+    }
+
+    /// 根据已登记的亲属关系和对方的监护能力，生成以本人为被监护人的候选人
+    pub fn guardian_candidate(&self, candidate: &Self) -> GuardianCandidate {
+        GuardianCandidate::new(
+            candidate.base.id,
+            self.relationship_to(candidate.base.id),
+            candidate.can_be_guardian(),
+        )
+    }
+
+    /// 按第二十七条、第二十八条的法定顺序评估本人的监护人候选人
+    pub fn select_guardians(
+        &self,
+        candidates: Vec<GuardianCandidate>,
+    ) -> FanResult<GuardianSelection> {
+        let now = self.base.now();
+        let category = ward_category(full_years(&self.birth_date, &now), self.capacity_at(now))?;
+        Ok(GuardianSelection::evaluate(
+            self.base.id,
+            category,
+            candidates,
+        ))
+    }
+
     /// 设置监护人，并修改作为监护人的 NaturalPerson 实例
-    pub fn set_guardian(&mut self, guardian: &mut Self, scope: GuardianshipScope) -> FanResult<()> {
+    ///
+    /// `basis` 须由 [`GuardianSelection`] 依法定顺序出具或经指定取得，且与双方身份一致。
+    /// 已有监护人的，新的监护人与其共同担任监护人。
+    pub fn set_guardian(
+        &mut self,
+        guardian: &mut Self,
+        basis: GuardianshipBasis,
+        scope: GuardianshipScope,
+    ) -> FanResult<()> {
         if !guardian.can_be_guardian() {
             return Err(FanError::validation(
                 "Invalid guardian",
@@ -145,20 +344,88 @@ impl NaturalPerson {
                 "NaturalPerson",
             ));
         }
+        check_basis(&basis, self.base.id, guardian.base.id)?;
+        let now = self.base.now();
+        check_not_guarding(&self.guardianships, guardian.base.id, now)?;
+
+        self.guardianships
+            .push(Guardianship::new(basis, scope, now));
+        self.base.touch();
+        guardian.base.touch();
+        Ok(())
+    }
 
-        self.guardian = Some(Guardianship {
-            guardian: guardian.base.id,
-            ward: self.base.id,
-            scope,
-            created_at: Utc::now(),
-            valid_until: None,
-        });
-        self.base.updated_at = Utc::now();
-        guardian.is_guardian = true;
-        guardian.base.updated_at = Utc::now();
+    /// 终止与某一监护人之间的监护关系（第三十六条、第三十九条）
+    ///
+    /// 以被监护人取得完全民事行为能力、监护人丧失监护能力或者一方死亡为由终止的，须与双方在终止时的状态相符；
+    /// 以人民法院判决为由终止的，须有针对该监护关系且已生效的判决。
+    pub fn terminate_guardianship(
+        &mut self,
+        guardian: &mut Self,
+        cause: TerminationCause,
+        terminated_at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        let (ward_id, guardian_id) = (self.base.id, guardian.base.id);
+        let facts = TerminationFacts {
+            ward: ward_id,
+            guardian: guardian_id,
+            ward_capacity: self.capacity_at(terminated_at),
+            ward_presumed_dead: self.is_presumed_dead_at(terminated_at),
+            guardian_able: guardian.can_be_guardian_at(terminated_at),
+            guardian_presumed_dead: guardian.is_presumed_dead_at(terminated_at),
+        };
+        check_termination(&cause, &facts, terminated_at)?;
+        terminate_in(
+            &mut self.guardianships,
+            ward_id,
+            guardian_id,
+            &cause,
+            terminated_at,
+        )?;
+        self.base.touch();
+        guardian.base.touch();
         Ok(())
     }
 
+    /// 获取全部监护关系记录，包括已终止的
+    pub fn guardianships(&self) -> &[Guardianship] {
+        &self.guardianships
+    }
+
+    /// 获取当前存续的监护关系
+    pub fn guardians(&self) -> Vec<&Guardianship> {
+        self.guardians_at(self.base.now())
+    }
+
+    /// 获取在指定时刻存续的监护关系
+    ///
+    /// 被监护人取得或者恢复完全民事行为能力或者被宣告死亡的，监护关系自然终止，无须另行记录。
+    pub fn guardians_at(&self, at: DateTime<Utc>) -> Vec<&Guardianship> {
+        if self.capacity_at(at) == NaturalCapacity::Full || self.is_presumed_dead_at(at) {
+            return Vec::new();
+        }
+        self.guardianships
+            .iter()
+            .filter(|g| g.is_active_at(at))
+            .collect()
+    }
+
+    /// 当前是否担任 `ward` 的监护人
+    pub fn is_guardian_of(&self, ward: &dyn Entity) -> bool {
+        self.is_guardian_of_at(ward, self.base.now())
+    }
+
+    /// 在指定时刻是否担任 `ward` 的监护人
+    ///
+    /// 以被监护人一方在该时刻存续的监护关系为准，且本人仍须具有监护能力。
+    pub fn is_guardian_of_at(&self, ward: &dyn Entity, at: DateTime<Utc>) -> bool {
+        self.can_be_guardian_at(at)
+            && ward
+                .guardianships_at(at)
+                .iter()
+                .any(|g| g.guardian() == self.base.id)
+    }
+
     // /// 设置监护人，并修改作为监护人的 NaturalPerson 实例
     // pub fn set_guardian(
     //     ward: &Arc<Mutex<Self>>,
@@ -218,22 +485,253 @@ impl NaturalPerson {
     //         created_at: Utc::now(),
     //         valid_until: None,
     //     });
-    //     ward.base.updated_at = Utc::now();
+    //     ward.base.touch();
 
     //     guardian.is_guardian = true;
-    //     guardian.base.updated_at = Utc::now();
+    //     guardian.base.touch();
 
     //     Ok(())
     // }
 
     /// 判断是否可以作为监护人
+    ///
+    /// 须具有完全民事行为能力且精神状态正常；视为完全民事行为能力人的未成年人亦可担任。
     pub fn can_be_guardian(&self) -> bool {
-        matches!(
-            &self.base.capacity_status,
-            CapacityStatus::NaturalPerson(NaturalCapacity::Full)
-        ) && self.mental_status == MentalStatus::Normal
-            && self.age() >= 18
+        self.can_be_guardian_at(self.base.now())
+    }
+
+    /// 判断在指定时刻是否可以作为监护人
+    ///
+    /// 被宣告失踪或宣告死亡的，不能履行监护职责。
+    pub fn can_be_guardian_at(&self, at: DateTime<Utc>) -> bool {
+        self.capacity_at(at) == NaturalCapacity::Full
+            && self.mental_status == MentalStatus::Normal
+            && self.civil_status_at(at) == CivilStatus::Present
+    }
+}
+
+/// 根据年龄和行为能力确定被监护人类别，完全民事行为能力人不需要监护
+fn ward_category(age: u8, capacity: NaturalCapacity) -> FanResult<WardCategory> {
+    match capacity {
+        NaturalCapacity::Full => Err(FanError::validation_with_ref(
+            "完全民事行为能力人不需要设立监护",
+            ValidationErrorType::EntityStatusIllegal,
+            "《民法典》第二十七条、第二十八条",
+            "select_guardians",
+            "NaturalPerson",
+        )),
+        _ if age < 18 => Ok(WardCategory::Minor),
+        _ => Ok(WardCategory::IncapacitatedAdult),
+    }
+}
+
+/// 查找某人相对于本人的身份
+fn relationship_in(relatives: &[(Uuid, Relationship)], other: Uuid) -> Relationship {
+    relatives
+        .iter()
+        .find(|(id, _)| *id == other)
+        .map(|(_, relationship)| relationship.clone())
+        .unwrap_or(Relationship::Other)
+}
+
+/// 校验监护依据与被监护人、监护人身份是否一致
+fn check_basis(basis: &GuardianshipBasis, ward: Uuid, guardian: Uuid) -> FanResult<()> {
+    if basis.ward() != ward || basis.guardian() != guardian {
+        return Err(FanError::validation(
+            "监护依据与被监护人或监护人不符",
+            ValidationErrorType::EntityRelationMalformed,
+            "set_guardian",
+            "NaturalPerson",
+        ));
+    }
+    Ok(())
+}
+
+/// 同一监护人不能重复担任同一被监护人的监护人
+fn check_not_guarding(
+    guardianships: &[Guardianship],
+    guardian: Uuid,
+    at: DateTime<Utc>,
+) -> FanResult<()> {
+    if guardianships
+        .iter()
+        .any(|g| g.guardian() == guardian && g.is_active_at(at))
+    {
+        return Err(FanError::validation(
+            "该监护人已在担任监护人",
+            ValidationErrorType::EntityRelationMalformed,
+            "set_guardian",
+            "NaturalPerson",
+        ));
+    }
+    Ok(())
+}
+
+/// 终止监护关系时双方的状态
+struct TerminationFacts {
+    ward: Uuid,
+    guardian: Uuid,
+    ward_capacity: NaturalCapacity,
+    ward_presumed_dead: bool,
+    guardian_able: bool,
+    guardian_presumed_dead: bool,
+}
+
+/// 校验终止原因是否与双方在终止时的状态相符
+///
+/// 死亡以宣告死亡的记录为准；人民法院撤销监护人资格或者认定终止的，须有针对该监护关系且已生效的判决。
+fn check_termination(
+    cause: &TerminationCause,
+    facts: &TerminationFacts,
+    at: DateTime<Utc>,
+) -> FanResult<()> {
+    let consistent = match cause {
+        TerminationCause::WardGainedCapacity => facts.ward_capacity == NaturalCapacity::Full,
+        TerminationCause::GuardianLostCapacity => !facts.guardian_able,
+        TerminationCause::WardDied => facts.ward_presumed_dead,
+        TerminationCause::GuardianDied => facts.guardian_presumed_dead,
+        TerminationCause::Revoked(ruling) | TerminationCause::CourtDetermined(ruling) => {
+            ruling.applies_to(facts.ward, facts.guardian, at)
+        }
+    };
+    if !consistent {
+        return Err(FanError::validation_with_ref(
+            "终止原因与被监护人或监护人的状态或者人民法院的判决不符",
+            ValidationErrorType::EntityStatusIllegal,
+            "《民法典》第三十六条、第三十九条",
+            "terminate_guardianship",
+            "NaturalPerson",
+        ));
+    }
+    Ok(())
+}
+
+/// 终止双方之间存续的监护关系
+fn terminate_in(
+    guardianships: &mut [Guardianship],
+    ward: Uuid,
+    guardian: Uuid,
+    cause: &TerminationCause,
+    terminated_at: DateTime<Utc>,
+) -> FanResult<()> {
+    guardianships
+        .iter_mut()
+        .find(|g| g.ward() == ward && g.guardian() == guardian && g.is_active_at(terminated_at))
+        .ok_or_else(|| {
+            FanError::validation(
+                "双方之间不存在存续的监护关系",
+                ValidationErrorType::EntityRelationMalformed,
+                "terminate_guardianship",
+                "NaturalPerson",
+            )
+        })?
+        .terminate(cause.clone(), terminated_at)
+}
+
+/// 校验一项认定能否适用于该自然人
+///
+/// 第二十四条仅适用于成年人；恢复认定须以此前存在有效的无能力或限制能力认定为前提。
+fn check_adjudication(
+    basis: &CapacityBasis<'_>,
+    adjudication: &CapacityAdjudication,
+) -> FanResult<()> {
+    let at = adjudication.adjudicated_at();
+    if full_years(basis.birth_date, &at) < 18 {
+        return Err(FanError::validation_with_ref(
+            "仅能对成年人作出行为能力认定",
+            ValidationErrorType::EntityStatusIllegal,
+            "《民法典》第二十四条",
+            "adjudicate_capacity",
+            "NaturalPerson",
+        ));
+    }
+
+    if *adjudication.kind() == AdjudicationKind::Restoration
+        && basis.evaluate(at) == NaturalCapacity::Full
+    {
+        return Err(FanError::validation_with_ref(
+            "恢复认定须以存在有效的无民事行为能力或限制民事行为能力认定为前提",
+            ValidationErrorType::OperationSequenceWrong,
+            "《民法典》第二十四条第二款",
+            "adjudicate_capacity",
+            "NaturalPerson",
+        ));
+    }
+
+    Ok(())
+}
+
+/// 校验劳动收入依据：须自年满十六周岁之后开始，且仅适用于未成年人
+fn check_self_support(birth_date: &DateTime<Utc>, basis: &SelfSupportBasis) -> FanResult<()> {
+    let age = full_years(birth_date, &basis.effective_from());
+    if !(16..18).contains(&age) {
+        return Err(FanError::validation_with_ref(
+            "仅十六周岁以上的未成年人可以劳动收入为主要生活来源视为完全民事行为能力人",
+            ValidationErrorType::EntityStatusIllegal,
+            "《民法典》第十八条第二款",
+            "record_self_support",
+            "NaturalPerson",
+        ));
     }
+    Ok(())
+}
+
+/// 终止劳动收入依据
+fn cease_in(basis: Option<&mut SelfSupportBasis>, ceased_at: DateTime<Utc>) -> FanResult<()> {
+    basis
+        .ok_or_else(|| {
+            FanError::validation(
+                "未记录劳动收入依据",
+                ValidationErrorType::EntityError,
+                "cease_self_support",
+                "NaturalPerson",
+            )
+        })?
+        .cease(ceased_at)
+}
+
+/// 撤销记录中的指定认定
+fn revoke_in(
+    adjudications: &mut [CapacityAdjudication],
+    adjudication_id: Uuid,
+    revoked_at: DateTime<Utc>,
+) -> FanResult<()> {
+    adjudications
+        .iter_mut()
+        .find(|a| a.id() == adjudication_id)
+        .ok_or_else(|| {
+            FanError::validation(
+                "认定记录不存在",
+                ValidationErrorType::EntityError,
+                "revoke_adjudication",
+                "NaturalPerson",
+            )
+        })?
+        .revoke(revoked_at)
+}
+
+/// 计算自出生至指定时刻已满的周岁
+///
+/// 依《民法典》第二百零一条，出生当日不计入，周岁生日的次日起方为已满该周岁；
+/// 二月二十九日出生的，在平年以二月二十八日为周岁生日。
+fn full_years(birth_date: &DateTime<Utc>, at: &DateTime<Utc>) -> u8 {
+    let birth = birth_date.date_naive();
+    let at = at.date_naive();
+    if at <= birth {
+        return 0;
+    }
+
+    let anniversary = |year: i32| {
+        NaiveDate::from_ymd_opt(year, birth.month(), birth.day())
+            .or_else(|| NaiveDate::from_ymd_opt(year, 2, 28))
+            .expect("valid anniversary date")
+    };
+
+    let mut years = at.year() - birth.year();
+    if at <= anniversary(at.year()) {
+        years -= 1;
+    }
+    years.clamp(0, u8::MAX as i32) as u8
 }
 
 // 给 NaturalPerson 实现 Entity trait
@@ -245,7 +743,7 @@ impl Entity for NaturalPerson {
         self.base.entity_type.clone()
     }
     fn capacity_status(&self) -> CapacityStatus {
-        self.base.capacity_status.clone()
+        self.capacity_status_at(self.base.now())
     }
     fn created_at(&self) -> DateTime<Utc> {
         self.base.created_at
@@ -254,14 +752,28 @@ impl Entity for NaturalPerson {
         self.base.updated_at
     }
 
+    fn capacity_status_at(&self, at: DateTime<Utc>) -> CapacityStatus {
+        CapacityStatus::NaturalPerson(self.capacity_at(at))
+    }
+
     fn has_capacity(&self) -> bool {
-        match &self.base.capacity_status {
-            CapacityStatus::NaturalPerson(capacity) => match capacity {
-                NaturalCapacity::Full => true,
-                _ => false,
-            },
-            _ => false,
-        }
+        self.has_capacity_at(self.base.now())
+    }
+
+    fn has_capacity_at(&self, at: DateTime<Utc>) -> bool {
+        self.capacity_at(at) == NaturalCapacity::Full
+    }
+
+    fn age_at(&self, at: DateTime<Utc>) -> Option<u8> {
+        Some(full_years(&self.birth_date, &at))
+    }
+
+    fn guardianships_at(&self, at: DateTime<Utc>) -> Vec<Guardianship> {
+        self.guardians_at(at).into_iter().cloned().collect()
+    }
+
+    fn is_presumed_dead_at(&self, at: DateTime<Utc>) -> bool {
+        matches!(self.civil_status_at(at), CivilStatus::PresumedDead { .. })
     }
 }
 
@@ -271,8 +783,11 @@ pub struct SyncNaturalPerson {
     base: Arc<RwLock<BaseEntity>>,
     birth_date: DateTime<Utc>, // 不需要锁，因为不可变
     mental_status: Arc<RwLock<MentalStatus>>,
-    guardian: Arc<RwLock<Option<Guardianship>>>,
-    is_guardian: Arc<RwLock<bool>>,
+    adjudications: Arc<RwLock<Vec<CapacityAdjudication>>>,
+    self_support: Arc<RwLock<Option<SelfSupportBasis>>>,
+    absences: Arc<RwLock<Vec<AbsenceDeclaration>>>,
+    relatives: Arc<RwLock<Vec<(Uuid, Relationship)>>>,
+    guardianships: Arc<RwLock<Vec<Guardianship>>>,
 }
 
 /// 给 SyncNaturalPerson（线程安全的NP） 实现 Entity trait
@@ -288,65 +803,208 @@ impl SyncNaturalPerson {
     ///
     /// 返回一个新创建的自然人实体实例
     pub fn new(birth_date: DateTime<Utc>, mental_status: MentalStatus) -> Self {
-        // 获取当前时间，用于记录实体的创建和更新时间
-        let now = Utc::now();
+        Self::with_clock(birth_date, mental_status, default_clock())
+    }
 
+    /// 使用指定时钟创建一个新的自然人实体
+    pub fn with_clock(
+        birth_date: DateTime<Utc>,
+        mental_status: MentalStatus,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
         // 根据出生日期和精神状态评估自然人的民事行为能力
-        let capacity = NaturalPerson::evaluate_capacity(&birth_date, &mental_status);
+        let capacity = CapacityBasis {
+            birth_date: &birth_date,
+            mental_status: &mental_status,
+            adjudications: &[],
+            self_support: None,
+        }
+        .evaluate(clock.now());
 
         // 构建并返回一个新的自然人实体
         Self {
             // 使用Arc和RwLock来管理实体的基础信息，确保线程安全和可变性
-            base: Arc::new(RwLock::new(BaseEntity {
-                // 为每个自然人实体分配一个唯一的UUID作为标识
-                id: Uuid::new_v4(),
-                // 设置实体类型为自然人
-                entity_type: EntityType::NaturalPerson,
-                // 根据自然人的民事行为能力设置其民事行为能力状态
-                capacity_status: CapacityStatus::NaturalPerson(capacity),
-                // 记录实体的创建时间
-                created_at: now,
-                // 记录实体的最后更新时间
-                updated_at: now,
-            })),
+            base: Arc::new(RwLock::new(BaseEntity::new(
+                EntityType::NaturalPerson,
+                CapacityStatus::NaturalPerson(capacity),
+                clock,
+            ))),
             // 自然人的出生日期
             birth_date,
             // 自然人的精神状态，使用Arc和RwLock确保线程安全和可变性
             mental_status: Arc::new(RwLock::new(mental_status)),
-            // 自然人的监护人信息，初始设置为None，使用Arc和RwLock确保线程安全和可变性
-            guardian: Arc::new(RwLock::new(None)),
-            // 自然人是否是监护人的状态，初始设置为false，使用Arc和RwLock确保线程安全和可变性
-            is_guardian: Arc::new(RwLock::new(false)),
+            // 自然人的行为能力认定记录，初始为空
+            adjudications: Arc::new(RwLock::new(Vec::new())),
+            // 劳动收入依据，初始为空
+            self_support: Arc::new(RwLock::new(None)),
+            // 宣告失踪、宣告死亡记录，初始为空
+            absences: Arc::new(RwLock::new(Vec::new())),
+            // 已登记的近亲属，初始为空
+            relatives: Arc::new(RwLock::new(Vec::new())),
+            // 自然人作为被监护人的监护关系，初始为空，使用Arc和RwLock确保线程安全和可变性
+            guardianships: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
     pub fn age(&self) -> u8 {
-        let now = Utc::now();
-        let age = now.year() - self.birth_date.year();
-        age as u8
+        self.age_at(self.base.read().now())
     }
 
-    pub fn update_mental_status(&self, new_status: MentalStatus) -> FanResult<()> {
-        let mut status = self.mental_status.write();
+    pub fn age_at(&self, at: DateTime<Utc>) -> u8 {
+        full_years(&self.birth_date, &at)
+    }
 
-        *status = new_status.clone();
+    pub fn birth_date(&self) -> DateTime<Utc> {
+        self.birth_date
+    }
 
+    pub fn capacity_at(&self, at: DateTime<Utc>) -> NaturalCapacity {
+        let status = self.mental_status.read();
+        let adjudications = self.adjudications.read();
+        let self_support = self.self_support.read();
+        CapacityBasis {
+            birth_date: &self.birth_date,
+            mental_status: &status,
+            adjudications: &adjudications,
+            self_support: self_support.as_ref(),
+        }
+        .evaluate(at)
+    }
+
+    fn refresh_capacity(&self) {
+        let now = self.base.read().now();
+        let capacity = self.capacity_at(now);
         let mut base = self.base.write();
+        base.capacity_status = CapacityStatus::NaturalPerson(capacity);
+        base.touch();
+    }
 
-        if let CapacityStatus::NaturalPerson(_) = &mut base.capacity_status {
-            base.capacity_status = CapacityStatus::NaturalPerson(NaturalPerson::evaluate_capacity(
-                &self.birth_date,
-                &new_status,
-            ));
+    pub fn update_mental_status(&self, new_status: MentalStatus) -> FanResult<()> {
+        *self.mental_status.write() = new_status;
+        self.refresh_capacity();
+        Ok(())
+    }
+
+    pub fn adjudicate_capacity(&self, adjudication: CapacityAdjudication) -> FanResult<()> {
+        {
+            let status = self.mental_status.read();
+            let mut adjudications = self.adjudications.write();
+            let self_support = self.self_support.read();
+            let basis = CapacityBasis {
+                birth_date: &self.birth_date,
+                mental_status: &status,
+                adjudications: &adjudications,
+                self_support: self_support.as_ref(),
+            };
+            check_adjudication(&basis, &adjudication)?;
+            adjudications.push(adjudication);
         }
-        base.updated_at = Utc::now();
+        self.refresh_capacity();
+        Ok(())
+    }
 
+    pub fn revoke_adjudication(
+        &self,
+        adjudication_id: Uuid,
+        revoked_at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        revoke_in(&mut self.adjudications.write(), adjudication_id, revoked_at)?;
+        self.refresh_capacity();
         Ok(())
     }
 
+    pub fn adjudications(&self) -> Vec<CapacityAdjudication> {
+        self.adjudications.read().clone()
+    }
+
+    pub fn record_self_support(&self, basis: SelfSupportBasis) -> FanResult<()> {
+        check_self_support(&self.birth_date, &basis)?;
+        *self.self_support.write() = Some(basis);
+        self.refresh_capacity();
+        Ok(())
+    }
+
+    pub fn cease_self_support(&self, ceased_at: DateTime<Utc>) -> FanResult<()> {
+        cease_in(self.self_support.write().as_mut(), ceased_at)?;
+        self.refresh_capacity();
+        Ok(())
+    }
+
+    pub fn self_support(&self) -> Option<SelfSupportBasis> {
+        self.self_support.read().clone()
+    }
+
+    pub fn record_absence(&self, declaration: AbsenceDeclaration) -> FanResult<()> {
+        {
+            let mut absences = self.absences.write();
+            check_absence(&absences, &declaration)?;
+            absences.push(declaration);
+        }
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn revoke_absence(&self, declaration_id: Uuid, revoked_at: DateTime<Utc>) -> FanResult<()> {
+        revoke_absence_in(&mut self.absences.write(), declaration_id, revoked_at)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn change_custodian(&self, declaration_id: Uuid, custodian: Uuid) -> FanResult<()> {
+        find_in(&mut self.absences.write(), declaration_id)?.change_custodian(custodian)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn absences(&self) -> Vec<AbsenceDeclaration> {
+        self.absences.read().clone()
+    }
+
+    pub fn civil_status(&self) -> CivilStatus {
+        self.civil_status_at(self.base.read().now())
+    }
+
+    pub fn civil_status_at(&self, at: DateTime<Utc>) -> CivilStatus {
+        civil_status_at(&self.absences.read(), at)
+    }
+
+    pub fn add_relative(&self, relative: Uuid, relationship: Relationship) {
+        {
+            let mut relatives = self.relatives.write();
+            relatives.retain(|(id, _)| *id != relative);
+            relatives.push((relative, relationship));
+        }
+        self.base.write().touch();
+    }
+
+    pub fn relationship_to(&self, other: Uuid) -> Relationship {
+        relationship_in(&self.relatives.read(), other)
+    }
+
+    pub fn guardian_candidate(&self, candidate: &Self) -> FanResult<GuardianCandidate> {
+        Ok(GuardianCandidate::new(
+            candidate.base.read().id,
+            self.relationship_to(candidate.base.read().id),
+            candidate.can_be_guardian()?,
+        ))
+    }
+
+    pub fn select_guardians(
+        &self,
+        candidates: Vec<GuardianCandidate>,
+    ) -> FanResult<GuardianSelection> {
+        let (id, now) = {
+            let base = self.base.read();
+            (base.id, base.now())
+        };
+        let category = ward_category(self.age_at(now), self.capacity_at(now))?;
+        Ok(GuardianSelection::evaluate(id, category, candidates))
+    }
+
     pub fn set_guardian(
         ward: &Arc<Mutex<Self>>,
         guardian: &Arc<Mutex<Self>>,
+        basis: GuardianshipBasis,
         scope: GuardianshipScope,
     ) -> FanResult<()> {
         // 先检查监护人资格
@@ -366,52 +1024,111 @@ impl SyncNaturalPerson {
             base.id
         };
 
-        // println!("{}", guardian_id);
-
-        // 按地址顺序加锁避免死锁
-        let (ward_guard, guardian_guard) = if Arc::as_ptr(ward) < Arc::as_ptr(guardian) {
-            (ward.try_lock().unwrap(), guardian.lock())
-        } else {
-            (guardian.try_lock().unwrap(), ward.lock())
-        };
+        let (ward_guard, guardian_guard) = Self::lock_pair(ward, guardian);
 
         // 更新被监护人状态
         {
-            let mut ward_guardian = ward_guard.guardian.write();
+            let mut ward_guardianships = ward_guard.guardianships.write();
             let mut ward_base = ward_guard.base.write();
 
-            *ward_guardian = Some(Guardianship {
-                guardian: guardian_id,
-                ward: ward_base.id,
-                scope,
-                created_at: Utc::now(),
-                valid_until: None,
-            });
-            ward_base.updated_at = Utc::now();
+            check_basis(&basis, ward_base.id, guardian_id)?;
+            let now = ward_base.now();
+            check_not_guarding(&ward_guardianships, guardian_id, now)?;
+            ward_guardianships.push(Guardianship::new(basis, scope, now));
+            ward_base.touch();
         }
 
         // 更新监护人状态
-        {
-            let mut guardian_is_guardian = guardian_guard.is_guardian.write();
-            let mut guardian_base = guardian_guard.base.write();
+        guardian_guard.base.write().touch();
 
-            *guardian_is_guardian = true;
-            guardian_base.updated_at = Utc::now();
-        }
+        Ok(())
+    }
 
+    /// 终止与某一监护人之间的监护关系（第三十六条、第三十九条）
+    pub fn terminate_guardianship(
+        ward: &Arc<Mutex<Self>>,
+        guardian: &Arc<Mutex<Self>>,
+        cause: TerminationCause,
+        terminated_at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        let (ward_guard, guardian_guard) = Self::lock_pair(ward, guardian);
+
+        let ward_id = ward_guard.base.read().id;
+        let guardian_id = guardian_guard.base.read().id;
+        let facts = TerminationFacts {
+            ward: ward_id,
+            guardian: guardian_id,
+            ward_capacity: ward_guard.capacity_at(terminated_at),
+            ward_presumed_dead: ward_guard.is_presumed_dead_at(terminated_at),
+            guardian_able: guardian_guard.can_be_guardian_at(terminated_at),
+            guardian_presumed_dead: guardian_guard.is_presumed_dead_at(terminated_at),
+        };
+        check_termination(&cause, &facts, terminated_at)?;
+        terminate_in(
+            &mut ward_guard.guardianships.write(),
+            ward_id,
+            guardian_id,
+            &cause,
+            terminated_at,
+        )?;
+        ward_guard.base.write().touch();
+        guardian_guard.base.write().touch();
         Ok(())
     }
 
+    /// 按地址顺序加锁避免死锁
+    fn lock_pair<'a>(
+        ward: &'a Arc<Mutex<Self>>,
+        guardian: &'a Arc<Mutex<Self>>,
+    ) -> (MutexGuard<'a, Self>, MutexGuard<'a, Self>) {
+        if Arc::as_ptr(ward) < Arc::as_ptr(guardian) {
+            let ward_guard = ward.lock();
+            (ward_guard, guardian.lock())
+        } else {
+            let guardian_guard = guardian.lock();
+            (ward.lock(), guardian_guard)
+        }
+    }
+
+    pub fn guardianships(&self) -> Vec<Guardianship> {
+        self.guardianships.read().clone()
+    }
+
+    pub fn guardians(&self) -> Vec<Guardianship> {
+        self.guardians_at(self.base.read().now())
+    }
+
+    pub fn guardians_at(&self, at: DateTime<Utc>) -> Vec<Guardianship> {
+        if self.capacity_at(at) == NaturalCapacity::Full || self.is_presumed_dead_at(at) {
+            return Vec::new();
+        }
+        self.guardianships
+            .read()
+            .iter()
+            .filter(|g| g.is_active_at(at))
+            .cloned()
+            .collect()
+    }
+
+    pub fn is_guardian_of(&self, ward: &dyn Entity) -> bool {
+        self.is_guardian_of_at(ward, self.base.read().now())
+    }
+
+    pub fn is_guardian_of_at(&self, ward: &dyn Entity, at: DateTime<Utc>) -> bool {
+        let id = self.base.read().id;
+        self.can_be_guardian_at(at) && ward.guardianships_at(at).iter().any(|g| g.guardian() == id)
+    }
+
     pub fn can_be_guardian(&self) -> FanResult<bool> {
-        let status = self.mental_status.read();
+        let now = self.base.read().now();
 
-        let base = self.base.read();
+        Ok(self.can_be_guardian_at(now))
+    }
 
-        Ok(matches!(
-            &base.capacity_status,
-            CapacityStatus::NaturalPerson(NaturalCapacity::Full)
-        ) && *status == MentalStatus::Normal
-            && self.age() >= 18)
+    pub fn can_be_guardian_at(&self, at: DateTime<Utc>) -> bool {
+        self.capacity_at(at) == NaturalCapacity::Full
+            && *self.mental_status.read() == MentalStatus::Normal
+            && self.civil_status_at(at) == CivilStatus::Present
     }
 
     // 从非线程安全版本转换
@@ -420,8 +1137,11 @@ impl SyncNaturalPerson {
             base: Arc::new(RwLock::new(person.base)),
             birth_date: person.birth_date,
             mental_status: Arc::new(RwLock::new(person.mental_status)),
-            guardian: Arc::new(RwLock::new(person.guardian)),
-            is_guardian: Arc::new(RwLock::new(person.is_guardian)),
+            adjudications: Arc::new(RwLock::new(person.adjudications)),
+            self_support: Arc::new(RwLock::new(person.self_support)),
+            absences: Arc::new(RwLock::new(person.absences)),
+            relatives: Arc::new(RwLock::new(person.relatives)),
+            guardianships: Arc::new(RwLock::new(person.guardianships)),
         }
     }
 }
@@ -436,7 +1156,7 @@ impl Entity for SyncNaturalPerson {
     }
 
     fn capacity_status(&self) -> CapacityStatus {
-        self.base.read().capacity_status.clone()
+        self.capacity_status_at(self.base.read().now())
     }
 
     fn created_at(&self) -> DateTime<Utc> {
@@ -447,26 +1167,68 @@ impl Entity for SyncNaturalPerson {
         self.base.read().updated_at
     }
 
+    fn capacity_status_at(&self, at: DateTime<Utc>) -> CapacityStatus {
+        CapacityStatus::NaturalPerson(self.capacity_at(at))
+    }
+
     fn has_capacity(&self) -> bool {
-        match self.capacity_status() {
-            CapacityStatus::NaturalPerson(NaturalCapacity::Full) => true,
-            _ => false,
-        }
+        self.has_capacity_at(self.base.read().now())
+    }
+
+    fn has_capacity_at(&self, at: DateTime<Utc>) -> bool {
+        self.capacity_at(at) == NaturalCapacity::Full
+    }
+
+    fn age_at(&self, at: DateTime<Utc>) -> Option<u8> {
+        Some(full_years(&self.birth_date, &at))
+    }
+
+    fn guardianships_at(&self, at: DateTime<Utc>) -> Vec<Guardianship> {
+        self.guardians_at(at)
+    }
+
+    fn is_presumed_dead_at(&self, at: DateTime<Utc>) -> bool {
+        matches!(self.civil_status_at(at), CivilStatus::PresumedDead { .. })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::clock::{FixedClock, ManualClock};
+    use crate::core::entity::absence::DisappearanceCause;
+    use crate::core::entity::adjudication::Applicant;
+    use crate::core::entity::emancipation::SelfSupportBasis;
+    use crate::core::entity::guardianship::{
+        BasisSource, GuardianshipAction, GuardianshipAuthority, GuardianshipRuling,
+    };
 
     fn get_test_date() -> DateTime<Utc> {
         Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap()
     }
 
+    fn get_test_clock() -> Arc<dyn Clock> {
+        Arc::new(FixedClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ))
+    }
+
+    fn get_test_adjudication(capacity: NaturalCapacity) -> CapacityAdjudication {
+        CapacityAdjudication::new(
+            AdjudicationKind::Incapacity,
+            Applicant::InterestedParty(Uuid::new_v4()),
+            "北京市海淀区人民法院".to_string(),
+            Utc.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap(),
+            capacity,
+        )
+        .unwrap()
+    }
+
     fn get_test_guardianship_scope() -> GuardianshipScope {
-        GuardianshipScope {
-            permitted_actions: HashSet::from(["care".to_string(), "education".to_string()]),
-        }
+        GuardianshipScope::new([
+            GuardianshipAction::PersonalCare,
+            GuardianshipAction::Education,
+        ])
     }
 
     // 测试创建一个自然人
@@ -474,25 +1236,118 @@ mod tests {
     fn test_create_natural_person() {
         let birth_date = get_test_date();
         let mental_status = MentalStatus::Normal;
-        let person = NaturalPerson::new(birth_date, mental_status);
+        let person = NaturalPerson::with_clock(birth_date, mental_status, get_test_clock());
 
-        assert_eq!(person.age(), 5); // Assuming current year is 2025
+        assert_eq!(person.age(), 5);
         assert_eq!(person.mental_status, MentalStatus::Normal);
     }
 
+    // 测试周岁计算：周岁生日的次日起方为已满该周岁
+    #[test]
+    fn test_age_at_birthday_boundary() {
+        let birth_date = Utc.with_ymd_and_hms(2004, 12, 20, 0, 0, 0).unwrap();
+        let person = NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
+
+        let before = Utc.with_ymd_and_hms(2022, 12, 19, 12, 0, 0).unwrap();
+        let birthday = Utc.with_ymd_and_hms(2022, 12, 20, 12, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2022, 12, 21, 0, 0, 0).unwrap();
+        assert_eq!(person.age_at(before), 17);
+        assert_eq!(person.age_at(birthday), 17);
+        assert_eq!(person.age_at(after), 18);
+        assert_eq!(
+            person.age_at(Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap()),
+            0
+        );
+
+        let leap_day = Utc.with_ymd_and_hms(2004, 2, 29, 0, 0, 0).unwrap();
+        let leapling = NaturalPerson::with_clock(leap_day, MentalStatus::Normal, get_test_clock());
+        assert_eq!(
+            leapling.age_at(Utc.with_ymd_and_hms(2022, 2, 28, 0, 0, 0).unwrap()),
+            17
+        );
+        assert_eq!(
+            leapling.age_at(Utc.with_ymd_and_hms(2022, 3, 1, 0, 0, 0).unwrap()),
+            18
+        );
+    }
+
+    // 测试行为能力随时钟推进而变化
+    #[test]
+    fn test_capacity_follows_clock() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2022, 12, 20, 0, 0, 0).unwrap(),
+        ));
+        let birth_date = Utc.with_ymd_and_hms(2004, 12, 20, 0, 0, 0).unwrap();
+        let person = NaturalPerson::with_clock(birth_date, MentalStatus::Normal, clock.clone());
+
+        assert!(!person.has_capacity());
+        assert!(!person.can_be_guardian());
+
+        clock.advance(chrono::Duration::days(1));
+        assert!(person.has_capacity());
+        assert!(person.can_be_guardian());
+    }
+
+    // 测试在指定时刻评估行为能力
+    #[test]
+    fn test_capacity_at() {
+        let birth_date = Utc.with_ymd_and_hms(2004, 12, 20, 0, 0, 0).unwrap();
+        let person = NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
+
+        assert_eq!(
+            person.capacity_at(Utc.with_ymd_and_hms(2012, 12, 20, 0, 0, 0).unwrap()),
+            NaturalCapacity::None
+        );
+        assert_eq!(
+            person.capacity_at(Utc.with_ymd_and_hms(2022, 6, 1, 0, 0, 0).unwrap()),
+            NaturalCapacity::Limited
+        );
+        assert_eq!(
+            person.capacity_at(Utc.with_ymd_and_hms(2022, 12, 21, 0, 0, 0).unwrap()),
+            NaturalCapacity::Full
+        );
+        assert!(!person.has_capacity_at(Utc.with_ymd_and_hms(2022, 6, 1, 0, 0, 0).unwrap()));
+
+        let sync_person = SyncNaturalPerson::from_natural_person(person);
+        assert_eq!(
+            sync_person.capacity_at(Utc.with_ymd_and_hms(2022, 6, 1, 0, 0, 0).unwrap()),
+            NaturalCapacity::Limited
+        );
+        assert_eq!(
+            sync_person.age_at(Utc.with_ymd_and_hms(2022, 12, 21, 0, 0, 0).unwrap()),
+            18
+        );
+    }
+
     // 测试自然人的行为能力评估
     #[test]
     fn test_evaluate_capacity() {
         let birth_date = get_test_date();
-        let person_baby = NaturalPerson::new(birth_date, MentalStatus::Normal);
-
-        let birth_date = Utc.with_ymd_and_hms(2014, 03, 12, 0, 0, 0).unwrap();
-        let person_teenage = NaturalPerson::new(birth_date, MentalStatus::Normal);
-
-        let birth_date = Utc.with_ymd_and_hms(2004, 03, 12, 0, 0, 0).unwrap();
-        let person_adult = NaturalPerson::new(birth_date, MentalStatus::Normal);
-        let person_ophelia = NaturalPerson::new(birth_date, MentalStatus::PartiallyImpaired);
-        let person_madman = NaturalPerson::new(birth_date, MentalStatus::SeverelyImpaired);
+        let person_baby =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
+
+        let birth_date = Utc.with_ymd_and_hms(2014, 3, 12, 0, 0, 0).unwrap();
+        let person_teenage =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
+
+        let birth_date = Utc.with_ymd_and_hms(2004, 3, 12, 0, 0, 0).unwrap();
+        let person_adult =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
+        // 成年人的行为能力须经法院认定才发生变化
+        let mut person_ophelia = NaturalPerson::with_clock(
+            birth_date,
+            MentalStatus::PartiallyImpaired,
+            get_test_clock(),
+        );
+        let mut person_madman =
+            NaturalPerson::with_clock(birth_date, MentalStatus::SeverelyImpaired, get_test_clock());
+        assert!(person_madman.has_capacity());
+        person_ophelia
+            .adjudicate_capacity(get_test_adjudication(NaturalCapacity::Limited))
+            .unwrap();
+        person_madman
+            .adjudicate_capacity(get_test_adjudication(NaturalCapacity::None))
+            .unwrap();
         println!("{:#?}", person_ophelia);
         assert_eq!(
             person_baby.base.capacity_status,
@@ -516,11 +1371,129 @@ mod tests {
         );
     }
 
+    // 测试认定与恢复认定按时间驱动行为能力，且历史记录完整保留
+    #[test]
+    fn test_adjudication_and_restoration() {
+        let birth_date = Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap();
+        let mut person =
+            NaturalPerson::with_clock(birth_date, MentalStatus::SeverelyImpaired, get_test_clock());
+
+        let incapacity = get_test_adjudication(NaturalCapacity::None);
+        let incapacity_id = incapacity.id();
+        person.adjudicate_capacity(incapacity).unwrap();
+
+        let restoration = CapacityAdjudication::new(
+            AdjudicationKind::Restoration,
+            Applicant::Person,
+            "北京市海淀区人民法院".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap(),
+            NaturalCapacity::Full,
+        )
+        .unwrap();
+        person.adjudicate_capacity(restoration).unwrap();
+
+        let before = Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap();
+        let during = Utc.with_ymd_and_hms(2023, 10, 1, 0, 0, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap();
+        assert_eq!(person.capacity_at(before), NaturalCapacity::Full);
+        assert_eq!(person.capacity_at(during), NaturalCapacity::None);
+        assert_eq!(person.capacity_at(after), NaturalCapacity::Full);
+
+        // 撤销原认定后，该期间视为未被认定
+        person
+            .revoke_adjudication(
+                incapacity_id,
+                Utc.with_ymd_and_hms(2023, 6, 1, 0, 0, 0).unwrap(),
+            )
+            .unwrap();
+        assert_eq!(person.capacity_at(during), NaturalCapacity::Full);
+        assert_eq!(person.adjudications().len(), 2);
+        assert!(person.adjudications()[0].revoked_at().is_some());
+    }
+
+    // 测试不符合第二十四条的认定被拒绝
+    #[test]
+    fn test_invalid_adjudication() {
+        // 本人不能申请认定自己无民事行为能力
+        assert!(CapacityAdjudication::new(
+            AdjudicationKind::Incapacity,
+            Applicant::Person,
+            "北京市海淀区人民法院".to_string(),
+            Utc.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap(),
+            NaturalCapacity::None,
+        )
+        .is_err());
+
+        // 不能对未成年人作出认定
+        let mut minor = NaturalPerson::with_clock(
+            get_test_date(),
+            MentalStatus::SeverelyImpaired,
+            get_test_clock(),
+        );
+        assert!(minor
+            .adjudicate_capacity(get_test_adjudication(NaturalCapacity::None))
+            .is_err());
+
+        // 没有在先认定时不能恢复
+        let birth_date = Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap();
+        let mut adult =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
+        let restoration = CapacityAdjudication::new(
+            AdjudicationKind::Restoration,
+            Applicant::Person,
+            "北京市海淀区人民法院".to_string(),
+            Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap(),
+            NaturalCapacity::Full,
+        )
+        .unwrap();
+        assert!(adult.adjudicate_capacity(restoration).is_err());
+    }
+
+    // 测试以劳动收入为主要生活来源的未成年人视为完全民事行为能力人
+    #[test]
+    fn test_self_supporting_minor() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2024, 9, 1, 0, 0, 0).unwrap(),
+        ));
+        let birth_date = Utc.with_ymd_and_hms(2007, 6, 1, 0, 0, 0).unwrap();
+        let mut person = NaturalPerson::with_clock(birth_date, MentalStatus::Normal, clock.clone());
+        assert!(!person.has_capacity());
+        assert!(!person.can_be_guardian());
+
+        // 未满十六周岁时开始的劳动收入不能作为依据
+        let too_early = SelfSupportBasis::new(
+            "某餐厅".to_string(),
+            vec!["劳动合同".to_string()],
+            Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+        );
+        assert!(person.record_self_support(too_early).is_err());
+
+        let basis = SelfSupportBasis::new(
+            "某餐厅".to_string(),
+            vec!["劳动合同".to_string(), "工资流水".to_string()],
+            Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+        );
+        person.record_self_support(basis).unwrap();
+        assert!(person.has_capacity());
+        assert!(person.can_be_guardian());
+        assert_eq!(
+            person.capacity_at(Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap()),
+            NaturalCapacity::Limited
+        );
+
+        person
+            .cease_self_support(Utc.with_ymd_and_hms(2024, 10, 1, 0, 0, 0).unwrap())
+            .unwrap();
+        clock.advance(chrono::Duration::days(60));
+        assert!(!person.has_capacity());
+    }
+
     // 测试更新自然人的精神状态并重新评估行为能力
     #[test]
     fn test_update_mental_status() {
         let birth_date = get_test_date();
-        let mut person = NaturalPerson::new(birth_date, MentalStatus::Normal);
+        let mut person =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
         person
             .update_mental_status(MentalStatus::SeverelyImpaired)
             .unwrap();
@@ -536,13 +1509,16 @@ mod tests {
     #[test]
     fn test_can_be_guardian() {
         let birth_date = get_test_date();
-        let person_baby = NaturalPerson::new(birth_date, MentalStatus::Normal);
+        let person_baby =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
 
-        let birth_date = Utc.with_ymd_and_hms(2014, 03, 12, 0, 0, 0).unwrap();
-        let person_teenage = NaturalPerson::new(birth_date, MentalStatus::Normal);
+        let birth_date = Utc.with_ymd_and_hms(2014, 3, 12, 0, 0, 0).unwrap();
+        let person_teenage =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
 
-        let birth_date = Utc.with_ymd_and_hms(2004, 03, 12, 0, 0, 0).unwrap();
-        let person_adult = NaturalPerson::new(birth_date, MentalStatus::Normal);
+        let birth_date = Utc.with_ymd_and_hms(2004, 3, 12, 0, 0, 0).unwrap();
+        let person_adult =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
 
         assert!(!person_baby.can_be_guardian());
         assert!(!person_teenage.can_be_guardian());
@@ -553,51 +1529,334 @@ mod tests {
     #[test]
     fn test_set_guardian() {
         let birth_date = get_test_date();
-        let mut person = NaturalPerson::new(birth_date, MentalStatus::SeverelyImpaired);
+        let mut person =
+            NaturalPerson::with_clock(birth_date, MentalStatus::SeverelyImpaired, get_test_clock());
 
         let birth_date = Utc.with_ymd_and_hms(2003, 12, 25, 0, 0, 0).unwrap();
-        let mut guardian = NaturalPerson::new(birth_date, MentalStatus::Normal);
+        let mut guardian =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
         let scope = get_test_guardianship_scope();
+        person.add_relative(guardian.id(), Relationship::Parent);
 
-        person.set_guardian(&mut guardian, scope.clone()).unwrap();
+        let candidate = person.guardian_candidate(&guardian);
+        let basis = person
+            .select_guardians(vec![candidate])
+            .unwrap()
+            .basis_for(guardian.id())
+            .unwrap();
+        person
+            .set_guardian(&mut guardian, basis, scope.clone())
+            .unwrap();
 
-        assert!(person.guardian.is_some());
-        assert_eq!(person.guardian.clone().unwrap().guardian, guardian.base.id);
+        assert_eq!(person.guardians().len(), 1);
+        assert_eq!(person.guardians()[0].guardian(), guardian.base.id);
         assert_eq!(
-            person.guardian.clone().unwrap().scope.permitted_actions,
-            scope.permitted_actions
+            person.guardians()[0].scope().permitted_actions(),
+            scope.permitted_actions()
         );
+        assert!(guardian.is_guardian_of(&person));
     }
 
     // 测试不能设置无资格的监护人
     #[test]
     fn test_set_invalid_guardian() {
         let birth_date = get_test_date();
-        let mut person = NaturalPerson::new(birth_date, MentalStatus::Normal);
-        let mut invalid_guardian = NaturalPerson::new(birth_date, MentalStatus::SeverelyImpaired); // 不符合监护人条件
+        let mut person =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
+        let mut invalid_guardian =
+            NaturalPerson::with_clock(birth_date, MentalStatus::SeverelyImpaired, get_test_clock()); // 不符合监护人条件
         let scope = get_test_guardianship_scope();
 
-        let result = person.set_guardian(&mut invalid_guardian, scope);
+        let selection = person
+            .select_guardians(vec![person.guardian_candidate(&invalid_guardian)])
+            .unwrap();
+        assert!(selection.requires_public_guardian());
 
+        // 伪造的依据同样被拒绝
+        let mut guardian = NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(2003, 12, 25, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            get_test_clock(),
+        );
+        let mut other_ward =
+            NaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
+        other_ward.add_relative(guardian.id(), Relationship::Sibling);
+        let basis: GuardianshipBasis = other_ward
+            .select_guardians(vec![other_ward.guardian_candidate(&guardian)])
+            .unwrap()
+            .designate(
+                GuardianshipAuthority::Court("北京市海淀区人民法院".to_string()),
+                guardian.id(),
+                get_test_clock().now(),
+                String::new(),
+            )
+            .unwrap()
+            .into();
+        let result = person.set_guardian(&mut invalid_guardian, basis.clone(), scope.clone());
+        assert!(result.is_err());
+        let result = person.set_guardian(&mut guardian, basis, scope);
         assert!(result.is_err());
     }
 
+    // 测试父母共同监护及监护关系的撤销与自然终止
+    #[test]
+    fn test_joint_guardianship_lifecycle() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let birth_date = Utc.with_ymd_and_hms(2009, 3, 1, 0, 0, 0).unwrap();
+        let mut ward = NaturalPerson::with_clock(birth_date, MentalStatus::Normal, clock.clone());
+        let parent_birth = Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap();
+        let mut father =
+            NaturalPerson::with_clock(parent_birth, MentalStatus::Normal, clock.clone());
+        let mut mother =
+            NaturalPerson::with_clock(parent_birth, MentalStatus::Normal, clock.clone());
+        ward.add_relative(father.id(), Relationship::Parent);
+        ward.add_relative(mother.id(), Relationship::Parent);
+
+        // 父母共同担任监护人
+        let selection = ward
+            .select_guardians(vec![
+                ward.guardian_candidate(&father),
+                ward.guardian_candidate(&mother),
+            ])
+            .unwrap();
+        for parent in [&mut father, &mut mother] {
+            let basis = selection.basis_for(parent.id()).unwrap();
+            ward.set_guardian(parent, basis, get_test_guardianship_scope())
+                .unwrap();
+        }
+        assert_eq!(ward.guardians().len(), 2);
+        let duplicate = selection.basis_for(father.id()).unwrap();
+        assert!(ward
+            .set_guardian(&mut father, duplicate, get_test_guardianship_scope())
+            .is_err());
+
+        // 被监护人尚未成年，不能以取得完全民事行为能力为由终止
+        let now = clock.now();
+        assert!(ward
+            .terminate_guardianship(&mut mother, TerminationCause::WardGainedCapacity, now)
+            .is_err());
+
+        // 人民法院撤销母亲的监护人资格
+        let ward_id = ward.id();
+        let ruling = |guardian: Uuid, ruled_at| {
+            GuardianshipRuling::new(
+                "北京市海淀区人民法院".to_string(),
+                ward_id,
+                guardian,
+                ruled_at,
+                "严重损害被监护人身心健康".to_string(),
+            )
+            .unwrap()
+        };
+        assert!(GuardianshipRuling::new(
+            "北京市海淀区人民法院".to_string(),
+            ward.id(),
+            mother.id(),
+            now,
+            String::new(),
+        )
+        .is_err());
+        // 判决须针对该监护人，且在终止时已经生效
+        for premature in [
+            ruling(father.id(), now),
+            ruling(mother.id(), now + chrono::Duration::days(1)),
+        ] {
+            assert!(ward
+                .terminate_guardianship(&mut mother, TerminationCause::Revoked(premature), now)
+                .is_err());
+        }
+        // 监护人尚生存，不能以死亡为由终止
+        assert!(ward
+            .terminate_guardianship(&mut mother, TerminationCause::GuardianDied, now)
+            .is_err());
+        let revoked = TerminationCause::Revoked(ruling(mother.id(), now));
+        ward.terminate_guardianship(&mut mother, revoked.clone(), now)
+            .unwrap();
+        assert_eq!(ward.guardians().len(), 1);
+        assert!(!mother.is_guardian_of(&ward));
+        assert!(father.is_guardian_of(&ward));
+        assert_eq!(ward.guardianships()[1].termination(), Some(&revoked));
+        assert!(ward
+            .terminate_guardianship(&mut mother, revoked, now)
+            .is_err());
+
+        // 成年后监护关系自然终止，无须另行记录
+        clock.set(Utc.with_ymd_and_hms(2027, 3, 2, 0, 0, 0).unwrap());
+        assert!(ward.guardians().is_empty());
+        assert!(!father.is_guardian_of(&ward));
+        let before = Utc.with_ymd_and_hms(2027, 2, 1, 0, 0, 0).unwrap();
+        assert!(father.is_guardian_of_at(&ward, before));
+
+        ward.terminate_guardianship(
+            &mut father,
+            TerminationCause::WardGainedCapacity,
+            clock.now(),
+        )
+        .unwrap();
+        assert!(father.is_guardian_of_at(&ward, before));
+    }
+
+    #[test]
+    fn test_guardianship_expiry() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let mut ward =
+            NaturalPerson::with_clock(get_test_date(), MentalStatus::Normal, clock.clone());
+        let mut guardian = NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(1990, 1, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock.clone(),
+        );
+        ward.add_relative(guardian.id(), Relationship::Grandparent);
+
+        // 指定临时监护人，期限一个月
+        let basis: GuardianshipBasis = ward
+            .select_guardians(vec![ward.guardian_candidate(&guardian)])
+            .unwrap()
+            .designate(
+                GuardianshipAuthority::ResidentsCommittee(Uuid::new_v4()),
+                guardian.id(),
+                clock.now(),
+                "临时监护".to_string(),
+            )
+            .unwrap()
+            .into();
+        let basis = basis.until(Utc.with_ymd_and_hms(2025, 7, 1, 0, 0, 0).unwrap());
+        ward.set_guardian(&mut guardian, basis, get_test_guardianship_scope())
+            .unwrap();
+        assert!(guardian.is_guardian_of(&ward));
+
+        clock.advance(chrono::Duration::days(30));
+        assert!(ward.guardians().is_empty());
+        assert!(!guardian.is_guardian_of(&ward));
+        assert!(ward
+            .terminate_guardianship(&mut guardian, TerminationCause::GuardianDied, clock.now())
+            .is_err());
+    }
+
+    #[test]
+    fn test_missing_and_declared_dead() {
+        let clock = Arc::new(ManualClock::new(
+            Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap(),
+        ));
+        let mut ward =
+            NaturalPerson::with_clock(get_test_date(), MentalStatus::Normal, clock.clone());
+        let mut guardian = NaturalPerson::with_clock(
+            Utc.with_ymd_and_hms(1980, 1, 1, 0, 0, 0).unwrap(),
+            MentalStatus::Normal,
+            clock.clone(),
+        );
+        ward.add_relative(guardian.id(), Relationship::Parent);
+        let basis = ward
+            .select_guardians(vec![ward.guardian_candidate(&guardian)])
+            .unwrap()
+            .basis_for(guardian.id())
+            .unwrap();
+        ward.set_guardian(&mut guardian, basis, get_test_guardianship_scope())
+            .unwrap();
+
+        // 宣告失踪：仍具有行为能力，但不能履行监护职责
+        let last_heard_of = Utc.with_ymd_and_hms(2023, 5, 1, 0, 0, 0).unwrap();
+        let custodian = Uuid::new_v4();
+        let missing = AbsenceDeclaration::missing(
+            Uuid::new_v4(),
+            "北京市海淀区人民法院".to_string(),
+            last_heard_of,
+            clock.now(),
+            custodian,
+        )
+        .unwrap();
+        let missing_id = missing.id();
+        guardian.record_absence(missing).unwrap();
+        assert_eq!(guardian.civil_status(), CivilStatus::Missing { custodian });
+        assert!(guardian.has_capacity());
+        assert!(!guardian.can_be_guardian());
+        assert!(!guardian.is_guardian_of(&ward));
+
+        let new_custodian = Uuid::new_v4();
+        guardian
+            .change_custodian(missing_id, new_custodian)
+            .unwrap();
+        assert_eq!(
+            guardian.civil_status(),
+            CivilStatus::Missing {
+                custodian: new_custodian
+            }
+        );
+        ward.terminate_guardianship(
+            &mut guardian,
+            TerminationCause::GuardianLostCapacity,
+            clock.now(),
+        )
+        .unwrap();
+
+        // 下落不明满四年，宣告死亡
+        clock.set(Utc.with_ymd_and_hms(2027, 6, 1, 0, 0, 0).unwrap());
+        let death = AbsenceDeclaration::death(
+            Uuid::new_v4(),
+            "北京市海淀区人民法院".to_string(),
+            last_heard_of,
+            clock.now(),
+            DisappearanceCause::Ordinary,
+        )
+        .unwrap();
+        let death_id = death.id();
+        guardian.record_absence(death).unwrap();
+        assert_eq!(
+            guardian.civil_status(),
+            CivilStatus::PresumedDead {
+                presumed_death: clock.now()
+            }
+        );
+        // 死亡宣告不影响其实际的行为能力，但不能担任监护人
+        assert!(guardian.has_capacity());
+        assert!(!guardian.can_be_guardian());
+        assert!(guardian
+            .record_absence(
+                AbsenceDeclaration::missing(
+                    Uuid::new_v4(),
+                    "北京市海淀区人民法院".to_string(),
+                    last_heard_of,
+                    clock.now(),
+                    custodian,
+                )
+                .unwrap()
+            )
+            .is_err());
+
+        // 重新出现，撤销死亡宣告和失踪宣告
+        clock.advance(chrono::Duration::days(10));
+        guardian.revoke_absence(death_id, clock.now()).unwrap();
+        assert!(matches!(
+            guardian.civil_status(),
+            CivilStatus::Missing { .. }
+        ));
+        guardian.revoke_absence(missing_id, clock.now()).unwrap();
+        assert_eq!(guardian.civil_status(), CivilStatus::Present);
+        assert!(guardian.has_capacity());
+        assert!(guardian.can_be_guardian());
+    }
+
     // 测试线程安全的自然人创建
     #[test]
     fn test_sync_natural_person_creation() {
         let birth_date = get_test_date();
         let mental_status = MentalStatus::Normal;
-        let sync_person = SyncNaturalPerson::new(birth_date, mental_status);
+        let sync_person =
+            SyncNaturalPerson::with_clock(birth_date, mental_status, get_test_clock());
 
         assert_eq!(sync_person.age(), 5);
-        assert_eq!(sync_person.age(), 5);
     }
 
     // 测试更新线程安全的自然人精神状态
     #[test]
     fn test_sync_update_mental_status() {
         let birth_date = get_test_date();
-        let sync_person = SyncNaturalPerson::new(birth_date, MentalStatus::Normal);
+        let sync_person =
+            SyncNaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
 
         sync_person
             .update_mental_status(MentalStatus::PartiallyImpaired)
@@ -613,16 +1872,18 @@ mod tests {
     #[test]
     fn test_sync_set_guardian() {
         let mut birth_date = get_test_date();
-        let person = SyncNaturalPerson::new(birth_date, MentalStatus::Normal);
+        let person =
+            SyncNaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
 
         birth_date = Utc.with_ymd_and_hms(2003, 12, 25, 0, 0, 0).unwrap();
-        let guardian = SyncNaturalPerson::new(birth_date, MentalStatus::Normal);
+        let guardian =
+            SyncNaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
         let scope = get_test_guardianship_scope();
 
         // println!("{:#?}", guardian);
 
         // let birth_date = Utc.with_ymd_and_hms(2003, 12, 25, 0, 0, 0).unwrap();
-        // let guardian2 = SyncNaturalPerson::new(birth_date, MentalStatus::Normal);
+        // let guardian2 = SyncNaturalPerson::with_clock(birth_date, MentalStatus::Normal, get_test_clock());
         // let scope = get_test_guardianship_scope();
         // println!("{:#?}", guardian2);
 
@@ -631,19 +1892,56 @@ mod tests {
         //     guardian2.can_be_guardian().unwrap()
         // );
 
-        SyncNaturalPerson::set_guardian(
-            &Arc::new(Mutex::new(person.clone())),
-            &Arc::new(Mutex::new(guardian)),
-            scope.clone(),
-        )
-        .unwrap();
+        person.add_relative(guardian.id(), Relationship::Grandparent);
+        let basis = person
+            .select_guardians(vec![person.guardian_candidate(&guardian).unwrap()])
+            .unwrap()
+            .basis_for(guardian.id())
+            .unwrap();
+
+        let ward = Arc::new(Mutex::new(person.clone()));
+        let guardian = Arc::new(Mutex::new(guardian));
+        SyncNaturalPerson::set_guardian(&ward, &guardian, basis, scope.clone()).unwrap();
+        assert!(guardian.lock().is_guardian_of(&person));
 
-        let ward_guard = person.guardian.read();
-        assert!(ward_guard.is_some());
+        let ward_guard = person.guardians();
+        assert_eq!(ward_guard.len(), 1);
         assert_eq!(
-            ward_guard.as_ref().unwrap().scope.permitted_actions.clone(),
-            scope.permitted_actions
+            ward_guard[0].scope().permitted_actions(),
+            scope.permitted_actions()
         );
+        assert!(matches!(
+            ward_guard[0].basis().source(),
+            BasisSource::Statutory(Relationship::Grandparent)
+        ));
+
+        // 监护人经宣告死亡后，方能以其死亡为由终止
+        let now = get_test_clock().now();
+        assert!(SyncNaturalPerson::terminate_guardianship(
+            &ward,
+            &guardian,
+            TerminationCause::GuardianDied,
+            now,
+        )
+        .is_err());
+        let death = AbsenceDeclaration::death(
+            Uuid::new_v4(),
+            "北京市海淀区人民法院".to_string(),
+            now,
+            now,
+            DisappearanceCause::AccidentCertifiedFatal { occurred_at: now },
+        )
+        .unwrap();
+        guardian.lock().record_absence(death).unwrap();
+        SyncNaturalPerson::terminate_guardianship(
+            &ward,
+            &guardian,
+            TerminationCause::GuardianDied,
+            now,
+        )
+        .unwrap();
+        assert!(person.guardians().is_empty());
+        assert!(!guardian.lock().is_guardian_of(&person));
     }
 
     // 测试从非线程安全版本转换为线程安全版本
@@ -651,7 +1949,7 @@ mod tests {
     fn test_from_natural_person() {
         let birth_date = get_test_date();
         let mental_status = MentalStatus::Normal;
-        let person = NaturalPerson::new(birth_date, mental_status);
+        let person = NaturalPerson::with_clock(birth_date, mental_status, get_test_clock());
         let sync_person = SyncNaturalPerson::from_natural_person(person);
 
         assert_eq!(sync_person.age(), 5);
@@ -0,0 +1,671 @@
+//! 合伙企业的合伙人
+//! 对应《合伙企业法》：普通合伙企业由普通合伙人组成（第二条），有限合伙企业由二个以上五十个以下合伙人设立，
+//! 至少应当有一个普通合伙人（第六十一条）。国有独资公司、国有企业、上市公司以及公益性的事业单位、社会团体
+//! 不得成为普通合伙人（第三条）。合伙人退伙、被除名或者转变身份的，对其此前发生的合伙企业债务仍按原身份承担责任。
+
+use crate::core::entity::base::{CapacityStatus, Entity, NaturalCapacity};
+use crate::core::entity::unincorporated::{
+    LiabilityType, Partner, PartnerType, PartnershipType, UnincorporatedOrgType,
+};
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+/// 有限合伙企业的合伙人人数上限
+pub const MAX_LIMITED_PARTNERSHIP_MEMBERS: usize = 50;
+
+/// 合伙人已不具备法定人数的，须在此期限（日）内补足，否则合伙企业应当解散（第八十五条第四项）
+pub const REPLENISH_PERIOD_DAYS: i64 = 30;
+
+/// 利润分配比例合计的容差
+const RATIO_TOLERANCE: f32 = 1e-4;
+
+/// 除名的事由（《合伙企业法》第四十九条）
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpulsionGround {
+    /// 未履行出资义务
+    FailureToContribute,
+    /// 因故意或者重大过失给合伙企业造成损失
+    WilfulOrGrossNegligence,
+    /// 执行合伙事务时有不正当行为
+    Misconduct,
+    /// 发生合伙协议约定的事由
+    AgreedCause(String),
+}
+
+/// 合伙人变动的方式
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartnerChangeKind {
+    /// 退伙（第四十五条至第四十八条）
+    Withdrawal,
+    /// 经其他合伙人一致同意被除名（第四十九条）
+    Expulsion(ExpulsionGround),
+    /// 普通合伙人与有限合伙人相互转变（第八十二条）
+    Conversion(PartnerType),
+}
+
+/// 一次合伙人变动的记录
+#[derive(Debug, Clone)]
+pub struct PartnerChange {
+    partner: Partner,            // 变动前的合伙人
+    kind: PartnerChangeKind,     // 变动方式
+    effective_at: DateTime<Utc>, // 生效之日
+}
+
+impl PartnerChange {
+    /// 获取变动前的合伙人
+    pub fn partner(&self) -> &Partner {
+        &self.partner
+    }
+
+    /// 获取变动方式
+    pub fn kind(&self) -> &PartnerChangeKind {
+        &self.kind
+    }
+
+    /// 获取生效之日
+    pub fn effective_at(&self) -> DateTime<Utc> {
+        self.effective_at
+    }
+
+    /// 是否使其不再是合伙人
+    pub fn ends_membership(&self) -> bool {
+        !matches!(self.kind, PartnerChangeKind::Conversion(_))
+    }
+}
+
+/// 合伙人退伙或者被除名后，合伙企业须采取的措施
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompositionFollowUp {
+    /// 有限合伙企业仅剩有限合伙人的，应当解散（第七十五条）
+    Dissolve,
+    /// 有限合伙企业仅剩普通合伙人的，应当转为普通合伙企业（第七十五条）
+    ConvertToGeneralPartnership,
+    /// 合伙人已不具备法定人数，`deadline` 前未补足的应当解散（第八十五条第四项）
+    Replenish { deadline: DateTime<Utc> },
+}
+
+/// 合伙人退伙或者被除名的结果
+#[derive(Debug, Clone)]
+pub struct PartnerExit {
+    partner: Partner,                       // 退伙或被除名的合伙人
+    follow_up: Option<CompositionFollowUp>, // 合伙企业须采取的措施，组成仍符合规定的为空
+}
+
+impl PartnerExit {
+    /// 获取退伙或被除名的合伙人
+    pub fn partner(&self) -> &Partner {
+        &self.partner
+    }
+
+    /// 获取合伙企业须采取的措施
+    pub fn follow_up(&self) -> Option<&CompositionFollowUp> {
+        self.follow_up.as_ref()
+    }
+}
+
+/// 某一主体能否成为普通合伙人
+///
+/// 自然人须具有完全民事行为能力（第十四条）；国有企业、上市公司以及公益性的法人不得成为普通合伙人（第三条）。
+pub fn check_general_partner(entity: &dyn Entity) -> FanResult<()> {
+    let qualified = match entity.capacity_status() {
+        CapacityStatus::NaturalPerson(capacity) => capacity == NaturalCapacity::Full,
+        _ => entity
+            .legal_form()
+            .is_none_or(|form| form.may_be_general_partner()),
+    };
+    if !qualified {
+        return Err(FanError::validation_with_ref(
+            "无完全民事行为能力的自然人、国有企业、上市公司以及公益性的事业单位、社会团体不得成为普通合伙人",
+            ValidationErrorType::ContractPartyUnqualified,
+            "《合伙企业法》第三条、第十四条",
+            "check_general_partner",
+            "UnincorporatedOrg",
+        ));
+    }
+    Ok(())
+}
+
+/// 校验新合伙人能否入伙
+pub(crate) fn check_admission(
+    org_type: &UnincorporatedOrgType,
+    members: &[Partner],
+    partner: &Partner,
+    entity: &dyn Entity,
+) -> FanResult<()> {
+    let UnincorporatedOrgType::Partnership(partnership_type) = org_type else {
+        return Err(FanError::validation(
+            "只有合伙企业才能有合伙人",
+            ValidationErrorType::EntityCapacityLacking,
+            "add_partner",
+            "UnincorporatedOrg",
+        ));
+    };
+    if partner.id() != entity.id() || members.iter().any(|m| m.id() == partner.id()) {
+        return Err(FanError::validation(
+            "合伙人与主体不一致，或已是本企业的合伙人",
+            ValidationErrorType::EntityRelationMalformed,
+            "add_partner",
+            "UnincorporatedOrg",
+        ));
+    }
+    check_partner_type(partnership_type, partner)?;
+    if *partner.partnership_type() == PartnerType::GeneralPartner {
+        check_general_partner(entity)?;
+    }
+    if *partnership_type == PartnershipType::Limited
+        && members.len() >= MAX_LIMITED_PARTNERSHIP_MEMBERS
+    {
+        return Err(FanError::validation_with_ref(
+            "有限合伙企业的合伙人不得超过五十个",
+            ValidationErrorType::EntityRelationMalformed,
+            "《合伙企业法》第六十一条",
+            "add_partner",
+            "UnincorporatedOrg",
+        ));
+    }
+    let ratio = partner.profit_sharing_ratio();
+    let total: f32 = members
+        .iter()
+        .map(|m| m.profit_sharing_ratio())
+        .sum::<f32>()
+        + ratio;
+    if !(0.0..=1.0).contains(&ratio) || total > 1.0 + RATIO_TOLERANCE {
+        return Err(FanError::validation(
+            "利润分配比例须在零至一之间，全体合伙人的比例合计不得超过一",
+            ValidationErrorType::EntityError,
+            "add_partner",
+            "UnincorporatedOrg",
+        ));
+    }
+    Ok(())
+}
+
+/// 合伙人类型须与合伙企业类型相符，责任方式须与合伙人类型相符
+fn check_partner_type(partnership_type: &PartnershipType, partner: &Partner) -> FanResult<()> {
+    let type_ok = *partnership_type == PartnershipType::Limited
+        || *partner.partnership_type() == PartnerType::GeneralPartner;
+    let liability_ok = match partner.partnership_type() {
+        PartnerType::GeneralPartner => *partner.liability_type() == LiabilityType::Unlimited,
+        PartnerType::LimitedPartner => *partner.liability_type() == LiabilityType::Limited,
+    };
+    if !type_ok || !liability_ok {
+        return Err(FanError::validation_with_ref(
+            "普通合伙企业只能由承担无限责任的普通合伙人组成，有限合伙人以出资额为限承担责任",
+            ValidationErrorType::EntityRelationMalformed,
+            "《合伙企业法》第二条",
+            "add_partner",
+            "UnincorporatedOrg",
+        ));
+    }
+    Ok(())
+}
+
+/// 校验合伙企业的组成
+///
+/// 普通合伙企业须有二个以上合伙人（第十四条），不得约定将全部利润分配给部分合伙人（第三十三条）；
+/// 有限合伙企业须有二个以上五十个以下合伙人，至少有一个普通合伙人（第六十一条）；利润分配比例合计须为一。
+pub(crate) fn check_composition(
+    org_type: &UnincorporatedOrgType,
+    members: &[Partner],
+) -> FanResult<()> {
+    let UnincorporatedOrgType::Partnership(partnership_type) = org_type else {
+        return Ok(());
+    };
+    let has_general = members
+        .iter()
+        .any(|m| *m.partnership_type() == PartnerType::GeneralPartner);
+    let (valid, reason, basis) = match partnership_type {
+        PartnershipType::Limited => (
+            (2..=MAX_LIMITED_PARTNERSHIP_MEMBERS).contains(&members.len()) && has_general,
+            "有限合伙企业须有二个以上五十个以下合伙人，且至少有一个普通合伙人",
+            "《合伙企业法》第六十一条",
+        ),
+        PartnershipType::General | PartnershipType::Special => (
+            members.len() >= 2 && members.iter().all(|m| m.profit_sharing_ratio() > 0.0),
+            "普通合伙企业须有二个以上合伙人，且不得将全部利润分配给部分合伙人",
+            "《合伙企业法》第十四条、第三十三条",
+        ),
+    };
+    let total: f32 = members.iter().map(|m| m.profit_sharing_ratio()).sum();
+    if !valid || (total - 1.0).abs() > RATIO_TOLERANCE {
+        return Err(FanError::validation_with_ref(
+            format!("{}，利润分配比例合计须为一", reason),
+            ValidationErrorType::EntityRelationMalformed,
+            basis,
+            "validate_composition",
+            "UnincorporatedOrg",
+        ));
+    }
+    Ok(())
+}
+
+/// 合伙人退伙或被除名，返回合伙企业因此须采取的措施
+pub(crate) fn remove_partner(
+    org_type: &UnincorporatedOrgType,
+    members: &mut Vec<Partner>,
+    changes: &mut Vec<PartnerChange>,
+    executive_partner: &mut Option<Uuid>,
+    partner_id: Uuid,
+    kind: PartnerChangeKind,
+    at: DateTime<Utc>,
+) -> FanResult<PartnerExit> {
+    let index = find_partner(members, partner_id, "remove_partner")?;
+    let partner = members.remove(index);
+    if *executive_partner == Some(partner_id) {
+        *executive_partner = None;
+    }
+    changes.push(PartnerChange {
+        partner: partner.clone(),
+        kind,
+        effective_at: at,
+    });
+    Ok(PartnerExit {
+        partner,
+        follow_up: composition_follow_up(org_type, members, at),
+    })
+}
+
+/// 除名须经其他合伙人一致同意（第四十九条）
+pub(crate) fn check_expulsion_consents(
+    members: &[Partner],
+    partner_id: Uuid,
+    consents: &[Uuid],
+) -> FanResult<()> {
+    find_partner(members, partner_id, "expel_partner")?;
+    let unanimous = members
+        .iter()
+        .filter(|m| m.id() != partner_id)
+        .all(|m| consents.contains(&m.id()));
+    let valid = consents
+        .iter()
+        .all(|c| *c != partner_id && members.iter().any(|m| m.id() == *c));
+    if !unanimous || !valid {
+        return Err(FanError::validation_with_ref(
+            "除名须经被除名人以外的其他合伙人一致同意",
+            ValidationErrorType::OperationUnauthorized,
+            "《合伙企业法》第四十九条",
+            "expel_partner",
+            "UnincorporatedOrg",
+        ));
+    }
+    Ok(())
+}
+
+/// 合伙人变动后合伙企业须采取的措施，组成仍符合规定的为空
+fn composition_follow_up(
+    org_type: &UnincorporatedOrgType,
+    members: &[Partner],
+    at: DateTime<Utc>,
+) -> Option<CompositionFollowUp> {
+    let UnincorporatedOrgType::Partnership(partnership_type) = org_type else {
+        return None;
+    };
+    let has = |partner_type: PartnerType| {
+        members
+            .iter()
+            .any(|m| *m.partnership_type() == partner_type)
+    };
+    if *partnership_type == PartnershipType::Limited {
+        if !has(PartnerType::GeneralPartner) {
+            return Some(CompositionFollowUp::Dissolve);
+        }
+        if !has(PartnerType::LimitedPartner) {
+            return Some(CompositionFollowUp::ConvertToGeneralPartnership);
+        }
+    }
+    (members.len() < 2).then(|| CompositionFollowUp::Replenish {
+        deadline: at + Duration::days(REPLENISH_PERIOD_DAYS),
+    })
+}
+
+/// 普通合伙人与有限合伙人相互转变
+pub(crate) fn convert_partner(
+    org_type: &UnincorporatedOrgType,
+    members: &mut [Partner],
+    changes: &mut Vec<PartnerChange>,
+    entity: &dyn Entity,
+    to: PartnerType,
+    at: DateTime<Utc>,
+) -> FanResult<()> {
+    if *org_type != UnincorporatedOrgType::Partnership(PartnershipType::Limited) {
+        return Err(FanError::validation_with_ref(
+            "只有有限合伙企业的合伙人才能转变身份",
+            ValidationErrorType::EntityRelationMalformed,
+            "《合伙企业法》第八十二条",
+            "convert_partner",
+            "UnincorporatedOrg",
+        ));
+    }
+    let partner_id = entity.id();
+    let index = find_partner(members, partner_id, "convert_partner")?;
+    if *members[index].partnership_type() == to {
+        return Err(FanError::validation(
+            "合伙人已是该类型",
+            ValidationErrorType::OperationSequenceWrong,
+            "convert_partner",
+            "UnincorporatedOrg",
+        ));
+    }
+    // 转变后仍须既有普通合伙人又有有限合伙人
+    let remains = members
+        .iter()
+        .enumerate()
+        .any(|(i, m)| i != index && *m.partnership_type() != to);
+    if !remains {
+        return Err(FanError::validation_with_ref(
+            "有限合伙企业转变身份后须仍有普通合伙人和有限合伙人",
+            ValidationErrorType::EntityRelationMalformed,
+            "《合伙企业法》第六十一条、第七十五条",
+            "convert_partner",
+            "UnincorporatedOrg",
+        ));
+    }
+    let liability = match to {
+        PartnerType::GeneralPartner => {
+            check_general_partner(entity)?;
+            LiabilityType::Unlimited
+        }
+        PartnerType::LimitedPartner => {
+            if !members[index].contribution().is_positive() {
+                return Err(FanError::validation_with_ref(
+                    "以劳务出资的合伙人不能转为有限合伙人",
+                    ValidationErrorType::EntityError,
+                    "《合伙企业法》第六十四条",
+                    "convert_partner",
+                    "UnincorporatedOrg",
+                ));
+            }
+            LiabilityType::Limited
+        }
+    };
+    changes.push(PartnerChange {
+        partner: members[index].clone(),
+        kind: PartnerChangeKind::Conversion(to.clone()),
+        effective_at: at,
+    });
+    members[index] = Partner::new(
+        partner_id,
+        to,
+        members[index].contribution(),
+        members[index].profit_sharing_ratio(),
+        liability,
+    );
+    Ok(())
+}
+
+/// 合伙人对在指定时刻发生的合伙企业债务承担责任的方式，不承担责任的为空
+///
+/// 退伙人、被除名人对其退伙前发生的债务按原身份承担责任（第五十三条、第八十一条）；有限合伙人转为普通合伙人的，
+/// 对其作为有限合伙人期间的债务承担无限连带责任（第八十三条）；普通合伙人转为有限合伙人的，对其作为普通合伙人
+/// 期间的债务承担无限连带责任（第八十四条）；新入伙的普通合伙人对入伙前的债务承担无限连带责任（第四十四条）。
+pub(crate) fn liability_for_debt(
+    members: &[Partner],
+    changes: &[PartnerChange],
+    partner_id: Uuid,
+    incurred_at: DateTime<Utc>,
+) -> Option<LiabilityType> {
+    let history: Vec<&PartnerChange> = changes
+        .iter()
+        .filter(|c| c.partner.id() == partner_id)
+        .collect();
+    // 债务发生时的身份：其后第一次变动前的身份，其后没有变动的为当前身份
+    let type_at_debt = |current: &PartnerType| {
+        history
+            .iter()
+            .find(|c| c.effective_at > incurred_at)
+            .map_or(current.clone(), |c| c.partner.partnership_type().clone())
+    };
+    let unlimited = match members.iter().find(|m| m.id() == partner_id) {
+        Some(current) => {
+            *current.partnership_type() == PartnerType::GeneralPartner
+                || type_at_debt(current.partnership_type()) == PartnerType::GeneralPartner
+        }
+        None => {
+            let exit = history.iter().rev().find(|c| c.ends_membership())?;
+            if incurred_at >= exit.effective_at {
+                return None;
+            }
+            type_at_debt(exit.partner.partnership_type()) == PartnerType::GeneralPartner
+        }
+    };
+    Some(if unlimited {
+        LiabilityType::Unlimited
+    } else {
+        LiabilityType::Limited
+    })
+}
+
+fn find_partner(members: &[Partner], partner_id: Uuid, operation: &str) -> FanResult<usize> {
+    members
+        .iter()
+        .position(|m| m.id() == partner_id)
+        .ok_or_else(|| {
+            FanError::validation(
+                "Partner not found",
+                ValidationErrorType::EntityError,
+                operation,
+                "UnincorporatedOrg",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::entity::{CompanyType, LegalPerson, LegalPersonType};
+    use crate::core::money::Money;
+    use crate::testing::{fixed_clock, now, person};
+    use rust_decimal::Decimal;
+
+    fn partner(id: Uuid, partner_type: PartnerType, ratio: f32) -> Partner {
+        let liability = match partner_type {
+            PartnerType::GeneralPartner => LiabilityType::Unlimited,
+            PartnerType::LimitedPartner => LiabilityType::Limited,
+        };
+        Partner::new(
+            id,
+            partner_type,
+            Money::cny(Decimal::from(100_000)),
+            ratio,
+            liability,
+        )
+    }
+
+    fn limited_partnership() -> UnincorporatedOrgType {
+        UnincorporatedOrgType::Partnership(PartnershipType::Limited)
+    }
+
+    #[test]
+    fn test_general_partner_qualification() {
+        assert!(check_general_partner(&person(1990)).is_ok());
+        assert!(check_general_partner(&person(2015)).is_err());
+
+        let listed = LegalPerson::with_clock(
+            LegalPersonType::Company(CompanyType::Listed),
+            Money::cny(Decimal::from(100_000_000)),
+            Uuid::new_v4(),
+            "深圳市福田区".to_string(),
+            now(),
+            fixed_clock(),
+        );
+        assert!(check_general_partner(&listed).is_err());
+    }
+
+    #[test]
+    fn test_admission_matches_partnership_type() {
+        let general = UnincorporatedOrgType::Partnership(PartnershipType::General);
+        let adult = person(1990);
+        let as_limited = partner(adult.id(), PartnerType::LimitedPartner, 0.5);
+        assert!(check_admission(&general, &[], &as_limited, &adult).is_err());
+        assert!(check_admission(&limited_partnership(), &[], &as_limited, &adult).is_ok());
+
+        // 主体与合伙人不一致、重复入伙或利润分配比例合计超过一的，不能入伙
+        let as_general = partner(adult.id(), PartnerType::GeneralPartner, 0.5);
+        assert!(check_admission(&general, &[], &as_general, &person(1980)).is_err());
+        assert!(check_admission(
+            &general,
+            std::slice::from_ref(&as_general),
+            &as_general,
+            &adult
+        )
+        .is_err());
+        let other = person(1985);
+        let greedy = partner(other.id(), PartnerType::GeneralPartner, 0.6);
+        assert!(check_admission(&general, &[as_general], &greedy, &other).is_err());
+    }
+
+    #[test]
+    fn test_composition_requires_general_partner() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let only_limited = [
+            partner(a, PartnerType::LimitedPartner, 0.5),
+            partner(b, PartnerType::LimitedPartner, 0.5),
+        ];
+        assert!(check_composition(&limited_partnership(), &only_limited).is_err());
+
+        let mixed = [
+            partner(a, PartnerType::GeneralPartner, 0.5),
+            partner(b, PartnerType::LimitedPartner, 0.5),
+        ];
+        assert!(check_composition(&limited_partnership(), &mixed).is_ok());
+        assert!(check_composition(&limited_partnership(), &mixed[..1]).is_err());
+    }
+
+    #[test]
+    fn test_exit_returns_required_follow_up() {
+        let (gp, lp, other) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut members = vec![
+            partner(gp, PartnerType::GeneralPartner, 0.4),
+            partner(lp, PartnerType::LimitedPartner, 0.3),
+            partner(other, PartnerType::LimitedPartner, 0.3),
+        ];
+        let (mut changes, mut executive) = (Vec::new(), Some(gp));
+
+        // 唯一的普通合伙人退伙，有限合伙企业应当解散
+        let exit = remove_partner(
+            &limited_partnership(),
+            &mut members,
+            &mut changes,
+            &mut executive,
+            gp,
+            PartnerChangeKind::Withdrawal,
+            now(),
+        )
+        .unwrap();
+        assert_eq!(exit.follow_up(), Some(&CompositionFollowUp::Dissolve));
+        assert_eq!(executive, None);
+
+        // 普通合伙企业仅剩一名合伙人的，应在三十日内补足
+        let general = UnincorporatedOrgType::Partnership(PartnershipType::General);
+        let mut members = vec![
+            partner(gp, PartnerType::GeneralPartner, 0.5),
+            partner(other, PartnerType::GeneralPartner, 0.5),
+        ];
+        let exit = remove_partner(
+            &general,
+            &mut members,
+            &mut changes,
+            &mut None,
+            other,
+            PartnerChangeKind::Withdrawal,
+            now(),
+        )
+        .unwrap();
+        assert_eq!(
+            exit.follow_up(),
+            Some(&CompositionFollowUp::Replenish {
+                deadline: now() + Duration::days(REPLENISH_PERIOD_DAYS)
+            })
+        );
+    }
+
+    #[test]
+    fn test_expulsion_requires_unanimous_consent() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let members = [
+            partner(a, PartnerType::GeneralPartner, 0.4),
+            partner(b, PartnerType::GeneralPartner, 0.3),
+            partner(c, PartnerType::GeneralPartner, 0.3),
+        ];
+        assert!(check_expulsion_consents(&members, c, &[a]).is_err());
+        assert!(check_expulsion_consents(&members, c, &[a, b, c]).is_err());
+        assert!(check_expulsion_consents(&members, c, &[a, b, Uuid::new_v4()]).is_err());
+        assert!(check_expulsion_consents(&members, Uuid::new_v4(), &[a, b, c]).is_err());
+        assert!(check_expulsion_consents(&members, c, &[b, a]).is_ok());
+    }
+
+    #[test]
+    fn test_conversion_keeps_both_partner_types() {
+        let (manager, investor) = (person(1980), person(1985));
+        let mut members = vec![
+            partner(manager.id(), PartnerType::GeneralPartner, 0.5),
+            partner(investor.id(), PartnerType::LimitedPartner, 0.5),
+        ];
+        let mut changes = Vec::new();
+        let convert = |members: &mut Vec<Partner>, changes: &mut Vec<PartnerChange>, entity, to| {
+            convert_partner(&limited_partnership(), members, changes, entity, to, now())
+        };
+        assert!(convert(
+            &mut members,
+            &mut changes,
+            &manager,
+            PartnerType::LimitedPartner
+        )
+        .is_err());
+        assert!(convert(
+            &mut members,
+            &mut changes,
+            &investor,
+            PartnerType::GeneralPartner
+        )
+        .is_err());
+        assert!(changes.is_empty());
+
+        let newcomer = person(1990);
+        members.push(partner(newcomer.id(), PartnerType::LimitedPartner, 0.0));
+        convert(
+            &mut members,
+            &mut changes,
+            &investor,
+            PartnerType::GeneralPartner,
+        )
+        .unwrap();
+        assert_eq!(
+            changes[0].kind(),
+            &PartnerChangeKind::Conversion(PartnerType::GeneralPartner)
+        );
+        assert!(!changes[0].ends_membership());
+    }
+
+    #[test]
+    fn test_former_partner_liable_for_prior_debts() {
+        let (gp, lp) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut members = vec![
+            partner(gp, PartnerType::GeneralPartner, 0.5),
+            partner(lp, PartnerType::LimitedPartner, 0.5),
+        ];
+        let mut changes = Vec::new();
+        let before = now() - Duration::days(1);
+        remove_partner(
+            &limited_partnership(),
+            &mut members,
+            &mut changes,
+            &mut None,
+            lp,
+            PartnerChangeKind::Expulsion(ExpulsionGround::Misconduct),
+            now(),
+        )
+        .unwrap();
+        assert_eq!(
+            liability_for_debt(&members, &changes, lp, before),
+            Some(LiabilityType::Limited)
+        );
+        assert_eq!(liability_for_debt(&members, &changes, lp, now()), None);
+        assert_eq!(
+            liability_for_debt(&members, &changes, gp, now()),
+            Some(LiabilityType::Unlimited)
+        );
+    }
+}
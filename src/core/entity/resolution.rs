@@ -0,0 +1,776 @@
+//! 组织的决议
+//! 对应《民法典》第八十五条：营利法人的权力机构、执行机构作出决定的会议召集程序、表决方式违反法律、行政法规、
+//! 法人章程，或者决议内容违反法人章程的，营利法人的出资人可以请求人民法院撤销该决议。
+//! 决议无效、可撤销与不成立的具体情形依《公司法》第二十五条至第二十七条；合伙企业的表决依《合伙企业法》第三十条、第三十一条。
+
+use crate::core::entity::cap_table::CapTable;
+use crate::core::entity::legal_person::{CompanyType, LegalPerson, LegalPersonType};
+use crate::core::entity::unincorporated::{Partner, UnincorporatedOrg, UnincorporatedOrgType};
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// 股东自决议作出之日起请求撤销的期限（日）
+pub const REVOCATION_PERIOD_DAYS: i64 = 60;
+
+/// 作出决议的机构
+#[derive(Debug, Clone, PartialEq)]
+pub enum GoverningBody {
+    /// 股东会，按出资比例行使表决权
+    ShareholdersMeeting(CompanyType),
+    /// 董事会，一人一票
+    BoardOfDirectors(CompanyType),
+    /// 合伙人会议，一人一票
+    PartnersMeeting,
+}
+
+impl GoverningBody {
+    /// 是否为营利法人的机构，适用第八十五条的撤销规则
+    pub fn is_for_profit_legal_person(&self) -> bool {
+        !matches!(self, GoverningBody::PartnersMeeting)
+    }
+}
+
+/// 会议的形式
+#[derive(Debug, Clone, PartialEq)]
+pub enum MeetingType {
+    /// 定期会议
+    Regular,
+    /// 临时会议
+    Extraordinary,
+    /// 不召开会议，全体表决权人以书面形式一致同意（《公司法》第五十九条第三款）
+    WrittenConsent,
+}
+
+/// 决议事项
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolutionSubject {
+    /// 一般事项
+    Ordinary(String),
+    /// 修改公司章程或合伙协议
+    AmendArticles,
+    /// 增加或者减少注册资本
+    ChangeCapital,
+    /// 合并、分立、解散
+    MergerDivisionOrDissolution,
+    /// 变更公司形式
+    ChangeCorporateForm,
+    /// 改变合伙企业的名称
+    ChangeName,
+    /// 改变经营范围、主要经营场所的地点
+    ChangeBusinessScope,
+    /// 处分不动产
+    DisposeRealProperty,
+    /// 转让或者处分知识产权和其他财产权利
+    TransferIntellectualProperty,
+    /// 以企业名义为他人提供担保
+    ProvideGuarantee,
+    /// 聘任合伙人以外的人担任经营管理人员
+    AppointManager,
+    /// 新合伙人入伙
+    AdmitPartner,
+}
+
+impl ResolutionSubject {
+    /// 是否为须经代表三分之二以上表决权的股东通过的特别决议事项（《公司法》第六十六条、第一百一十六条）
+    pub fn is_special_for_company(&self) -> bool {
+        matches!(
+            self,
+            ResolutionSubject::AmendArticles
+                | ResolutionSubject::ChangeCapital
+                | ResolutionSubject::MergerDivisionOrDissolution
+                | ResolutionSubject::ChangeCorporateForm
+        )
+    }
+
+    /// 是否为须经全体合伙人一致同意的事项（《合伙企业法》第十九条、第三十一条、第四十三条、第八十五条）
+    pub fn requires_partner_unanimity(&self) -> bool {
+        matches!(
+            self,
+            ResolutionSubject::AmendArticles
+                | ResolutionSubject::MergerDivisionOrDissolution
+                | ResolutionSubject::ChangeName
+                | ResolutionSubject::ChangeBusinessScope
+                | ResolutionSubject::DisposeRealProperty
+                | ResolutionSubject::TransferIntellectualProperty
+                | ResolutionSubject::ProvideGuarantee
+                | ResolutionSubject::AppointManager
+                | ResolutionSubject::AdmitPartner
+        )
+    }
+}
+
+/// 会议
+#[derive(Debug, Clone)]
+pub struct Meeting {
+    body: GoverningBody,               // 作出决议的机构
+    meeting_type: MeetingType,         // 会议形式
+    noticed_at: Option<DateTime<Utc>>, // 会议通知之日
+    held_at: DateTime<Utc>,            // 会议召开或书面决定作出之日
+}
+
+impl Meeting {
+    pub fn new(
+        body: GoverningBody,
+        meeting_type: MeetingType,
+        noticed_at: Option<DateTime<Utc>>,
+        held_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            body,
+            meeting_type,
+            noticed_at,
+            held_at,
+        }
+    }
+
+    /// 获取作出决议的机构
+    pub fn body(&self) -> &GoverningBody {
+        &self.body
+    }
+
+    /// 获取会议形式
+    pub fn meeting_type(&self) -> &MeetingType {
+        &self.meeting_type
+    }
+
+    /// 获取会议通知之日
+    pub fn noticed_at(&self) -> Option<DateTime<Utc>> {
+        self.noticed_at
+    }
+
+    /// 获取会议召开之日
+    pub fn held_at(&self) -> DateTime<Utc> {
+        self.held_at
+    }
+}
+
+/// 享有表决权的成员及其表决权数
+#[derive(Debug, Clone)]
+pub struct Electorate {
+    body: GoverningBody,          // 作出决议的机构
+    voters: Vec<(Uuid, Decimal)>, // 表决权人及其表决权数
+}
+
+impl Electorate {
+    /// 公司的股东，按认缴出资比例行使表决权（《公司法》第六十五条）
+    pub fn shareholders(legal_person_type: &LegalPersonType, table: &CapTable) -> FanResult<Self> {
+        let LegalPersonType::Company(company_type) = legal_person_type else {
+            return Err(not_applicable("shareholders"));
+        };
+        Ok(Self {
+            body: GoverningBody::ShareholdersMeeting(company_type.clone()),
+            voters: table
+                .holdings()
+                .iter()
+                .map(|h| (h.shareholder(), h.subscribed().amount()))
+                .collect(),
+        })
+    }
+
+    /// 公司股东会的表决权人
+    pub fn of_company(company: &LegalPerson) -> FanResult<Self> {
+        let table = company
+            .cap_table()
+            .ok_or_else(|| not_applicable("of_company"))?;
+        Self::shareholders(company.legal_person_type(), table)
+    }
+
+    /// 合伙人会议的表决权人
+    pub fn of_partnership(org: &UnincorporatedOrg) -> FanResult<Self> {
+        Self::partners(org.org_type(), org.members())
+    }
+
+    /// 公司的董事会成员，一人一票（《公司法》第七十三条）
+    pub fn board(legal_person_type: &LegalPersonType, directors: &[Uuid]) -> FanResult<Self> {
+        let LegalPersonType::Company(company_type) = legal_person_type else {
+            return Err(not_applicable("board"));
+        };
+        Ok(Self {
+            body: GoverningBody::BoardOfDirectors(company_type.clone()),
+            voters: directors.iter().map(|d| (*d, Decimal::ONE)).collect(),
+        })
+    }
+
+    /// 合伙企业的全体合伙人，一人一票（《合伙企业法》第三十条）
+    pub fn partners(org_type: &UnincorporatedOrgType, members: &[Partner]) -> FanResult<Self> {
+        if !matches!(org_type, UnincorporatedOrgType::Partnership(_)) {
+            return Err(not_applicable("partners"));
+        }
+        Ok(Self {
+            body: GoverningBody::PartnersMeeting,
+            voters: members.iter().map(|p| (p.id(), Decimal::ONE)).collect(),
+        })
+    }
+
+    /// 获取作出决议的机构
+    pub fn body(&self) -> &GoverningBody {
+        &self.body
+    }
+
+    /// 某一成员的表决权数
+    pub fn weight(&self, voter: Uuid) -> Option<Decimal> {
+        self.voters
+            .iter()
+            .find(|(id, _)| *id == voter)
+            .map(|(_, weight)| *weight)
+    }
+
+    /// 全部表决权数
+    pub fn total(&self) -> Decimal {
+        self.voters.iter().map(|(_, weight)| *weight).sum()
+    }
+}
+
+/// 计算通过比例的基数
+#[derive(Debug, Clone, PartialEq)]
+pub enum VoteBase {
+    /// 全部表决权
+    All,
+    /// 出席会议的表决权
+    Attending,
+}
+
+/// 通过决议所需的表决权
+#[derive(Debug, Clone, PartialEq)]
+pub enum Threshold {
+    /// 过半数
+    MoreThanHalf(VoteBase),
+    /// 三分之二以上
+    TwoThirds(VoteBase),
+    /// 全体一致同意
+    Unanimous,
+    /// 章程规定的比例以上
+    AtLeast(Decimal, VoteBase),
+}
+
+impl Threshold {
+    fn is_met(&self, votes_for: Decimal, attending: Decimal, total: Decimal) -> bool {
+        let base = |b: &VoteBase| match b {
+            VoteBase::All => total,
+            VoteBase::Attending => attending,
+        };
+        match self {
+            Threshold::MoreThanHalf(b) => votes_for * Decimal::TWO > base(b),
+            Threshold::TwoThirds(b) => votes_for * Decimal::from(3) >= base(b) * Decimal::TWO,
+            Threshold::Unanimous => votes_for == total,
+            Threshold::AtLeast(ratio, b) => votes_for >= base(b) * ratio,
+        }
+    }
+}
+
+/// 表决规则，章程或合伙协议可以另行规定
+#[derive(Debug, Clone, PartialEq)]
+pub struct VotingRules {
+    pub notice_days: i64,        // 会议召开前通知的天数
+    pub quorum: Option<Decimal>, // 出席会议的表决权占全部表决权的最低比例
+    pub threshold: Threshold,    // 通过决议所需的表决权
+}
+
+impl VotingRules {
+    /// 法律规定的表决规则
+    pub fn statutory(
+        body: &GoverningBody,
+        meeting_type: &MeetingType,
+        subject: &ResolutionSubject,
+    ) -> Self {
+        let half = Some(Decimal::new(5, 1));
+        match body {
+            GoverningBody::ShareholdersMeeting(company_type) if company_type.is_joint_stock() => {
+                Self {
+                    // 年会提前二十日、临时会议提前十五日通知（《公司法》第一百一十五条）
+                    notice_days: if *meeting_type == MeetingType::Regular {
+                        20
+                    } else {
+                        15
+                    },
+                    quorum: None,
+                    threshold: if subject.is_special_for_company() {
+                        Threshold::TwoThirds(VoteBase::Attending)
+                    } else {
+                        Threshold::MoreThanHalf(VoteBase::Attending)
+                    },
+                }
+            }
+            GoverningBody::ShareholdersMeeting(_) => Self {
+                // 召开股东会会议，应当于会议召开十五日前通知全体股东（《公司法》第六十四条）
+                notice_days: 15,
+                quorum: None,
+                threshold: if subject.is_special_for_company() {
+                    Threshold::TwoThirds(VoteBase::All)
+                } else {
+                    Threshold::MoreThanHalf(VoteBase::All)
+                },
+            },
+            GoverningBody::BoardOfDirectors(company_type) => Self {
+                // 股份有限公司董事会每次会议应当于会议召开十日前通知（《公司法》第一百二十三条）
+                notice_days: if company_type.is_joint_stock() { 10 } else { 0 },
+                quorum: half,
+                threshold: Threshold::MoreThanHalf(VoteBase::All),
+            },
+            GoverningBody::PartnersMeeting => Self {
+                notice_days: 0,
+                quorum: None,
+                threshold: if subject.requires_partner_unanimity() {
+                    Threshold::Unanimous
+                } else {
+                    Threshold::MoreThanHalf(VoteBase::All)
+                },
+            },
+        }
+    }
+}
+
+/// 表决意见
+#[derive(Debug, Clone, PartialEq)]
+pub enum VoteChoice {
+    For,     // 同意
+    Against, // 反对
+    Abstain, // 弃权
+}
+
+/// 决议内容的瑕疵
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentDefect {
+    /// 内容违反法律、行政法规，决议无效（《公司法》第二十五条）
+    ViolatesLaw(String),
+    /// 内容违反章程或合伙协议，决议可撤销
+    ViolatesArticles(String),
+}
+
+/// 决议的效力
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolutionValidity {
+    /// 有效
+    Valid,
+    /// 可撤销，营利法人的出资人应在期限内请求人民法院撤销
+    Revocable { deadline: Option<DateTime<Utc>> },
+    /// 无效
+    Void,
+    /// 不成立
+    NotFormed,
+}
+
+impl ResolutionValidity {
+    fn severity(&self) -> u8 {
+        match self {
+            ResolutionValidity::Valid => 0,
+            ResolutionValidity::Revocable { .. } => 1,
+            ResolutionValidity::Void => 2,
+            ResolutionValidity::NotFormed => 3,
+        }
+    }
+}
+
+/// 决议的一项瑕疵及其后果
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolutionDefect {
+    pub validity: ResolutionValidity, // 该瑕疵导致的效力
+    pub reason: String,               // 瑕疵的具体情形
+    pub legal_basis: String,          // 法律依据
+}
+
+/// 决议的效力审查结果
+///
+/// 决议被确认无效、撤销或不成立的，组织依据该决议与善意相对人形成的民事法律关系不受影响（第八十五条但书）。
+#[derive(Debug, Clone)]
+pub struct ResolutionReport {
+    pub validity: ResolutionValidity,   // 决议的效力
+    pub defects: Vec<ResolutionDefect>, // 全部瑕疵
+    pub votes_for: Decimal,             // 同意的表决权数
+    pub attending: Decimal,             // 出席的表决权数
+    pub total: Decimal,                 // 全部表决权数
+}
+
+/// 决议
+#[derive(Debug, Clone)]
+pub struct Resolution {
+    id: Uuid,                            // 决议ID
+    meeting: Meeting,                    // 作出决议的会议
+    subject: ResolutionSubject,          // 决议事项
+    votes: Vec<(Uuid, VoteChoice)>,      // 表决情况
+    content_defects: Vec<ContentDefect>, // 决议内容的瑕疵
+}
+
+impl Resolution {
+    pub fn new(meeting: Meeting, subject: ResolutionSubject) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            meeting,
+            subject,
+            votes: Vec::new(),
+            content_defects: Vec::new(),
+        }
+    }
+
+    /// 记录一名成员的表决，同一成员只能表决一次
+    pub fn cast(&mut self, voter: Uuid, choice: VoteChoice) -> FanResult<()> {
+        if self.votes.iter().any(|(id, _)| *id == voter) {
+            return Err(FanError::validation(
+                "同一成员不能重复表决",
+                ValidationErrorType::OperationSequenceWrong,
+                "cast",
+                "Resolution",
+            ));
+        }
+        self.votes.push((voter, choice));
+        Ok(())
+    }
+
+    /// 记录决议内容的瑕疵
+    pub fn add_content_defect(&mut self, defect: ContentDefect) {
+        self.content_defects.push(defect);
+    }
+
+    /// 按法律规定的表决规则审查决议的效力
+    pub fn evaluate(&self, electorate: &Electorate) -> FanResult<ResolutionReport> {
+        let rules = VotingRules::statutory(
+            &self.meeting.body,
+            &self.meeting.meeting_type,
+            &self.subject,
+        );
+        self.evaluate_with(electorate, &rules)
+    }
+
+    /// 按章程或合伙协议规定的表决规则审查决议的效力
+    pub fn evaluate_with(
+        &self,
+        electorate: &Electorate,
+        rules: &VotingRules,
+    ) -> FanResult<ResolutionReport> {
+        if electorate.body != self.meeting.body {
+            return Err(FanError::validation(
+                "表决权人名单与作出决议的机构不一致",
+                ValidationErrorType::EntityRelationMalformed,
+                "evaluate",
+                "Resolution",
+            ));
+        }
+
+        let company = self.meeting.body.is_for_profit_legal_person();
+        let revocable = ResolutionValidity::Revocable {
+            deadline: company
+                .then(|| self.meeting.held_at + Duration::days(REVOCATION_PERIOD_DAYS)),
+        };
+        let mut defects = Vec::new();
+        let mut defect = |validity: &ResolutionValidity, reason: &str, basis: &str| {
+            defects.push(ResolutionDefect {
+                validity: validity.clone(),
+                reason: reason.to_string(),
+                legal_basis: basis.to_string(),
+            })
+        };
+
+        let total = electorate.total();
+        let (mut votes_for, mut attending) = (Decimal::ZERO, Decimal::ZERO);
+        for (voter, choice) in &self.votes {
+            match electorate.weight(*voter) {
+                Some(weight) => {
+                    attending += weight;
+                    if *choice == VoteChoice::For {
+                        votes_for += weight;
+                    }
+                }
+                None => defect(&revocable, "无表决权的人参与表决", "《民法典》第八十五条"),
+            }
+        }
+
+        // 不成立（《公司法》第二十七条）
+        if self.meeting.meeting_type == MeetingType::WrittenConsent {
+            if votes_for != total {
+                defect(
+                    &ResolutionValidity::NotFormed,
+                    "未召开会议，且全体表决权人未以书面形式一致同意",
+                    "《公司法》第二十七条第一项",
+                );
+            }
+        } else if self.votes.is_empty() {
+            defect(
+                &ResolutionValidity::NotFormed,
+                "会议未对决议事项进行表决",
+                "《公司法》第二十七条第二项",
+            );
+        } else {
+            if rules.quorum.is_some_and(|q| attending < total * q) {
+                defect(
+                    &ResolutionValidity::NotFormed,
+                    "出席会议的人数或者所持表决权数未达到规定",
+                    "《公司法》第二十七条第三项",
+                );
+            }
+            if !rules.threshold.is_met(votes_for, attending, total) {
+                let basis = if company {
+                    "《公司法》第二十七条第四项"
+                } else {
+                    "《合伙企业法》第三十条、第三十一条"
+                };
+                defect(
+                    &ResolutionValidity::NotFormed,
+                    "同意决议事项的人数或者所持表决权数未达到规定",
+                    basis,
+                );
+            }
+        }
+
+        // 无效与可撤销（《公司法》第二十五条、第二十六条）
+        for content in &self.content_defects {
+            match content {
+                ContentDefect::ViolatesLaw(reason) => {
+                    defect(&ResolutionValidity::Void, reason, "《公司法》第二十五条")
+                }
+                ContentDefect::ViolatesArticles(reason) => {
+                    defect(&revocable, reason, "《民法典》第八十五条")
+                }
+            }
+        }
+        if self.meeting.meeting_type != MeetingType::WrittenConsent && rules.notice_days > 0 {
+            let short_notice = self.meeting.noticed_at.is_none_or(|noticed| {
+                noticed + Duration::days(rules.notice_days) > self.meeting.held_at
+            });
+            // 全体表决权人出席的，通知瑕疵仅为轻微瑕疵，对决议未产生实质影响
+            if short_notice && attending < total {
+                defect(
+                    &revocable,
+                    "会议召集程序违反规定的通知期限",
+                    "《民法典》第八十五条",
+                );
+            }
+        }
+
+        let validity = defects
+            .iter()
+            .map(|d| d.validity.clone())
+            .max_by_key(|v| v.severity())
+            .unwrap_or(ResolutionValidity::Valid);
+        Ok(ResolutionReport {
+            validity,
+            defects,
+            votes_for,
+            attending,
+            total,
+        })
+    }
+
+    /// 获取决议ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// 获取作出决议的会议
+    pub fn meeting(&self) -> &Meeting {
+        &self.meeting
+    }
+
+    /// 获取决议事项
+    pub fn subject(&self) -> &ResolutionSubject {
+        &self.subject
+    }
+
+    /// 获取表决情况
+    pub fn votes(&self) -> &[(Uuid, VoteChoice)] {
+        &self.votes
+    }
+
+    /// 获取决议内容的瑕疵
+    pub fn content_defects(&self) -> &[ContentDefect] {
+        &self.content_defects
+    }
+}
+
+fn not_applicable(operation: &str) -> FanError {
+    FanError::validation(
+        "该组织没有此类决议机构",
+        ValidationErrorType::EntityError,
+        operation,
+        "Electorate",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::entity::unincorporated::{LiabilityType, PartnerType, PartnershipType};
+    use crate::core::money::{Currency, Money};
+    use chrono::TimeZone;
+
+    fn held() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 9, 1, 0, 0, 0).unwrap()
+    }
+
+    /// 有限责任公司的股东 A、B、C 分别持有 60%、30%、10% 的表决权
+    fn shareholders() -> (Electorate, Uuid, Uuid, Uuid) {
+        let company = LegalPersonType::Company(CompanyType::Limited);
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut table = CapTable::new(Currency::CNY, true);
+        for (holder, amount) in [(a, 60), (b, 30), (c, 10)] {
+            table
+                .subscribe(holder, Money::cny(Decimal::from(amount)), held())
+                .unwrap();
+        }
+        let electorate = Electorate::shareholders(&company, &table).unwrap();
+        (electorate, a, b, c)
+    }
+
+    /// 提前 `noticed_days` 日通知的临时股东会
+    fn meeting(noticed_days: i64) -> Meeting {
+        Meeting::new(
+            GoverningBody::ShareholdersMeeting(CompanyType::Limited),
+            MeetingType::Extraordinary,
+            Some(held() - Duration::days(noticed_days)),
+            held(),
+        )
+    }
+
+    #[test]
+    fn test_ordinary_resolution_passes_by_majority() {
+        let (electorate, a, b, _) = shareholders();
+        let mut ordinary =
+            Resolution::new(meeting(15), ResolutionSubject::Ordinary("年度预算".into()));
+        ordinary.cast(a, VoteChoice::For).unwrap();
+        ordinary.cast(b, VoteChoice::Against).unwrap();
+        assert!(ordinary.cast(a, VoteChoice::Against).is_err());
+
+        let report = ordinary.evaluate(&electorate).unwrap();
+        assert_eq!(report.validity, ResolutionValidity::Valid);
+        assert_eq!(report.votes_for / report.total, Decimal::new(6, 1));
+        assert!(report.defects.is_empty());
+    }
+
+    #[test]
+    fn test_resolution_not_formed() {
+        let (electorate, a, b, _) = shareholders();
+
+        // 60% 表决权不能通过修改章程
+        let mut amend = Resolution::new(meeting(15), ResolutionSubject::AmendArticles);
+        amend.cast(a, VoteChoice::For).unwrap();
+        amend.cast(b, VoteChoice::Against).unwrap();
+        let report = amend.evaluate(&electorate).unwrap();
+        assert_eq!(report.validity, ResolutionValidity::NotFormed);
+        assert_eq!(report.defects[0].legal_basis, "《公司法》第二十七条第四项");
+
+        // 会议未对决议事项进行表决
+        let silent = Resolution::new(meeting(15), ResolutionSubject::ChangeCapital);
+        assert_eq!(
+            silent.evaluate(&electorate).unwrap().validity,
+            ResolutionValidity::NotFormed
+        );
+    }
+
+    #[test]
+    fn test_written_consent_requires_every_shareholder() {
+        let (electorate, a, b, c) = shareholders();
+        let written = || {
+            Meeting::new(
+                GoverningBody::ShareholdersMeeting(CompanyType::Limited),
+                MeetingType::WrittenConsent,
+                None,
+                held(),
+            )
+        };
+        let mut partial = Resolution::new(written(), ResolutionSubject::Ordinary("分红".into()));
+        partial.cast(a, VoteChoice::For).unwrap();
+        partial.cast(b, VoteChoice::For).unwrap();
+        assert_eq!(
+            partial.evaluate(&electorate).unwrap().validity,
+            ResolutionValidity::NotFormed
+        );
+
+        let mut unanimous = Resolution::new(written(), ResolutionSubject::Ordinary("分红".into()));
+        for holder in [a, b, c] {
+            unanimous.cast(holder, VoteChoice::For).unwrap();
+        }
+        assert_eq!(
+            unanimous.evaluate(&electorate).unwrap().validity,
+            ResolutionValidity::Valid
+        );
+    }
+
+    #[test]
+    fn test_procedural_defect_is_revocable() {
+        let (electorate, a, b, c) = shareholders();
+
+        // 通知期限不足且有股东未出席的，决议可撤销
+        let mut rushed = Resolution::new(meeting(5), ResolutionSubject::Ordinary("分红".into()));
+        rushed.cast(a, VoteChoice::For).unwrap();
+        assert_eq!(
+            rushed.evaluate(&electorate).unwrap().validity,
+            ResolutionValidity::Revocable {
+                deadline: Some(held() + Duration::days(REVOCATION_PERIOD_DAYS))
+            }
+        );
+
+        // 全体股东出席的，通知瑕疵不影响决议
+        let mut attended = Resolution::new(meeting(5), ResolutionSubject::Ordinary("分红".into()));
+        attended.cast(a, VoteChoice::For).unwrap();
+        attended.cast(b, VoteChoice::Against).unwrap();
+        attended.cast(c, VoteChoice::Abstain).unwrap();
+        assert_eq!(
+            attended.evaluate(&electorate).unwrap().validity,
+            ResolutionValidity::Valid
+        );
+
+        // 内容违反章程的，决议可撤销
+        let mut beyond = Resolution::new(meeting(15), ResolutionSubject::Ordinary("分红".into()));
+        beyond.cast(a, VoteChoice::For).unwrap();
+        beyond.add_content_defect(ContentDefect::ViolatesArticles("超出章程分配比例".into()));
+        assert!(matches!(
+            beyond.evaluate(&electorate).unwrap().validity,
+            ResolutionValidity::Revocable { .. }
+        ));
+    }
+
+    #[test]
+    fn test_illegal_content_is_void() {
+        let (electorate, a, _, _) = shareholders();
+        let mut rushed = Resolution::new(meeting(5), ResolutionSubject::Ordinary("分红".into()));
+        rushed.cast(a, VoteChoice::For).unwrap();
+        rushed.add_content_defect(ContentDefect::ViolatesLaw("抽逃出资".into()));
+
+        // 同时存在程序瑕疵的，以无效论
+        let report = rushed.evaluate(&electorate).unwrap();
+        assert_eq!(report.validity, ResolutionValidity::Void);
+        assert_eq!(report.defects.len(), 2);
+    }
+
+    #[test]
+    fn test_partnership_guarantee_requires_unanimity() {
+        let partners: Vec<Partner> = (0..3)
+            .map(|_| {
+                Partner::new(
+                    Uuid::new_v4(),
+                    PartnerType::GeneralPartner,
+                    Money::cny(Decimal::from(100)),
+                    1.0 / 3.0,
+                    LiabilityType::Unlimited,
+                )
+            })
+            .collect();
+        let org_type = UnincorporatedOrgType::Partnership(PartnershipType::General);
+        let electorate = Electorate::partners(&org_type, &partners).unwrap();
+        let mut guarantee = Resolution::new(
+            Meeting::new(
+                GoverningBody::PartnersMeeting,
+                MeetingType::Extraordinary,
+                None,
+                held(),
+            ),
+            ResolutionSubject::ProvideGuarantee,
+        );
+        guarantee.cast(partners[0].id(), VoteChoice::For).unwrap();
+        guarantee.cast(partners[1].id(), VoteChoice::For).unwrap();
+        guarantee
+            .cast(partners[2].id(), VoteChoice::Abstain)
+            .unwrap();
+        assert_eq!(
+            guarantee.evaluate(&electorate).unwrap().validity,
+            ResolutionValidity::NotFormed
+        );
+        assert!(Electorate::partners(&UnincorporatedOrgType::Branch, &partners).is_err());
+
+        // 股东会的表决权人名单不能用于合伙人会议
+        let (shareholders, _, _, _) = shareholders();
+        assert!(guarantee.evaluate(&shareholders).is_err());
+    }
+}
@@ -0,0 +1,334 @@
+//! 执行工作任务的人员
+//! 对应《民法典》第一百七十条：执行法人或者非法人组织工作任务的人员，就其职权范围内的事项，以法人或者非法人组织的名义
+//! 实施的民事法律行为，对法人或者非法人组织发生效力。人员及其职权范围由组织记载于人员名册，签署人不能自行声明。
+//! 章程、决议或者内部规定对法定代表人、负责人或者工作人员权限的限制同样由组织记载，并记录已被告知限制的相对人，
+//! 未被告知的相对人为善意相对人（第六十一条第三款、第一百七十条第二款、第五百零四条）。
+
+use crate::core::entity::base::{Entity, EntityType};
+use crate::core::matter::SubjectMatterType;
+use crate::core::money::Money;
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// 人员名册中的一项任职记录
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaffMember {
+    person: Uuid,                        // 执行工作任务的人员
+    duties: Vec<SubjectMatterType>,      // 职权范围内的事项
+    appointed_at: DateTime<Utc>,         // 任职之日
+    dismissed_at: Option<DateTime<Utc>>, // 离职之日
+}
+
+impl StaffMember {
+    /// 获取执行工作任务的人员
+    pub fn person(&self) -> Uuid {
+        self.person
+    }
+
+    /// 获取职权范围内的事项
+    pub fn duties(&self) -> &[SubjectMatterType] {
+        &self.duties
+    }
+
+    /// 获取任职之日
+    pub fn appointed_at(&self) -> DateTime<Utc> {
+        self.appointed_at
+    }
+
+    /// 获取离职之日
+    pub fn dismissed_at(&self) -> Option<DateTime<Utc>> {
+        self.dismissed_at
+    }
+
+    /// 在指定时刻是否在职
+    pub fn is_in_office_at(&self, at: DateTime<Utc>) -> bool {
+        self.appointed_at <= at && self.dismissed_at.is_none_or(|dismissed| at < dismissed)
+    }
+}
+
+/// 章程、决议或者内部规定对某一人员权限的限制
+#[derive(Debug, Clone, PartialEq)]
+pub struct InternalLimit {
+    person: Uuid,                            // 受限制的法定代表人、负责人或者工作人员
+    matters: Option<Vec<SubjectMatterType>>, // 可以处理的事项，空为不限事项
+    price_limit: Option<Money>,              // 单项交易的价款上限
+    imposed_at: DateTime<Utc>,               // 作出限制之日
+    notices: Vec<(Uuid, DateTime<Utc>)>,     // 已被告知该限制的相对人及告知之日
+}
+
+impl InternalLimit {
+    /// 获取受限制的人员
+    pub fn person(&self) -> Uuid {
+        self.person
+    }
+
+    /// 获取可以处理的事项
+    pub fn matters(&self) -> Option<&[SubjectMatterType]> {
+        self.matters.as_deref()
+    }
+
+    /// 获取单项交易的价款上限
+    pub fn price_limit(&self) -> Option<Money> {
+        self.price_limit
+    }
+
+    /// 获取作出限制之日
+    pub fn imposed_at(&self) -> DateTime<Utc> {
+        self.imposed_at
+    }
+
+    /// 某一相对人在指定时刻是否已被告知该限制
+    pub fn is_known_to(&self, counterparty: Uuid, at: DateTime<Utc>) -> bool {
+        self.notices
+            .iter()
+            .any(|(notified, notified_at)| *notified == counterparty && *notified_at <= at)
+    }
+}
+
+/// 法人、非法人组织的人员名册
+///
+/// 组织依名册回答某人在某一时刻是否为其执行工作任务的人员及其职权范围、受到何种内部限制，签署人的权限以此为准。
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Personnel {
+    staff: Vec<StaffMember>,    // 执行工作任务的人员
+    limits: Vec<InternalLimit>, // 对人员权限的内部限制，后作出的取代先作出的
+}
+
+impl Personnel {
+    /// 获取任职记录
+    pub fn staff(&self) -> &[StaffMember] {
+        &self.staff
+    }
+
+    /// 某一人员在指定时刻的职权范围，不在职的为空
+    pub fn duties_at(&self, person: Uuid, at: DateTime<Utc>) -> Option<Vec<SubjectMatterType>> {
+        self.staff
+            .iter()
+            .find(|m| m.person == person && m.is_in_office_at(at))
+            .map(|m| m.duties.clone())
+    }
+
+    /// 获取内部限制
+    pub fn limits(&self) -> &[InternalLimit] {
+        &self.limits
+    }
+
+    /// 某一人员在指定时刻受到的内部限制，没有限制的为空
+    pub fn limit_at(&self, person: Uuid, at: DateTime<Utc>) -> Option<&InternalLimit> {
+        self.limits
+            .iter()
+            .rev()
+            .find(|l| l.person == person && l.imposed_at <= at)
+    }
+
+    /// 组织任命执行工作任务的人员，人员须为在职人员以外具有完全民事行为能力的自然人，职权范围不得为空
+    pub(crate) fn appoint(
+        &mut self,
+        person: &dyn Entity,
+        duties: Vec<SubjectMatterType>,
+        at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        if person.entity_type() != EntityType::NaturalPerson
+            || !person.has_capacity_at(at)
+            || duties.is_empty()
+            || self.duties_at(person.id(), at).is_some()
+        {
+            return Err(FanError::validation_with_ref(
+                "执行工作任务的人员须为具有完全民事行为能力的自然人，职权范围不得为空，且不得重复任命",
+                ValidationErrorType::EntityRelationMalformed,
+                "《民法典》第一百七十条",
+                "appoint_staff",
+                "StaffMember",
+            ));
+        }
+        self.staff.push(StaffMember {
+            person: person.id(),
+            duties,
+            appointed_at: at,
+            dismissed_at: None,
+        });
+        Ok(())
+    }
+
+    /// 人员离职，此后不再以组织的名义执行工作任务
+    pub(crate) fn dismiss(&mut self, person: Uuid, at: DateTime<Utc>) -> FanResult<()> {
+        let member = self
+            .staff
+            .iter_mut()
+            .find(|m| m.person == person && m.is_in_office_at(at))
+            .ok_or_else(|| {
+                FanError::validation(
+                    "该人员并非在职人员",
+                    ValidationErrorType::OperationSequenceWrong,
+                    "dismiss_staff",
+                    "StaffMember",
+                )
+            })?;
+        member.dismissed_at = Some(at);
+        Ok(())
+    }
+
+    /// 限制法定代表人、负责人或者在职工作人员的权限，取代此前对该人员的限制
+    ///
+    /// `representatives` 为组织当时的法定代表人或者负责人；限制须至少涉及事项或者价款之一。
+    pub(crate) fn limit(
+        &mut self,
+        representatives: &[Uuid],
+        person: Uuid,
+        matters: Option<Vec<SubjectMatterType>>,
+        price_limit: Option<Money>,
+        at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        if !representatives.contains(&person) && self.duties_at(person, at).is_none() {
+            return Err(FanError::validation_with_ref(
+                "只能限制法定代表人、负责人或者在职工作人员的权限",
+                ValidationErrorType::EntityRelationMalformed,
+                "《民法典》第六十一条、第一百七十条",
+                "limit_authority",
+                "InternalLimit",
+            ));
+        }
+        if matters.is_none() && price_limit.is_none() {
+            return Err(FanError::validation(
+                "内部限制须涉及事项或者价款",
+                ValidationErrorType::EntityRelationMalformed,
+                "limit_authority",
+                "InternalLimit",
+            ));
+        }
+        self.limits.push(InternalLimit {
+            person,
+            matters,
+            price_limit,
+            imposed_at: at,
+            notices: Vec::new(),
+        });
+        Ok(())
+    }
+
+    /// 将对某一人员的现行限制告知相对人，此后该相对人不能再以不知限制为由主张行为有效
+    pub(crate) fn notify_limit(
+        &mut self,
+        person: Uuid,
+        counterparty: Uuid,
+        at: DateTime<Utc>,
+    ) -> FanResult<()> {
+        let limit = self
+            .limits
+            .iter_mut()
+            .rev()
+            .find(|l| l.person == person && l.imposed_at <= at)
+            .ok_or_else(|| {
+                FanError::validation(
+                    "该人员的权限未受内部限制",
+                    ValidationErrorType::OperationSequenceWrong,
+                    "notify_internal_limit",
+                    "InternalLimit",
+                )
+            })?;
+        if !limit.is_known_to(counterparty, at) {
+            limit.notices.push((counterparty, at));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{now, person};
+    use chrono::Duration;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_appointment_requires_capable_person_and_duties() {
+        let mut register = Personnel::default();
+        let sales = vec![SubjectMatterType::SpecificGoods];
+        assert!(register
+            .appoint(&person(2015), sales.clone(), now())
+            .is_err());
+        let clerk = person(1995);
+        assert!(register.appoint(&clerk, vec![], now()).is_err());
+        register.appoint(&clerk, sales.clone(), now()).unwrap();
+        assert!(register.appoint(&clerk, sales, now()).is_err());
+    }
+
+    #[test]
+    fn test_duties_follow_term_of_office() {
+        let mut register = Personnel::default();
+        let clerk = person(1995);
+        let sales = vec![SubjectMatterType::SpecificGoods];
+        register.appoint(&clerk, sales.clone(), now()).unwrap();
+        assert_eq!(register.duties_at(clerk.id(), now()), Some(sales));
+        assert_eq!(
+            register.duties_at(clerk.id(), now() - Duration::days(1)),
+            None
+        );
+
+        let later = now() + Duration::days(30);
+        register.dismiss(clerk.id(), later).unwrap();
+        assert!(register.duties_at(clerk.id(), later).is_none());
+        assert!(register
+            .duties_at(clerk.id(), later - Duration::days(1))
+            .is_some());
+        assert!(register.dismiss(clerk.id(), later).is_err());
+
+        // 离职后可以重新任命
+        register
+            .appoint(&clerk, vec![SubjectMatterType::Service], later)
+            .unwrap();
+        assert_eq!(
+            register.duties_at(clerk.id(), later),
+            Some(vec![SubjectMatterType::Service])
+        );
+    }
+
+    #[test]
+    fn test_internal_limits_and_notice() {
+        let mut register = Personnel::default();
+        let (director, clerk) = (person(1980), person(1995));
+        let outsider = Uuid::new_v4();
+        let cap = |yuan: i64| Some(Money::cny(Decimal::from(yuan)));
+        register
+            .appoint(&clerk, vec![SubjectMatterType::SpecificGoods], now())
+            .unwrap();
+
+        // 只能限制法定代表人或者在职工作人员，限制不得为空
+        let reps = [director.id()];
+        assert!(register
+            .limit(&reps, outsider, None, cap(1_000), now())
+            .is_err());
+        assert!(register
+            .limit(&reps, director.id(), None, None, now())
+            .is_err());
+        register
+            .limit(&reps, director.id(), None, cap(100_000), now())
+            .unwrap();
+        register
+            .limit(&reps, clerk.id(), None, cap(5_000), now())
+            .unwrap();
+        assert!(register
+            .limit_at(director.id(), now() - Duration::days(1))
+            .is_none());
+
+        // 告知在告知之日以后对该相对人生效，新的限制取代旧的限制，此前的告知不及于新的限制
+        let buyer = Uuid::new_v4();
+        let later = now() + Duration::days(10);
+        assert!(register.notify_limit(outsider, buyer, later).is_err());
+        register.notify_limit(director.id(), buyer, later).unwrap();
+        let limit = register.limit_at(director.id(), later).unwrap();
+        assert!(limit.is_known_to(buyer, later));
+        assert!(!limit.is_known_to(buyer, now()));
+        assert!(!limit.is_known_to(outsider, later));
+
+        let tighter = later + Duration::days(1);
+        register
+            .limit(&reps, director.id(), None, cap(50_000), tighter)
+            .unwrap();
+        let limit = register.limit_at(director.id(), tighter).unwrap();
+        assert_eq!(limit.price_limit(), cap(50_000));
+        assert!(!limit.is_known_to(buyer, tighter));
+    }
+}
@@ -0,0 +1,349 @@
+//! 法人的合并与分立
+//! 对应《民法典》第六十七条：法人合并的，其权利和义务由合并后的法人享有和承担。
+//! 法人分立的，其权利和义务由分立后的法人享有连带债权，承担连带债务，但是债权人和债务人另有约定的除外。
+//! 设立人为设立法人从事的民事活动，其法律后果由成立后的法人承受；法人未成立的，由设立人承受，
+//! 设立人为二人以上的，享有连带债权，承担连带债务（第七十五条第一款）。
+
+use crate::core::entity::base::Entity;
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// 合并或分立的方式
+#[derive(Debug, Clone, PartialEq)]
+pub enum SuccessionKind {
+    /// 吸收合并：一个法人吸收其他法人，被吸收的法人解散
+    MergerByAbsorption,
+    /// 新设合并：两个以上法人合并设立一个新的法人，合并各方解散
+    MergerByConsolidation,
+    /// 派生分立：原法人存续，分出部分设立新的法人
+    DivisionBySpinOff,
+    /// 新设分立：原法人解散，分立为两个以上新的法人
+    DivisionByDissolution,
+    /// 设立中的法人依法成立，由成立后的法人承受其权利义务
+    Establishment,
+    /// 法人未能成立，由设立人承受其权利义务
+    FailedEstablishment,
+}
+
+impl SuccessionKind {
+    /// 是否为分立，分立后的法人承担连带债务
+    pub fn is_division(&self) -> bool {
+        matches!(
+            self,
+            SuccessionKind::DivisionBySpinOff | SuccessionKind::DivisionByDissolution
+        )
+    }
+
+    /// 承继者是否承担连带债务：分立后的法人，或者法人未成立时的设立人
+    pub fn is_joint(&self) -> bool {
+        self.is_division() || *self == SuccessionKind::FailedEstablishment
+    }
+}
+
+/// 一次合并或分立的记录
+#[derive(Debug, Clone)]
+pub struct Succession {
+    id: Uuid,                    // 记录ID
+    kind: SuccessionKind,        // 合并或分立的方式
+    predecessors: Vec<Uuid>,     // 合并或分立前的法人
+    successors: Vec<Uuid>,       // 合并或分立后的法人，派生分立中包括存续的原法人
+    effective_at: DateTime<Utc>, // 合并或分立生效之日
+}
+
+impl Succession {
+    /// 获取记录ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// 获取合并或分立的方式
+    pub fn kind(&self) -> &SuccessionKind {
+        &self.kind
+    }
+
+    /// 获取合并或分立前的法人
+    pub fn predecessors(&self) -> &[Uuid] {
+        &self.predecessors
+    }
+
+    /// 获取合并或分立后的法人
+    pub fn successors(&self) -> &[Uuid] {
+        &self.successors
+    }
+
+    /// 获取生效日期
+    pub fn effective_at(&self) -> DateTime<Utc> {
+        self.effective_at
+    }
+
+    /// 该法人是否因此次合并或分立而终止
+    pub fn retires(&self, id: Uuid) -> bool {
+        self.predecessors.contains(&id) && !self.successors.contains(&id)
+    }
+}
+
+/// 以主体为当事人的法律关系，如合同：当事人因合并、分立或者法人成立而终止的，由其继受主体代替
+pub trait SuccessionAware {
+    /// 依登记簿，以现在的继受主体代替已终止的当事人
+    ///
+    /// `entities` 须包括登记簿记载的各继受主体；当事人均未终止的不发生变化。
+    fn rebind_successors(
+        &mut self,
+        registry: &SuccessionRegistry,
+        entities: &[Arc<dyn Entity>],
+    ) -> FanResult<()>;
+}
+
+/// 合并、分立与设立的登记簿，记录权利义务的承继链条
+#[derive(Debug, Clone, Default)]
+pub struct SuccessionRegistry {
+    records: Vec<Succession>,
+}
+
+impl SuccessionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 记录一次合并或分立
+    pub(crate) fn record(
+        &mut self,
+        kind: SuccessionKind,
+        predecessors: Vec<Uuid>,
+        successors: Vec<Uuid>,
+        effective_at: DateTime<Utc>,
+    ) -> FanResult<&Succession> {
+        // 新设合并、新设分立和法人成立中，承继者均为新的主体，不能同时是被承继者
+        let fresh = successors.iter().all(|s| !predecessors.contains(s));
+        let shape_ok = match kind {
+            SuccessionKind::MergerByAbsorption => {
+                predecessors.len() >= 2
+                    && successors.len() == 1
+                    && predecessors.contains(&successors[0])
+            }
+            SuccessionKind::MergerByConsolidation => {
+                predecessors.len() >= 2 && successors.len() == 1 && fresh
+            }
+            SuccessionKind::DivisionBySpinOff => {
+                predecessors.len() == 1
+                    && successors.len() >= 2
+                    && successors.contains(&predecessors[0])
+            }
+            SuccessionKind::DivisionByDissolution => {
+                predecessors.len() == 1 && successors.len() >= 2 && fresh
+            }
+            SuccessionKind::Establishment => {
+                predecessors.len() == 1 && successors.len() == 1 && fresh
+            }
+            SuccessionKind::FailedEstablishment => {
+                predecessors.len() == 1
+                    && !successors.is_empty()
+                    && !successors.contains(&predecessors[0])
+            }
+        };
+        let distinct = |ids: &[Uuid]| ids.iter().enumerate().all(|(i, id)| !ids[..i].contains(id));
+        if !shape_ok || !distinct(&predecessors) || !distinct(&successors) {
+            return Err(FanError::validation(
+                "合并或分立前后的法人不符合该方式的要求",
+                ValidationErrorType::EntityRelationMalformed,
+                "record_succession",
+                "SuccessionRegistry",
+            ));
+        }
+
+        let retired = predecessors
+            .iter()
+            .chain(successors.iter())
+            .any(|id| self.records.iter().any(|r| r.retires(*id)));
+        if retired {
+            return Err(FanError::validation_with_ref(
+                "已因合并或分立终止的法人不能再参与合并或分立",
+                ValidationErrorType::EntityStatusIllegal,
+                "《民法典》第六十七条",
+                "record_succession",
+                "SuccessionRegistry",
+            ));
+        }
+
+        // 新设的法人不能是已经出现在登记簿中的法人，以免形成循环的承继链条；设立人可以多次设立法人
+        let reused = kind != SuccessionKind::FailedEstablishment
+            && successors
+                .iter()
+                .filter(|id| !predecessors.contains(id))
+                .any(|id| {
+                    self.records
+                        .iter()
+                        .any(|r| r.predecessors.contains(id) || r.successors.contains(id))
+                });
+        if reused {
+            return Err(FanError::validation(
+                "合并或分立后新设的法人必须是新的主体",
+                ValidationErrorType::EntityRelationMalformed,
+                "record_succession",
+                "SuccessionRegistry",
+            ));
+        }
+
+        self.records.push(Succession {
+            id: Uuid::new_v4(),
+            kind,
+            predecessors,
+            successors,
+            effective_at,
+        });
+        Ok(self.records.last().expect("刚刚加入的记录"))
+    }
+
+    /// 获取全部记录
+    pub fn records(&self) -> &[Succession] {
+        &self.records
+    }
+
+    /// 某一法人参与的合并或分立，按时间先后排列
+    pub fn chain(&self, id: Uuid) -> Vec<&Succession> {
+        let mut chain = Vec::new();
+        let mut frontier = vec![id];
+        while let Some(current) = frontier.pop() {
+            for record in self
+                .records
+                .iter()
+                .filter(|r| r.predecessors.contains(&current))
+            {
+                if !chain.iter().any(|r: &&Succession| r.id == record.id) {
+                    chain.push(record);
+                    frontier.extend(record.successors.iter().filter(|s| **s != current));
+                }
+            }
+        }
+        chain.sort_by_key(|r| r.effective_at);
+        chain
+    }
+
+    /// 现在承受该法人地位的法人：未终止的为其本身，因合并或分立终止的为其继受法人
+    pub fn current_successors(&self, id: Uuid) -> Vec<Uuid> {
+        match self.records.iter().find(|r| r.retires(id)) {
+            None => vec![id],
+            Some(record) => {
+                let mut current = Vec::new();
+                for successor in &record.successors {
+                    for resolved in self.current_successors(*successor) {
+                        if !current.contains(&resolved) {
+                            current.push(resolved);
+                        }
+                    }
+                }
+                current
+            }
+        }
+    }
+
+    /// 对该法人的债务承担责任的法人，包括派生分立中分出的新法人
+    pub fn liable_for(&self, id: Uuid) -> Vec<Uuid> {
+        let mut liable = Vec::new();
+        let push = |resolved: Uuid, liable: &mut Vec<Uuid>| {
+            if !liable.contains(&resolved) {
+                liable.push(resolved);
+            }
+        };
+        if !self.records.iter().any(|r| r.retires(id)) {
+            push(id, &mut liable);
+        }
+        for record in self.records.iter().filter(|r| r.predecessors.contains(&id)) {
+            for successor in record.successors.iter().filter(|s| **s != id) {
+                for resolved in self.liable_for(*successor) {
+                    push(resolved, &mut liable);
+                }
+            }
+        }
+        liable
+    }
+
+    /// 依登记簿，使一组法律关系中已终止的当事人由其现在的继受主体代替（第六十七条、第七十五条第一款）
+    ///
+    /// 遇有不能代替的即返回错误，此前的法律关系已经完成代替。
+    pub fn rebind(
+        &self,
+        relations: &mut [&mut dyn SuccessionAware],
+        entities: &[Arc<dyn Entity>],
+    ) -> FanResult<()> {
+        relations
+            .iter_mut()
+            .try_for_each(|relation| relation.rebind_successors(self, entities))
+    }
+
+    /// 该法人的债务是否由分立后的法人或者设立人连带承担
+    pub fn is_jointly_liable(&self, id: Uuid) -> bool {
+        self.chain(id).iter().any(|r| r.kind.is_joint())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_succession_chain() {
+        let now = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let (a, b, c, d) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        let mut registry = SuccessionRegistry::new();
+
+        // A 吸收 B，A 再派生分立出 C，B 的债务由 A、C 连带承担
+        registry
+            .record(SuccessionKind::MergerByAbsorption, vec![a, b], vec![a], now)
+            .unwrap();
+        registry
+            .record(SuccessionKind::DivisionBySpinOff, vec![a], vec![a, c], now)
+            .unwrap();
+        assert_eq!(registry.current_successors(b), vec![a]);
+        assert_eq!(registry.liable_for(b), vec![a, c]);
+        assert!(registry.is_jointly_liable(b));
+        assert_eq!(registry.chain(b).len(), 2);
+
+        // 新设合并、新设分立的承继者不能是被承继者本身，分立后的法人不能重复
+        let (e, f) = (Uuid::new_v4(), Uuid::new_v4());
+        assert!(registry
+            .record(
+                SuccessionKind::MergerByConsolidation,
+                vec![c, e],
+                vec![e],
+                now
+            )
+            .is_err());
+        assert!(registry
+            .record(
+                SuccessionKind::DivisionByDissolution,
+                vec![e],
+                vec![e, f],
+                now
+            )
+            .is_err());
+        assert!(registry
+            .record(
+                SuccessionKind::DivisionBySpinOff,
+                vec![e],
+                vec![e, f, f],
+                now
+            )
+            .is_err());
+        assert!(registry.current_successors(e) == vec![e]);
+
+        // 已终止的 B 不能再参与合并
+        assert!(registry
+            .record(
+                SuccessionKind::MergerByConsolidation,
+                vec![b, c],
+                vec![d],
+                now
+            )
+            .is_err());
+    }
+}
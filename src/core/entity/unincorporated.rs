@@ -1,6 +1,17 @@
+use crate::core::clock::{default_clock, Clock};
 use crate::core::entity::base::{
     AuthorityScope, AuthorityStatus, BaseEntity, CapacityStatus, Entity, EntityType,
 };
+use crate::core::entity::industry::IndustryCatalogue;
+use crate::core::entity::liability::{self, Debt, LiabilityAllocation};
+use crate::core::entity::licence::{revoke_licence_in, Licence};
+use crate::core::entity::partnership::{
+    self, check_admission, check_composition, ExpulsionGround, PartnerChange, PartnerChangeKind,
+    PartnerExit,
+};
+use crate::core::entity::staff::{Personnel, StaffMember};
+use crate::core::matter::SubjectMatterType;
+use crate::core::money::Money;
 use crate::FanResult;
 use crate::{FanError, ValidationErrorType};
 
@@ -34,11 +45,54 @@ pub enum PartnershipType {
 pub struct Partner {
     id: Uuid,                      // 合伙人ID
     partnership_type: PartnerType, // 合伙人类型
-    contribution: f64,             // 出资额
+    contribution: Money,           // 出资额
     profit_sharing_ratio: f32,     // 利润分配比例
     liability_type: LiabilityType, // 责任承担方式
 }
 
+impl Partner {
+    pub fn new(
+        id: Uuid,
+        partnership_type: PartnerType,
+        contribution: Money,
+        profit_sharing_ratio: f32,
+        liability_type: LiabilityType,
+    ) -> Self {
+        Self {
+            id,
+            partnership_type,
+            contribution,
+            profit_sharing_ratio,
+            liability_type,
+        }
+    }
+
+    /// 获取合伙人ID
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// 获取合伙人类型
+    pub fn partnership_type(&self) -> &PartnerType {
+        &self.partnership_type
+    }
+
+    /// 获取出资额
+    pub fn contribution(&self) -> Money {
+        self.contribution
+    }
+
+    /// 获取利润分配比例
+    pub fn profit_sharing_ratio(&self) -> f32 {
+        self.profit_sharing_ratio
+    }
+
+    /// 获取责任承担方式
+    pub fn liability_type(&self) -> &LiabilityType {
+        &self.liability_type
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum PartnerType {
     GeneralPartner, // 普通合伙人
@@ -58,9 +112,14 @@ pub struct UnincorporatedOrg {
     org_type: UnincorporatedOrgType,
     executive_partner: Option<Uuid>, // 执行事务合伙人（合伙企业特有）
     proprietor: Option<Uuid>,        // 投资人（个人独资企业特有）
+    parent: Option<Uuid>,            // 所属的法人或者非法人组织（分支机构特有）
+    person_in_charge: Option<Uuid>,  // 负责人（分支机构特有）
     members: Vec<Partner>,           // 成员列表
     registered_address: String,
     establishment_date: DateTime<Utc>,
+    licences: Vec<Licence>,              // 持有的许可证
+    personnel: Personnel,                // 执行工作任务的人员名册
+    partner_changes: Vec<PartnerChange>, // 退伙、除名和身份转变的记录
 }
 
 impl UnincorporatedOrg {
@@ -69,7 +128,21 @@ impl UnincorporatedOrg {
         registered_address: String,
         establishment_date: DateTime<Utc>,
     ) -> Self {
-        let now = Utc::now();
+        Self::with_clock(
+            org_type,
+            registered_address,
+            establishment_date,
+            default_clock(),
+        )
+    }
+
+    /// 使用指定时钟创建非法人组织
+    pub fn with_clock(
+        org_type: UnincorporatedOrgType,
+        registered_address: String,
+        establishment_date: DateTime<Utc>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
         let authority_scope = AuthorityScope {
             status: AuthorityStatus::Full,
             permitted_authorities: HashSet::new(),
@@ -77,37 +150,121 @@ impl UnincorporatedOrg {
         };
 
         Self {
-            base: BaseEntity {
-                id: Uuid::new_v4(),
-                entity_type: EntityType::UnincorporatedOrg,
-                capacity_status: CapacityStatus::UnincorporatedOrg(authority_scope),
-                created_at: now,
-                updated_at: now,
-            },
+            base: BaseEntity::new(
+                EntityType::UnincorporatedOrg,
+                CapacityStatus::UnincorporatedOrg(authority_scope),
+                clock,
+            ),
             org_type,
             executive_partner: None,
             proprietor: None,
+            parent: None,
+            person_in_charge: None,
             members: Vec::new(),
             registered_address,
             establishment_date,
+            licences: Vec::new(),
+            personnel: Personnel::default(),
+            partner_changes: Vec::new(),
         }
     }
 
-    /// 添加合伙人
-    pub fn add_partner(&mut self, partner: Partner) -> FanResult<()> {
-        match self.org_type {
-            UnincorporatedOrgType::Partnership(_) => {
-                self.members.push(partner);
-                self.base.updated_at = Utc::now();
-                Ok(())
-            }
-            _ => Err(FanError::validation(
-                "Only partnership can add partners",
-                ValidationErrorType::EntityCapacityLacking,
-                "add_partner",
-                "UnincorporatedOrg",
-            )),
-        }
+    /// 添加合伙人，`entity` 为入伙的主体
+    pub fn add_partner(&mut self, partner: Partner, entity: &dyn Entity) -> FanResult<()> {
+        check_admission(&self.org_type, &self.members, &partner, entity)?;
+        check_contribution(&self.members, &partner)?;
+        self.members.push(partner);
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 校验合伙企业的人数、普通合伙人和利润分配比例是否符合法律规定
+    pub fn validate_composition(&self) -> FanResult<()> {
+        check_composition(&self.org_type, &self.members)
+    }
+
+    /// 合伙人退伙，返回合伙企业因此须采取的措施
+    pub fn withdraw_partner(&mut self, partner_id: Uuid) -> FanResult<PartnerExit> {
+        let at = self.base.now();
+        let exit = partnership::remove_partner(
+            &self.org_type,
+            &mut self.members,
+            &mut self.partner_changes,
+            &mut self.executive_partner,
+            partner_id,
+            PartnerChangeKind::Withdrawal,
+            at,
+        )?;
+        self.base.touch();
+        Ok(exit)
+    }
+
+    /// 经其他合伙人一致同意，将合伙人除名，`consents` 为同意除名的合伙人
+    pub fn expel_partner(
+        &mut self,
+        partner_id: Uuid,
+        ground: ExpulsionGround,
+        consents: &[Uuid],
+    ) -> FanResult<PartnerExit> {
+        partnership::check_expulsion_consents(&self.members, partner_id, consents)?;
+        let at = self.base.now();
+        let exit = partnership::remove_partner(
+            &self.org_type,
+            &mut self.members,
+            &mut self.partner_changes,
+            &mut self.executive_partner,
+            partner_id,
+            PartnerChangeKind::Expulsion(ground),
+            at,
+        )?;
+        self.base.touch();
+        Ok(exit)
+    }
+
+    /// 普通合伙人转为有限合伙人，或者有限合伙人转为普通合伙人，`entity` 为该合伙人
+    pub fn convert_partner(&mut self, entity: &dyn Entity, to: PartnerType) -> FanResult<()> {
+        let at = self.base.now();
+        partnership::convert_partner(
+            &self.org_type,
+            &mut self.members,
+            &mut self.partner_changes,
+            entity,
+            to,
+            at,
+        )?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 获取合伙人变动记录
+    pub fn partner_changes(&self) -> &[PartnerChange] {
+        &self.partner_changes
+    }
+
+    /// 合伙人对在指定时刻发生的合伙企业债务承担责任的方式，不承担责任的为空
+    pub fn liability_for(
+        &self,
+        partner_id: Uuid,
+        debt_incurred_at: DateTime<Utc>,
+    ) -> Option<LiabilityType> {
+        partnership::liability_for_debt(
+            &self.members,
+            &self.partner_changes,
+            partner_id,
+            debt_incurred_at,
+        )
+    }
+
+    /// 以组织的财产 `assets` 清偿一笔债务，计算不足部分由各成员承担的责任
+    pub fn allocate_liability(&self, assets: Money, debt: &Debt) -> FanResult<LiabilityAllocation> {
+        liability::allocate(
+            &self.org_type,
+            self.liability_bearer(),
+            &self.members,
+            &self.partner_changes,
+            assets,
+            debt,
+        )
     }
 
     /// 设置执行事务合伙人
@@ -116,7 +273,7 @@ impl UnincorporatedOrg {
             UnincorporatedOrgType::Partnership(_) => {
                 if self.members.iter().any(|p| p.id == partner_id) {
                     self.executive_partner = Some(partner_id);
-                    self.base.updated_at = Utc::now();
+                    self.base.touch();
                     Ok(())
                 } else {
                     Err(FanError::validation(
@@ -136,11 +293,12 @@ impl UnincorporatedOrg {
         }
     }
 
-    /// 添加职权范围
+    /// 添加职权范围，可以是经营范围的表述或行业代码，按标准行业分类目录对应到其所列的全部行业代码
     pub fn add_authority(&mut self, authority: String) -> FanResult<()> {
+        let codes = IndustryCatalogue::standard().resolve_or_err(&authority, "add_authority")?;
         if let CapacityStatus::UnincorporatedOrg(scope) = &mut self.base.capacity_status {
-            scope.permitted_authorities.insert(authority);
-            self.base.updated_at = Utc::now();
+            scope.permitted_authorities.extend(codes);
+            self.base.touch();
             Ok(())
         } else {
             Err(FanError::validation(
@@ -152,11 +310,80 @@ impl UnincorporatedOrg {
         }
     }
 
+    /// 取得许可证
+    pub fn add_licence(&mut self, licence: Licence) -> FanResult<()> {
+        self.licences.push(licence);
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 许可证被吊销或撤销，此后不能再依该许可证从事许可经营的项目
+    pub fn revoke_licence(&mut self, number: &str, at: DateTime<Utc>) -> FanResult<()> {
+        revoke_licence_in(&mut self.licences, number, at)?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 获取持有的许可证
+    pub fn licences(&self) -> &[Licence] {
+        &self.licences
+    }
+
+    /// 任命执行工作任务的人员，并记载其职权范围内的事项
+    pub fn appoint_staff(
+        &mut self,
+        person: &dyn Entity,
+        duties: Vec<SubjectMatterType>,
+    ) -> FanResult<()> {
+        self.personnel.appoint(person, duties, self.base.now())?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 人员离职
+    pub fn dismiss_staff(&mut self, person: Uuid) -> FanResult<()> {
+        self.personnel.dismiss(person, self.base.now())?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 以章程、决议或者内部规定限制法定代表人、负责人或者工作人员的权限，取代此前对该人员的限制
+    pub fn limit_authority(
+        &mut self,
+        person: Uuid,
+        matters: Option<Vec<SubjectMatterType>>,
+        price_limit: Option<Money>,
+    ) -> FanResult<()> {
+        let representatives = self.representatives();
+        self.personnel.limit(
+            &representatives,
+            person,
+            matters,
+            price_limit,
+            self.base.now(),
+        )?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 将对某一人员权限的限制告知相对人，此后该相对人知道该限制（第五百零四条）
+    pub fn notify_internal_limit(&mut self, person: Uuid, counterparty: Uuid) -> FanResult<()> {
+        self.personnel
+            .notify_limit(person, counterparty, self.base.now())?;
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 获取人员名册
+    pub fn staff(&self) -> &[StaffMember] {
+        self.personnel.staff()
+    }
+
     /// 更新职权状态
     pub fn update_authority_status(&mut self, new_status: AuthorityStatus) -> FanResult<()> {
         if let CapacityStatus::UnincorporatedOrg(scope) = &mut self.base.capacity_status {
             scope.status = new_status;
-            self.base.updated_at = Utc::now();
+            self.base.touch();
             Ok(())
         } else {
             Err(FanError::validation(
@@ -168,24 +395,91 @@ impl UnincorporatedOrg {
         }
     }
 
+    /// 获取组织类型
+    pub fn org_type(&self) -> &UnincorporatedOrgType {
+        &self.org_type
+    }
+
+    /// 获取执行事务合伙人
+    pub fn executive_partner(&self) -> Option<Uuid> {
+        self.executive_partner
+    }
+
+    /// 获取投资人
+    pub fn proprietor(&self) -> Option<Uuid> {
+        self.proprietor
+    }
+
+    /// 设置个人独资企业的投资人，投资人以其个人财产对企业债务承担无限责任
+    pub fn set_proprietor(&mut self, proprietor: &dyn Entity) -> FanResult<()> {
+        check_proprietor(&self.org_type, proprietor)?;
+        self.proprietor = Some(proprietor.id());
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 获取所属的法人或者非法人组织
+    pub fn parent(&self) -> Option<Uuid> {
+        self.parent
+    }
+
+    /// 设置分支机构所属的法人或者非法人组织
+    pub fn set_parent(&mut self, parent: &dyn Entity) -> FanResult<()> {
+        check_parent(&self.org_type, self.base.id, parent)?;
+        self.parent = Some(parent.id());
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 获取分支机构的负责人
+    pub fn person_in_charge(&self) -> Option<Uuid> {
+        self.person_in_charge
+    }
+
+    /// 设置分支机构的负责人，由其代表分支机构从事民事活动
+    pub fn set_person_in_charge(&mut self, person: &dyn Entity) -> FanResult<()> {
+        check_person_in_charge(&self.org_type, person, self.base.now())?;
+        self.person_in_charge = Some(person.id());
+        self.base.touch();
+        Ok(())
+    }
+
+    /// 对本组织的行为所产生的民事责任最终承担无限责任的主体
+    ///
+    /// 个人独资企业为投资人，分支机构为其所属的法人或者非法人组织（《民法典》第七十四条），合伙企业由合伙人
+    /// 按 [`Self::allocate_liability`] 分担，为空。
+    pub fn liability_bearer(&self) -> Option<Uuid> {
+        liability_bearer(&self.org_type, self.proprietor, self.parent)
+    }
+
+    /// 获取成员列表
+    pub fn members(&self) -> &[Partner] {
+        &self.members
+    }
+
+    /// 全体合伙人的出资总额
+    pub fn total_contribution(&self) -> FanResult<Option<Money>> {
+        total_contribution(&self.members)
+    }
+
+    /// 获取注册地址
+    pub fn registered_address(&self) -> &str {
+        &self.registered_address
+    }
+
+    /// 获取成立日期
+    pub fn establishment_date(&self) -> DateTime<Utc> {
+        self.establishment_date
+    }
+
     /// 检查是否可以进行特定活动
     pub fn can_perform_activity(&self, activity: &str) -> bool {
         if let CapacityStatus::UnincorporatedOrg(scope) = &self.base.capacity_status {
             match scope.status {
-                AuthorityStatus::Full => {
-                    scope.permitted_authorities.contains(activity)
-                        && !scope
-                            .restrictions
-                            .as_ref()
-                            .map_or(false, |r| r.contains(&activity.to_string()))
-                }
-                AuthorityStatus::Limited => {
-                    scope.permitted_authorities.contains(activity)
-                        && !scope
-                            .restrictions
-                            .as_ref()
-                            .map_or(false, |r| r.contains(&activity.to_string()))
-                }
+                AuthorityStatus::Full | AuthorityStatus::Limited => IndustryCatalogue::standard()
+                    .resolve(activity)
+                    .codes()
+                    .is_some_and(|codes| codes.iter().all(|c| scope.permits(c))),
                 AuthorityStatus::Suspended => false,
             }
         } else {
@@ -212,10 +506,35 @@ impl Entity for UnincorporatedOrg {
     }
 
     fn has_capacity(&self) -> bool {
-        match self.capacity_status() {
-            CapacityStatus::UnincorporatedOrg(_) => true,
-            _ => false,
-        }
+        matches!(self.capacity_status(), CapacityStatus::UnincorporatedOrg(_))
+    }
+
+    fn licences(&self) -> Vec<Licence> {
+        self.licences.clone()
+    }
+
+    fn personnel(&self) -> Option<Personnel> {
+        Some(self.personnel.clone())
+    }
+
+    fn liability_bearers(&self) -> Vec<Uuid> {
+        UnincorporatedOrg::liability_bearer(self)
+            .into_iter()
+            .collect()
+    }
+
+    fn representatives(&self) -> Vec<Uuid> {
+        representatives(
+            &self.org_type,
+            self.executive_partner,
+            self.proprietor,
+            self.person_in_charge,
+            &self.members,
+        )
+    }
+
+    fn unincorporated_form(&self) -> Option<UnincorporatedOrgType> {
+        Some(self.org_type.clone())
     }
 }
 
@@ -225,9 +544,15 @@ pub struct SyncUnincorporatedOrg {
     base: Arc<RwLock<BaseEntity>>,
     org_type: UnincorporatedOrgType, // 不可变
     executive_partner: Arc<RwLock<Option<Uuid>>>,
+    proprietor: Arc<RwLock<Option<Uuid>>>, // 投资人（个人独资企业特有）
+    parent: Arc<RwLock<Option<Uuid>>>,     // 所属的法人或者非法人组织（分支机构特有）
+    person_in_charge: Arc<RwLock<Option<Uuid>>>, // 负责人（分支机构特有）
     members: Arc<RwLock<Vec<Partner>>>,
     registered_address: Arc<RwLock<String>>,
-    establishment_date: DateTime<Utc>, // 不可变
+    establishment_date: DateTime<Utc>,                // 不可变
+    licences: Arc<RwLock<Vec<Licence>>>,              // 持有的许可证
+    personnel: Arc<RwLock<Personnel>>,                // 执行工作任务的人员名册
+    partner_changes: Arc<RwLock<Vec<PartnerChange>>>, // 退伙、除名和身份转变的记录
 }
 
 impl SyncUnincorporatedOrg {
@@ -236,7 +561,21 @@ impl SyncUnincorporatedOrg {
         registered_address: String,
         establishment_date: DateTime<Utc>,
     ) -> Self {
-        let now = Utc::now();
+        Self::with_clock(
+            org_type,
+            registered_address,
+            establishment_date,
+            default_clock(),
+        )
+    }
+
+    /// 使用指定时钟创建非法人组织
+    pub fn with_clock(
+        org_type: UnincorporatedOrgType,
+        registered_address: String,
+        establishment_date: DateTime<Utc>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
         let authority_scope = AuthorityScope {
             status: AuthorityStatus::Full,
             permitted_authorities: HashSet::new(),
@@ -244,35 +583,121 @@ impl SyncUnincorporatedOrg {
         };
 
         Self {
-            base: Arc::new(RwLock::new(BaseEntity {
-                id: Uuid::new_v4(),
-                entity_type: EntityType::UnincorporatedOrg,
-                capacity_status: CapacityStatus::UnincorporatedOrg(authority_scope),
-                created_at: now,
-                updated_at: now,
-            })),
+            base: Arc::new(RwLock::new(BaseEntity::new(
+                EntityType::UnincorporatedOrg,
+                CapacityStatus::UnincorporatedOrg(authority_scope),
+                clock,
+            ))),
             org_type,
             executive_partner: Arc::new(RwLock::new(None)),
+            proprietor: Arc::new(RwLock::new(None)),
+            parent: Arc::new(RwLock::new(None)),
+            person_in_charge: Arc::new(RwLock::new(None)),
             members: Arc::new(RwLock::new(Vec::new())),
             registered_address: Arc::new(RwLock::new(registered_address)),
             establishment_date,
+            licences: Arc::new(RwLock::new(Vec::new())),
+            personnel: Arc::new(RwLock::new(Personnel::default())),
+            partner_changes: Arc::new(RwLock::new(Vec::new())),
         }
     }
 
-    pub fn add_partner(&self, partner: Partner) -> FanResult<()> {
-        match self.org_type {
-            UnincorporatedOrgType::Partnership(_) => {
-                self.members.write().push(partner);
-                self.base.write().updated_at = Utc::now();
-                Ok(())
-            }
-            _ => Err(FanError::validation(
-                "Only partnership can add partners",
-                ValidationErrorType::EntityStatusIllegal,
-                "add_partner",
-                "SyncUnincorporatedOrg",
-            )),
+    pub fn add_partner(&self, partner: Partner, entity: &dyn Entity) -> FanResult<()> {
+        let mut members = self.members.write();
+        check_admission(&self.org_type, &members, &partner, entity)?;
+        check_contribution(&members, &partner)?;
+        members.push(partner);
+        drop(members); // 释放写锁
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn validate_composition(&self) -> FanResult<()> {
+        check_composition(&self.org_type, &self.members.read())
+    }
+
+    pub fn withdraw_partner(&self, partner_id: Uuid) -> FanResult<PartnerExit> {
+        self.remove_partner(partner_id, PartnerChangeKind::Withdrawal, None)
+    }
+
+    pub fn expel_partner(
+        &self,
+        partner_id: Uuid,
+        ground: ExpulsionGround,
+        consents: &[Uuid],
+    ) -> FanResult<PartnerExit> {
+        self.remove_partner(
+            partner_id,
+            PartnerChangeKind::Expulsion(ground),
+            Some(consents),
+        )
+    }
+
+    fn remove_partner(
+        &self,
+        partner_id: Uuid,
+        kind: PartnerChangeKind,
+        consents: Option<&[Uuid]>,
+    ) -> FanResult<PartnerExit> {
+        let at = self.base.read().now();
+        let mut members = self.members.write();
+        if let Some(consents) = consents {
+            partnership::check_expulsion_consents(&members, partner_id, consents)?;
         }
+        let exit = partnership::remove_partner(
+            &self.org_type,
+            &mut members,
+            &mut self.partner_changes.write(),
+            &mut self.executive_partner.write(),
+            partner_id,
+            kind,
+            at,
+        )?;
+        drop(members); // 释放写锁
+        self.base.write().touch();
+        Ok(exit)
+    }
+
+    pub fn convert_partner(&self, entity: &dyn Entity, to: PartnerType) -> FanResult<()> {
+        let at = self.base.read().now();
+        partnership::convert_partner(
+            &self.org_type,
+            &mut self.members.write(),
+            &mut self.partner_changes.write(),
+            entity,
+            to,
+            at,
+        )?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn partner_changes(&self) -> Vec<PartnerChange> {
+        self.partner_changes.read().clone()
+    }
+
+    pub fn liability_for(
+        &self,
+        partner_id: Uuid,
+        debt_incurred_at: DateTime<Utc>,
+    ) -> Option<LiabilityType> {
+        partnership::liability_for_debt(
+            &self.members.read(),
+            &self.partner_changes.read(),
+            partner_id,
+            debt_incurred_at,
+        )
+    }
+
+    pub fn allocate_liability(&self, assets: Money, debt: &Debt) -> FanResult<LiabilityAllocation> {
+        liability::allocate(
+            &self.org_type,
+            self.liability_bearer(),
+            &self.members.read(),
+            &self.partner_changes.read(),
+            assets,
+            debt,
+        )
     }
 
     pub fn set_executive_partner(&self, partner_id: Uuid) -> FanResult<()> {
@@ -282,7 +707,7 @@ impl SyncUnincorporatedOrg {
                 if members.iter().any(|p| p.id == partner_id) {
                     drop(members); // 释放读锁
                     *self.executive_partner.write() = Some(partner_id);
-                    self.base.write().updated_at = Utc::now();
+                    self.base.write().touch();
                     Ok(())
                 } else {
                     Err(FanError::validation(
@@ -303,10 +728,11 @@ impl SyncUnincorporatedOrg {
     }
 
     pub fn add_authority(&self, authority: String) -> FanResult<()> {
+        let codes = IndustryCatalogue::standard().resolve_or_err(&authority, "add_authority")?;
         let mut base = self.base.write();
         if let CapacityStatus::UnincorporatedOrg(scope) = &mut base.capacity_status {
-            scope.permitted_authorities.insert(authority);
-            base.updated_at = Utc::now();
+            scope.permitted_authorities.extend(codes);
+            base.touch();
             Ok(())
         } else {
             Err(FanError::validation(
@@ -318,11 +744,71 @@ impl SyncUnincorporatedOrg {
         }
     }
 
+    pub fn add_licence(&self, licence: Licence) -> FanResult<()> {
+        self.licences.write().push(licence);
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn revoke_licence(&self, number: &str, at: DateTime<Utc>) -> FanResult<()> {
+        revoke_licence_in(&mut self.licences.write(), number, at)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn appoint_staff(
+        &self,
+        person: &dyn Entity,
+        duties: Vec<SubjectMatterType>,
+    ) -> FanResult<()> {
+        let now = self.base.read().now();
+        self.personnel.write().appoint(person, duties, now)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn dismiss_staff(&self, person: Uuid) -> FanResult<()> {
+        let now = self.base.read().now();
+        self.personnel.write().dismiss(person, now)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    /// 以章程、决议或者内部规定限制法定代表人、负责人或者工作人员的权限，取代此前对该人员的限制
+    pub fn limit_authority(
+        &self,
+        person: Uuid,
+        matters: Option<Vec<SubjectMatterType>>,
+        price_limit: Option<Money>,
+    ) -> FanResult<()> {
+        let representatives = self.representatives();
+        let now = self.base.read().now();
+        self.personnel
+            .write()
+            .limit(&representatives, person, matters, price_limit, now)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    /// 将对某一人员权限的限制告知相对人，此后该相对人知道该限制（第五百零四条）
+    pub fn notify_internal_limit(&self, person: Uuid, counterparty: Uuid) -> FanResult<()> {
+        let now = self.base.read().now();
+        self.personnel
+            .write()
+            .notify_limit(person, counterparty, now)?;
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn staff(&self) -> Vec<StaffMember> {
+        self.personnel.read().staff().to_vec()
+    }
+
     pub fn update_authority_status(&self, new_status: AuthorityStatus) -> FanResult<()> {
         let mut base = self.base.write();
         if let CapacityStatus::UnincorporatedOrg(scope) = &mut base.capacity_status {
             scope.status = new_status;
-            base.updated_at = Utc::now();
+            base.touch();
             Ok(())
         } else {
             Err(FanError::validation(
@@ -338,20 +824,10 @@ impl SyncUnincorporatedOrg {
         let base = self.base.read();
         if let CapacityStatus::UnincorporatedOrg(scope) = &base.capacity_status {
             match scope.status {
-                AuthorityStatus::Full => {
-                    scope.permitted_authorities.contains(activity)
-                        && !scope
-                            .restrictions
-                            .as_ref()
-                            .map_or(false, |r| r.contains(&activity.to_string()))
-                }
-                AuthorityStatus::Limited => {
-                    scope.permitted_authorities.contains(activity)
-                        && !scope
-                            .restrictions
-                            .as_ref()
-                            .map_or(false, |r| r.contains(&activity.to_string()))
-                }
+                AuthorityStatus::Full | AuthorityStatus::Limited => IndustryCatalogue::standard()
+                    .resolve(activity)
+                    .codes()
+                    .is_some_and(|codes| codes.iter().all(|c| scope.permits(c))),
                 AuthorityStatus::Suspended => false,
             }
         } else {
@@ -359,14 +835,83 @@ impl SyncUnincorporatedOrg {
         }
     }
 
+    pub fn org_type(&self) -> &UnincorporatedOrgType {
+        &self.org_type
+    }
+
+    pub fn executive_partner(&self) -> Option<Uuid> {
+        *self.executive_partner.read()
+    }
+
+    pub fn proprietor(&self) -> Option<Uuid> {
+        *self.proprietor.read()
+    }
+
+    pub fn set_proprietor(&self, proprietor: &dyn Entity) -> FanResult<()> {
+        check_proprietor(&self.org_type, proprietor)?;
+        *self.proprietor.write() = Some(proprietor.id());
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn parent(&self) -> Option<Uuid> {
+        *self.parent.read()
+    }
+
+    pub fn set_parent(&self, parent: &dyn Entity) -> FanResult<()> {
+        let id = self.base.read().id;
+        check_parent(&self.org_type, id, parent)?;
+        *self.parent.write() = Some(parent.id());
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn person_in_charge(&self) -> Option<Uuid> {
+        *self.person_in_charge.read()
+    }
+
+    pub fn set_person_in_charge(&self, person: &dyn Entity) -> FanResult<()> {
+        let now = self.base.read().now();
+        check_person_in_charge(&self.org_type, person, now)?;
+        *self.person_in_charge.write() = Some(person.id());
+        self.base.write().touch();
+        Ok(())
+    }
+
+    pub fn liability_bearer(&self) -> Option<Uuid> {
+        liability_bearer(&self.org_type, *self.proprietor.read(), *self.parent.read())
+    }
+
+    pub fn members(&self) -> Vec<Partner> {
+        self.members.read().clone()
+    }
+
+    pub fn total_contribution(&self) -> FanResult<Option<Money>> {
+        total_contribution(&self.members.read())
+    }
+
+    pub fn registered_address(&self) -> String {
+        self.registered_address.read().clone()
+    }
+
+    pub fn establishment_date(&self) -> DateTime<Utc> {
+        self.establishment_date
+    }
+
     pub fn from_unincorporated_org(org: UnincorporatedOrg) -> Self {
         Self {
             base: Arc::new(RwLock::new(org.base)),
             org_type: org.org_type,
             executive_partner: Arc::new(RwLock::new(org.executive_partner)),
+            proprietor: Arc::new(RwLock::new(org.proprietor)),
+            parent: Arc::new(RwLock::new(org.parent)),
+            person_in_charge: Arc::new(RwLock::new(org.person_in_charge)),
             members: Arc::new(RwLock::new(org.members)),
             registered_address: Arc::new(RwLock::new(org.registered_address)),
             establishment_date: org.establishment_date,
+            licences: Arc::new(RwLock::new(org.licences)),
+            personnel: Arc::new(RwLock::new(org.personnel)),
+            partner_changes: Arc::new(RwLock::new(org.partner_changes)),
         }
     }
 }
@@ -393,35 +938,453 @@ impl Entity for SyncUnincorporatedOrg {
     }
 
     fn has_capacity(&self) -> bool {
-        match self.capacity_status() {
-            CapacityStatus::UnincorporatedOrg(_) => true,
-            _ => false,
+        matches!(self.capacity_status(), CapacityStatus::UnincorporatedOrg(_))
+    }
+
+    fn licences(&self) -> Vec<Licence> {
+        self.licences.read().clone()
+    }
+
+    fn personnel(&self) -> Option<Personnel> {
+        Some(self.personnel.read().clone())
+    }
+
+    fn liability_bearers(&self) -> Vec<Uuid> {
+        SyncUnincorporatedOrg::liability_bearer(self)
+            .into_iter()
+            .collect()
+    }
+
+    fn representatives(&self) -> Vec<Uuid> {
+        representatives(
+            &self.org_type,
+            self.executive_partner(),
+            self.proprietor(),
+            self.person_in_charge(),
+            &self.members(),
+        )
+    }
+
+    fn unincorporated_form(&self) -> Option<UnincorporatedOrgType> {
+        Some(self.org_type.clone())
+    }
+}
+
+/// 校验新合伙人的出资
+///
+/// 出资额不能为负；普通合伙人可以劳务出资，出资额可以为零，有限合伙人不得以劳务出资
+/// （《合伙企业法》第十六条、第六十四条）；全体合伙人的出资应以同一币种计。
+fn check_contribution(members: &[Partner], partner: &Partner) -> FanResult<()> {
+    let contribution = &partner.contribution;
+    let valid = match partner.partnership_type {
+        PartnerType::GeneralPartner => !contribution.amount().is_sign_negative(),
+        PartnerType::LimitedPartner => contribution.is_positive(),
+    };
+    if !valid {
+        return Err(FanError::validation_with_ref(
+            "出资额不能为负，有限合伙人的出资额必须大于零",
+            ValidationErrorType::EntityError,
+            "《合伙企业法》第十六条、第六十四条",
+            "add_partner",
+            "UnincorporatedOrg",
+        ));
+    }
+    if let Some(existing) = members.first() {
+        existing.contribution.checked_cmp(contribution)?;
+    }
+    Ok(())
+}
+
+/// 个人独资企业由一个自然人投资（《个人独资企业法》第二条）
+fn check_proprietor(org_type: &UnincorporatedOrgType, proprietor: &dyn Entity) -> FanResult<()> {
+    if *org_type != UnincorporatedOrgType::IndividualBusiness
+        || proprietor.entity_type() != EntityType::NaturalPerson
+    {
+        return Err(FanError::validation_with_ref(
+            "只有个人独资企业才有投资人，投资人须为自然人",
+            ValidationErrorType::EntityRelationMalformed,
+            "《个人独资企业法》第二条",
+            "set_proprietor",
+            "UnincorporatedOrg",
+        ));
+    }
+    Ok(())
+}
+
+/// 分支机构由已成立的法人或者分支机构以外的非法人组织设立（《民法典》第七十四条）
+fn check_parent(org_type: &UnincorporatedOrgType, id: Uuid, parent: &dyn Entity) -> FanResult<()> {
+    let eligible = match parent.entity_type() {
+        EntityType::LegalPerson => true,
+        EntityType::UnincorporatedOrg => {
+            parent.unincorporated_form() != Some(UnincorporatedOrgType::Branch)
         }
+        _ => false,
+    };
+    if *org_type != UnincorporatedOrgType::Branch || !eligible || parent.id() == id {
+        return Err(FanError::validation_with_ref(
+            "只有分支机构才有所属的组织，且须为已成立的法人或者分支机构以外的非法人组织",
+            ValidationErrorType::EntityRelationMalformed,
+            "《民法典》第七十四条",
+            "set_parent",
+            "UnincorporatedOrg",
+        ));
+    }
+    Ok(())
+}
+
+/// 分支机构的负责人为具有完全民事行为能力的自然人
+fn check_person_in_charge(
+    org_type: &UnincorporatedOrgType,
+    person: &dyn Entity,
+    at: DateTime<Utc>,
+) -> FanResult<()> {
+    if *org_type != UnincorporatedOrgType::Branch
+        || person.entity_type() != EntityType::NaturalPerson
+        || !person.has_capacity_at(at)
+    {
+        return Err(FanError::validation_with_ref(
+            "只有分支机构才设负责人，负责人须为具有完全民事行为能力的自然人",
+            ValidationErrorType::EntityRelationMalformed,
+            "《民法典》第一百零五条",
+            "set_person_in_charge",
+            "UnincorporatedOrg",
+        ));
+    }
+    Ok(())
+}
+
+fn liability_bearer(
+    org_type: &UnincorporatedOrgType,
+    proprietor: Option<Uuid>,
+    parent: Option<Uuid>,
+) -> Option<Uuid> {
+    match org_type {
+        UnincorporatedOrgType::IndividualBusiness => proprietor,
+        UnincorporatedOrgType::Branch => parent,
+        _ => None,
+    }
+}
+
+/// 代表非法人组织从事民事活动的人（第一百零五条）
+///
+/// 合伙企业委托执行事务合伙人的，由其代表；未委托的，由各普通合伙人代表（《合伙企业法》第二十六条）。
+/// 个人独资企业由投资人代表，分支机构由其负责人代表。
+fn representatives(
+    org_type: &UnincorporatedOrgType,
+    executive_partner: Option<Uuid>,
+    proprietor: Option<Uuid>,
+    person_in_charge: Option<Uuid>,
+    members: &[Partner],
+) -> Vec<Uuid> {
+    match org_type {
+        UnincorporatedOrgType::Partnership(_) => match executive_partner {
+            Some(partner) => vec![partner],
+            None => members
+                .iter()
+                .filter(|p| p.partnership_type == PartnerType::GeneralPartner)
+                .map(|p| p.id)
+                .collect(),
+        },
+        UnincorporatedOrgType::IndividualBusiness => proprietor.into_iter().collect(),
+        UnincorporatedOrgType::Branch => person_in_charge.into_iter().collect(),
+        _ => Vec::new(),
     }
 }
 
+/// 出资总额，没有合伙人时为空
+fn total_contribution(members: &[Partner]) -> FanResult<Option<Money>> {
+    let Some(first) = members.first() else {
+        return Ok(None);
+    };
+    Money::sum(
+        members.iter().map(|p| &p.contribution),
+        first.contribution.currency(),
+    )
+    .map(Some)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::clock::{Clock, ManualClock};
+    use crate::core::entity::{
+        CompanyType, CompositionFollowUp, DebtCause, LegalPerson, LegalPersonInFormation,
+        LegalPersonType,
+    };
+    use crate::core::money::Currency;
+    use crate::testing::{adult, fixed_clock, now};
+    use chrono::Duration;
+    use rust_decimal::Decimal;
 
     #[test]
     fn test_partnership_creation() {
-        let mut partnership = UnincorporatedOrg::new(
+        let clock = fixed_clock();
+        let mut partnership = UnincorporatedOrg::with_clock(
             UnincorporatedOrgType::Partnership(PartnershipType::General),
             "北京市海淀区xxx街道".to_string(),
-            Utc::now(),
+            clock.now(),
+            clock.clone(),
         );
+        let adult = || adult(&clock);
 
+        let first = adult();
         let partner = Partner {
-            id: Uuid::new_v4(),
+            id: first.id(),
             partnership_type: PartnerType::GeneralPartner,
-            contribution: 100000.0,
+            contribution: Money::cny(Decimal::from(100_000)),
             profit_sharing_ratio: 0.5,
             liability_type: LiabilityType::Unlimited,
         };
 
-        assert!(partnership.add_partner(partner.clone()).is_ok());
+        assert!(partnership.add_partner(partner.clone(), &first).is_ok());
         assert!(partnership.set_executive_partner(partner.id).is_ok());
+        assert!(partnership.validate_composition().is_err());
+
+        // 以劳务出资的普通合伙人，出资额为零
+        let second = adult();
+        let labour = Partner::new(
+            second.id(),
+            PartnerType::GeneralPartner,
+            Money::cny(Decimal::ZERO),
+            0.5,
+            LiabilityType::Unlimited,
+        );
+        assert!(partnership.add_partner(labour, &second).is_ok());
+        assert!(partnership.validate_composition().is_ok());
+
+        // 出资币种与其他合伙人不一致
+        let third = adult();
+        let foreign = Partner::new(
+            third.id(),
+            PartnerType::GeneralPartner,
+            Money::new(Decimal::from(10_000), Currency::USD),
+            0.0,
+            LiabilityType::Unlimited,
+        );
+        assert!(partnership.add_partner(foreign, &third).is_err());
+        assert_eq!(
+            partnership.total_contribution().unwrap(),
+            Some(Money::cny(Decimal::from(100_000)))
+        );
+
+        // 普通合伙企业不能有有限合伙人，利润分配比例合计不得超过一
+        let limited = Partner::new(
+            third.id(),
+            PartnerType::LimitedPartner,
+            Money::cny(Decimal::from(10_000)),
+            0.0,
+            LiabilityType::Limited,
+        );
+        assert!(partnership.add_partner(limited, &third).is_err());
+        let greedy = Partner::new(
+            third.id(),
+            PartnerType::GeneralPartner,
+            Money::cny(Decimal::from(10_000)),
+            0.5,
+            LiabilityType::Unlimited,
+        );
+        assert!(partnership.add_partner(greedy, &third).is_err());
+    }
+
+    #[test]
+    fn test_limited_partnership_changes() {
+        let clock = Arc::new(ManualClock::new(now()));
+        let clock_dyn: Arc<dyn Clock> = clock.clone();
+        let mut fund = UnincorporatedOrg::with_clock(
+            UnincorporatedOrgType::Partnership(PartnershipType::Limited),
+            "上海市浦东新区xxx路".to_string(),
+            clock.now(),
+            clock.clone(),
+        );
+        let cny = |n: i64| Money::cny(Decimal::from(n));
+
+        // 上市公司和国有企业不得成为普通合伙人，但可以成为有限合伙人
+        let listed = LegalPerson::with_clock(
+            LegalPersonType::Company(CompanyType::Listed),
+            cny(100_000_000),
+            Uuid::new_v4(),
+            "深圳市福田区".to_string(),
+            clock.now(),
+            clock_dyn.clone(),
+        );
+        let as_general = Partner::new(
+            listed.id(),
+            PartnerType::GeneralPartner,
+            cny(1_000_000),
+            0.2,
+            LiabilityType::Unlimited,
+        );
+        assert!(fund.add_partner(as_general, &listed).is_err());
+        let as_limited = Partner::new(
+            listed.id(),
+            PartnerType::LimitedPartner,
+            cny(1_000_000),
+            0.6,
+            LiabilityType::Limited,
+        );
+        fund.add_partner(as_limited, &listed).unwrap();
+        assert!(fund.validate_composition().is_err());
+
+        let manager = adult(&clock_dyn);
+        let general = Partner::new(
+            manager.id(),
+            PartnerType::GeneralPartner,
+            cny(100_000),
+            0.3,
+            LiabilityType::Unlimited,
+        );
+        fund.add_partner(general, &manager).unwrap();
+        fund.set_executive_partner(manager.id()).unwrap();
+
+        // 唯一的普通合伙人不能转为有限合伙人
+        assert!(fund
+            .convert_partner(&manager, PartnerType::LimitedPartner)
+            .is_err());
+        let successor = adult(&clock_dyn);
+        let second_general = Partner::new(
+            successor.id(),
+            PartnerType::GeneralPartner,
+            cny(100_000),
+            0.1,
+            LiabilityType::Unlimited,
+        );
+        fund.add_partner(second_general, &successor).unwrap();
+        fund.validate_composition().unwrap();
+
+        // 普通合伙人转为有限合伙人后，对其作为普通合伙人期间发生的债务仍承担无限连带责任
+        let early_debt = clock.now();
+        clock.advance(Duration::days(30));
+        fund.convert_partner(&manager, PartnerType::LimitedPartner)
+            .unwrap();
+        clock.advance(Duration::days(30));
+        let late_debt = clock.now();
+        assert_eq!(
+            fund.liability_for(manager.id(), early_debt),
+            Some(LiabilityType::Unlimited)
+        );
+        assert_eq!(
+            fund.liability_for(manager.id(), late_debt),
+            Some(LiabilityType::Limited)
+        );
+        fund.validate_composition().unwrap();
+
+        // 被除名的合伙人对除名前发生的债务承担责任，对除名后发生的债务不承担责任；除名须经其他合伙人一致同意
+        clock.advance(Duration::days(1));
+        assert!(fund
+            .expel_partner(
+                listed.id(),
+                ExpulsionGround::FailureToContribute,
+                &[manager.id()]
+            )
+            .is_err());
+        let expelled = fund
+            .expel_partner(
+                listed.id(),
+                ExpulsionGround::FailureToContribute,
+                &[manager.id(), successor.id()],
+            )
+            .unwrap();
+        assert_eq!(expelled.partner().id(), listed.id());
+        assert_eq!(expelled.follow_up(), None);
+        clock.advance(Duration::days(1));
+        assert_eq!(
+            fund.liability_for(listed.id(), late_debt),
+            Some(LiabilityType::Limited)
+        );
+        assert_eq!(fund.liability_for(listed.id(), clock.now()), None);
+        assert_eq!(fund.partner_changes().len(), 2);
+
+        // 执行事务合伙人退伙的，不再执行合伙事务；仅剩普通合伙人的，应当转为普通合伙企业
+        let exit = fund.withdraw_partner(manager.id()).unwrap();
+        assert_eq!(
+            exit.follow_up(),
+            Some(&CompositionFollowUp::ConvertToGeneralPartnership)
+        );
+        assert_eq!(fund.executive_partner(), None);
+        assert!(fund.withdraw_partner(manager.id()).is_err());
+    }
+
+    #[test]
+    fn test_proprietor_and_branch() {
+        let clock = fixed_clock();
+        let shop = SyncUnincorporatedOrg::with_clock(
+            UnincorporatedOrgType::IndividualBusiness,
+            "杭州市西湖区xxx路".to_string(),
+            clock.now(),
+            clock.clone(),
+        );
+        let owner = adult(&clock);
+        let company = LegalPerson::with_clock(
+            LegalPersonType::Company(CompanyType::Limited),
+            Money::cny(Decimal::from(1_000_000)),
+            owner.id(),
+            "杭州市滨江区xxx路".to_string(),
+            clock.now(),
+            clock.clone(),
+        );
+
+        // 个人独资企业的投资人须为自然人，以其个人财产承担无限责任
+        assert!(shop.set_proprietor(&company).is_err());
+        shop.set_proprietor(&owner).unwrap();
+        assert_eq!(shop.proprietor(), Some(owner.id()));
+        let debt = Debt::new(
+            Money::cny(Decimal::from(50_000)),
+            clock.now(),
+            DebtCause::Ordinary,
+        )
+        .unwrap();
+        let allocation = shop
+            .allocate_liability(Money::cny(Decimal::from(20_000)), &debt)
+            .unwrap();
+        let exposure = allocation.exposure_of(owner.id()).unwrap();
+        assert_eq!(exposure.liability_type, LiabilityType::Unlimited);
+        assert_eq!(exposure.exposure, Money::cny(Decimal::from(30_000)));
+
+        // 分支机构的民事责任由所属法人承担
+        let mut branch = UnincorporatedOrg::with_clock(
+            UnincorporatedOrgType::Branch,
+            "宁波市鄞州区xxx路".to_string(),
+            clock.now(),
+            clock.clone(),
+        );
+        assert!(branch.set_parent(&owner).is_err());
+        assert!(branch.set_proprietor(&owner).is_err());
+        branch.set_parent(&company).unwrap();
+        assert_eq!(branch.parent(), Some(company.id()));
+
+        // 分支机构由其负责人代表
+        assert!(branch.representatives().is_empty());
+        assert!(branch.set_person_in_charge(&company).is_err());
+        assert!(shop.set_person_in_charge(&owner).is_err());
+        branch.set_person_in_charge(&owner).unwrap();
+        assert_eq!(branch.representatives(), vec![owner.id()]);
+
+        // 分支机构不得隶属于其他分支机构或者设立中的法人
+        let mut sub_branch = UnincorporatedOrg::with_clock(
+            UnincorporatedOrgType::Branch,
+            "宁波市海曙区xxx路".to_string(),
+            clock.now(),
+            clock.clone(),
+        );
+        let forming = LegalPersonInFormation::with_clock(
+            LegalPersonType::Company(CompanyType::Limited),
+            Money::cny(Decimal::from(1_000_000)),
+            &[&owner],
+            clock.clone(),
+        )
+        .unwrap();
+        assert!(sub_branch.set_parent(&branch).is_err());
+        assert!(sub_branch.set_parent(&forming).is_err());
+        sub_branch.set_parent(&shop).unwrap();
+        assert_eq!(branch.liability_bearers(), vec![company.id()]);
+        let allocation = branch
+            .allocate_liability(Money::cny(Decimal::ZERO), &debt)
+            .unwrap();
+        assert_eq!(
+            allocation.exposure_of(company.id()).unwrap().exposure,
+            Money::cny(Decimal::from(50_000))
+        );
+        assert!(allocation.unallocated().is_zero());
     }
 
     #[test]
@@ -432,11 +1395,13 @@ mod tests {
             Utc::now(),
         );
 
-        org.add_authority("业务经营".to_string()).unwrap();
-        assert!(org.can_perform_activity("业务经营"));
+        org.add_authority("餐饮服务".to_string()).unwrap();
+        assert!(org.can_perform_activity("餐饮"));
+        assert!(!org.can_perform_activity("住宿服务"));
+        assert!(org.add_authority("业务经营".to_string()).is_err());
 
         org.update_authority_status(AuthorityStatus::Suspended)
             .unwrap();
-        assert!(!org.can_perform_activity("业务经营"));
+        assert!(!org.can_perform_activity("餐饮服务"));
     }
 }
@@ -0,0 +1,60 @@
+//! 标的物类型
+//! 合同标的的种类，既用于意思表示的内容，也用于组织人员名册所载的职权范围和代理权限。
+
+use std::fmt::Display;
+use uuid::Uuid;
+
+/// 标的物类型
+#[derive(Debug, Clone, PartialEq)]
+pub enum SubjectMatterType {
+    /// 特定物
+    SpecificGoods,
+    /// 种类物
+    GenericGoods,
+    /// 特定服务
+    Service,
+    /// 知识产权
+    IntellectualProperty,
+    /// 保证，`guarantor` 为保证人（第六百八十一条）
+    Guarantee { guarantor: Uuid },
+    /// 利润分配，`distributor` 为分配利润的一方
+    ProfitDistribution { distributor: Uuid },
+    /// 其他类型
+    Other(String),
+}
+
+impl SubjectMatterType {
+    /// 创建新的标的物类型
+    ///
+    /// 该构造函数用于初始化一个标的物类型的实例，其中包含了标的物类型的名称。
+    ///
+    /// # 参数 Arguments
+    /// - `name`: 标的物类型名称，表示标的物的类型，如特定物、种类物、特定服务等。
+    ///
+    /// # 返回值 Returns
+    /// 返回一个标的物类型的实例，其中包含了提供的参数值。
+    pub fn new(name: String) -> Self {
+        match name.as_str() {
+            "specific_goods" => Self::SpecificGoods,
+            "generic_goods" => Self::GenericGoods,
+            "service" => Self::Service,
+            "intellectual_property" => Self::IntellectualProperty,
+            _ => Self::Other(name),
+        }
+    }
+}
+
+/// 标的物类型的名称
+impl Display for SubjectMatterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SpecificGoods => write!(f, "specific_goods"),
+            Self::GenericGoods => write!(f, "generic_goods"),
+            Self::Service => write!(f, "service"),
+            Self::IntellectualProperty => write!(f, "intellectual_property"),
+            Self::Guarantee { .. } => write!(f, "guarantee"),
+            Self::ProfitDistribution { .. } => write!(f, "profit_distribution"),
+            Self::Other(name) => write!(f, "{}", name),
+        }
+    }
+}
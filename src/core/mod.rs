@@ -1 +1,4 @@
+pub mod clock;
 pub mod entity;
+pub mod matter;
+pub mod money;
@@ -0,0 +1,285 @@
+//! 金额
+//! 以十进制数表示金额并附带 ISO 4217 币种，用于注册资本、出资额和合同价款，避免浮点运算的误差。
+//! 不同币种的金额不能直接运算或比较；按币种的最小货币单位（如人民币的分）四舍五入。
+
+use crate::{FanError, FanResult};
+
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+/// ISO 4217 币种
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: [u8; 3],    // 三位字母代码
+    minor_units: u32, // 最小货币单位的小数位数
+}
+
+impl Currency {
+    /// 人民币，最小单位为分
+    pub const CNY: Currency = Currency::known(*b"CNY", 2);
+    /// 港元
+    pub const HKD: Currency = Currency::known(*b"HKD", 2);
+    /// 美元
+    pub const USD: Currency = Currency::known(*b"USD", 2);
+    /// 欧元
+    pub const EUR: Currency = Currency::known(*b"EUR", 2);
+    /// 英镑
+    pub const GBP: Currency = Currency::known(*b"GBP", 2);
+    /// 日元，没有辅币
+    pub const JPY: Currency = Currency::known(*b"JPY", 0);
+
+    const KNOWN: [Currency; 6] = [
+        Self::CNY,
+        Self::HKD,
+        Self::USD,
+        Self::EUR,
+        Self::GBP,
+        Self::JPY,
+    ];
+
+    const fn known(code: [u8; 3], minor_units: u32) -> Self {
+        Self { code, minor_units }
+    }
+
+    /// 按三位字母代码查找币种，未收录的代码按两位小数处理
+    pub fn from_code(code: &str) -> FanResult<Self> {
+        let bytes: [u8; 3] = code
+            .as_bytes()
+            .try_into()
+            .ok()
+            .filter(|b: &[u8; 3]| b.iter().all(u8::is_ascii_uppercase))
+            .ok_or_else(|| {
+                FanError::system(
+                    format!("无效的 ISO 4217 币种代码：{}", code),
+                    "InvalidCurrency",
+                )
+            })?;
+        Ok(Self::KNOWN
+            .into_iter()
+            .find(|c| c.code == bytes)
+            .unwrap_or(Self::known(bytes, 2)))
+    }
+
+    /// 获取三位字母代码
+    pub fn code(&self) -> &str {
+        std::str::from_utf8(&self.code).expect("币种代码均为 ASCII 字母")
+    }
+
+    /// 获取最小货币单位的小数位数
+    pub fn minor_units(&self) -> u32 {
+        self.minor_units
+    }
+}
+
+impl Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+/// 金额
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: Decimal,    // 数额
+    currency: Currency, // 币种
+}
+
+impl Money {
+    pub fn new(amount: Decimal, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// 以人民币计的金额
+    pub fn cny(amount: Decimal) -> Self {
+        Self::new(amount, Currency::CNY)
+    }
+
+    /// 零金额
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(Decimal::ZERO, currency)
+    }
+
+    /// 以最小货币单位（如分）计的金额
+    pub fn from_minor_units(units: i64, currency: Currency) -> Self {
+        Self::new(Decimal::new(units, currency.minor_units), currency)
+    }
+
+    /// 获取数额
+    pub fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// 获取币种
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// 是否为零
+    pub fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    /// 是否大于零
+    pub fn is_positive(&self) -> bool {
+        self.amount > Decimal::ZERO
+    }
+
+    /// 加法，币种不同的返回错误
+    pub fn checked_add(&self, other: &Money) -> FanResult<Money> {
+        self.ensure_same_currency(other, "add")?;
+        self.amount
+            .checked_add(other.amount)
+            .map(|amount| Money::new(amount, self.currency))
+            .ok_or_else(|| overflow("add"))
+    }
+
+    /// 减法，币种不同的返回错误
+    pub fn checked_sub(&self, other: &Money) -> FanResult<Money> {
+        self.ensure_same_currency(other, "sub")?;
+        self.amount
+            .checked_sub(other.amount)
+            .map(|amount| Money::new(amount, self.currency))
+            .ok_or_else(|| overflow("sub"))
+    }
+
+    /// 乘以比例或数量，结果不舍入
+    pub fn checked_mul(&self, factor: Decimal) -> FanResult<Money> {
+        self.amount
+            .checked_mul(factor)
+            .map(|amount| Money::new(amount, self.currency))
+            .ok_or_else(|| overflow("mul"))
+    }
+
+    /// 比较大小，币种不同的返回错误
+    pub fn checked_cmp(&self, other: &Money) -> FanResult<Ordering> {
+        self.ensure_same_currency(other, "cmp")?;
+        Ok(self.amount.cmp(&other.amount))
+    }
+
+    /// 求和，`currency` 为空集合时结果的币种
+    pub fn sum<'a>(
+        amounts: impl IntoIterator<Item = &'a Money>,
+        currency: Currency,
+    ) -> FanResult<Money> {
+        amounts
+            .into_iter()
+            .try_fold(Money::zero(currency), |total, money| {
+                total.checked_add(money)
+            })
+    }
+
+    /// 按最小货币单位四舍五入，如人民币保留到分
+    pub fn round(&self) -> Money {
+        self.round_with(RoundingStrategy::MidpointAwayFromZero)
+    }
+
+    /// 按最小货币单位以指定方式舍入
+    pub fn round_with(&self, strategy: RoundingStrategy) -> Money {
+        Money::new(
+            self.amount
+                .round_dp_with_strategy(self.currency.minor_units, strategy),
+            self.currency,
+        )
+    }
+
+    /// 换算为最小货币单位的整数，先四舍五入
+    pub fn to_minor_units(&self) -> FanResult<i64> {
+        let scaled = self.round().amount * Decimal::from(10i64.pow(self.currency.minor_units));
+        i64::try_from(scaled).map_err(|_| overflow("to_minor_units"))
+    }
+
+    fn ensure_same_currency(&self, other: &Money, operation: &str) -> FanResult<()> {
+        if self.currency != other.currency {
+            return Err(FanError::system(
+                format!(
+                    "币种不一致，无法{}：{} 与 {}",
+                    operation, self.currency, other.currency
+                ),
+                "CurrencyMismatch",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn overflow(operation: &str) -> FanError {
+    FanError::system(format!("金额运算溢出：{}", operation), "MoneyOverflow")
+}
+
+impl Display for Money {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.*} {}",
+            self.currency.minor_units as usize, self.amount, self.currency
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn cny(amount: &str) -> Money {
+        Money::cny(Decimal::from_str(amount).unwrap())
+    }
+
+    #[test]
+    fn test_decimal_arithmetic_is_exact() {
+        let total = cny("0.1").checked_add(&cny("0.2")).unwrap();
+        assert_eq!(total.amount(), Decimal::from_str("0.3").unwrap());
+        assert_eq!(
+            total.checked_sub(&cny("0.3")).unwrap(),
+            Money::zero(Currency::CNY)
+        );
+        assert_eq!(
+            Money::sum(&[cny("1.5"), cny("2.5")], Currency::CNY).unwrap(),
+            cny("4")
+        );
+        assert_eq!(
+            Money::sum(&[], Currency::USD).unwrap(),
+            Money::zero(Currency::USD)
+        );
+    }
+
+    #[test]
+    fn test_rounding_to_minor_units() {
+        // 三分之一的出资，按分四舍五入
+        let share = Money::cny(Decimal::from(100))
+            .checked_mul(Decimal::ONE / Decimal::from(3))
+            .unwrap();
+        assert_eq!(share.round().to_string(), "33.33 CNY");
+        assert_eq!(
+            cny("0.125")
+                .round_with(RoundingStrategy::MidpointNearestEven)
+                .amount(),
+            Decimal::from_str("0.12").unwrap()
+        );
+        assert_eq!(cny("0.125").to_minor_units().unwrap(), 13);
+        assert_eq!(
+            Money::from_minor_units(150, Currency::JPY).amount(),
+            Decimal::from(150)
+        );
+        assert_eq!(Money::from_minor_units(150, Currency::CNY), cny("1.5"));
+    }
+
+    #[test]
+    fn test_currency_mismatch_is_rejected() {
+        let dollars = Money::new(Decimal::from(10), Currency::USD);
+        assert!(cny("10").checked_add(&dollars).is_err());
+        assert!(cny("10").checked_sub(&dollars).is_err());
+        assert!(cny("10").checked_cmp(&dollars).is_err());
+        assert!(Money::sum(&[cny("1"), dollars], Currency::CNY).is_err());
+    }
+
+    #[test]
+    fn test_currency_codes() {
+        assert_eq!(Currency::from_code("USD").unwrap(), Currency::USD);
+        assert_eq!(Currency::from_code("CHF").unwrap().minor_units(), 2);
+        assert_eq!(Currency::from_code("CHF").unwrap().code(), "CHF");
+        assert!(Currency::from_code("usd").is_err());
+        assert!(Currency::from_code("RMB1").is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::core::clock::default_clock;
+
 use chrono::{DateTime, Utc};
 
 /// 法律规范验证错误类型
@@ -34,7 +36,7 @@ pub enum FanError {
         message: String,
         error_type: ValidationErrorType,
         legal_reference: Option<String>,
-        context: ErrorContext,
+        context: Box<ErrorContext>,
     },
 
     /// 程序运行错误
@@ -46,6 +48,7 @@ pub enum FanError {
 
 /// 错误上下文
 #[derive(Debug)]
+#[allow(dead_code)] // 仅通过 Debug 输出
 pub struct ErrorContext {
     /// 执行的操作
     operation: String,
@@ -76,8 +79,8 @@ impl ErrorContext {
             location: location.into(),
             // 初始化 entity_ids 向量为空
             entity_ids: Vec::new(),
-            // 设置当前时间为错误上下文的时间戳
-            timestamp: Utc::now(),
+            // 以默认时钟的当前时间作为错误上下文的时间戳
+            timestamp: default_clock().now(),
         }
     }
 
@@ -116,7 +119,7 @@ impl FanError {
             message: message.into(),
             error_type,
             legal_reference: None,
-            context: ErrorContext::new(operation, location),
+            context: Box::new(ErrorContext::new(operation, location)),
         }
     }
 
@@ -145,7 +148,7 @@ impl FanError {
             message: message.into(),
             error_type,
             legal_reference: Some(legal_reference.into()),
-            context: ErrorContext::new(operation, location),
+            context: Box::new(ErrorContext::new(operation, location)),
         }
     }
 
@@ -156,9 +159,9 @@ impl FanError {
     ///
     /// # 参数
     /// - `message`: 转换为`String`类型的错误消息。这可以是任何实现了`Into<String>` trait的类型，
-    ///              允许灵活地传递错误信息。
+    ///   允许灵活地传递错误信息。
     /// - `error_type`: 静态字符串，表示错误类型。使用静态字符串确保错误类型是已知且不变的，
-    ///                 便于错误处理和日志记录。
+    ///   便于错误处理和日志记录。
     ///
     /// # 返回值
     /// 返回`Self`类型的实例，具体来说是一个`SystemError`变体。
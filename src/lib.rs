@@ -4,6 +4,9 @@ pub use core::*;
 pub mod error;
 
 pub mod validate;
+
+#[cfg(test)]
+mod testing;
 pub use error::*;
 
 // 库的版本信息
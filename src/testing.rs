@@ -0,0 +1,35 @@
+//! 测试共用的固定时刻、时钟和自然人
+
+use crate::core::clock::{Clock, FixedClock};
+use crate::core::entity::{MentalStatus, NaturalPerson};
+
+use chrono::{DateTime, TimeZone, Utc};
+use std::sync::Arc;
+
+/// 测试所用的当前时刻，2025年6月1日
+pub(crate) fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap()
+}
+
+/// 停在 [`now`] 的时钟
+pub(crate) fn fixed_clock() -> Arc<dyn Clock> {
+    Arc::new(FixedClock::new(now()))
+}
+
+/// 使用固定时钟、于某年1月1日出生的精神正常的自然人
+pub(crate) fn person(born: i32) -> NaturalPerson {
+    NaturalPerson::with_clock(
+        Utc.with_ymd_and_hms(born, 1, 1, 0, 0, 0).unwrap(),
+        MentalStatus::Normal,
+        fixed_clock(),
+    )
+}
+
+/// 使用给定时钟的成年人
+pub(crate) fn adult(clock: &Arc<dyn Clock>) -> NaturalPerson {
+    NaturalPerson::with_clock(
+        Utc.with_ymd_and_hms(1985, 1, 1, 0, 0, 0).unwrap(),
+        MentalStatus::Normal,
+        clock.clone(),
+    )
+}
@@ -0,0 +1,229 @@
+//! 经营范围与合同效力
+//! 对应《民法典》第五百零五条：当事人超越经营范围订立的合同的效力，应当依照总则编第六章第三节和合同编的有关规定确定，
+//! 不得仅以超越经营范围确认合同无效。违反限制经营、特许经营以及法律、行政法规禁止经营规定的，合同无效（第一百五十三条）。
+
+use crate::contract::intent::content::IntentContent;
+use crate::core::entity::{
+    ActivityRegulation, ActivityResolution, CapacityStatus, Entity, EntityType, IndustryCatalogue,
+    IndustryCode,
+};
+use crate::{FanError, FanResult, ValidationErrorType};
+
+use chrono::{DateTime, Utc};
+
+/// 当事人从事合同约定的经营活动的评估结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScopeAssessment {
+    /// 合同未约定由该当事人从事经营活动，或该当事人没有登记的经营范围
+    NotApplicable,
+    /// 在登记的经营范围之内
+    WithinScope,
+    /// 超越登记的经营范围，但不因此无效
+    BeyondScopeButValid,
+}
+
+/// 评估当事人能否从事合同约定由其从事的经营活动
+///
+/// 禁止经营的，或者许可经营而当事人在 `at` 时没有有效许可证的，返回错误，合同无效；
+/// 一般经营项目超越经营范围的，合同仍然有效。设立中的法人没有登记的经营范围，不作经营范围的评估。
+pub fn check_business_activity(
+    party: &dyn Entity,
+    content: &IntentContent,
+    at: DateTime<Utc>,
+) -> FanResult<ScopeAssessment> {
+    let Some(business) = content
+        .business_activity
+        .as_ref()
+        .filter(|b| b.performer == party.id())
+    else {
+        return Ok(ScopeAssessment::NotApplicable);
+    };
+
+    // 表述所列的行业；无法确定的，按其可能对应的全部行业审查许可与禁止的规定
+    let catalogue = IndustryCatalogue::standard();
+    let resolution = catalogue.resolve(&business.activity);
+    let codes: &[IndustryCode] = match &resolution {
+        ActivityResolution::Resolved(codes) => codes,
+        ActivityResolution::Ambiguous { candidates, .. } => candidates,
+        ActivityResolution::Unrecognised { .. } => &[],
+    };
+    for code in codes {
+        match catalogue.regulation(code) {
+            ActivityRegulation::Prohibited => {
+                return Err(FanError::validation_with_ref(
+                    format!("{} 属于禁止经营的项目", business.activity),
+                    ValidationErrorType::ContractContentIllegal,
+                    "《民法典》第一百五十三条、第五百零五条",
+                    "check_business_activity",
+                    "BaseContract",
+                ));
+            }
+            ActivityRegulation::LicenceRequired { licence } => {
+                if !party.licences().iter().any(|l| l.permits_at(code, at)) {
+                    return Err(FanError::validation_with_ref(
+                        format!("从事 {} 须取得有效的{}", business.activity, licence),
+                        ValidationErrorType::ContractContentIllegal,
+                        "《民法典》第一百五十三条、第五百零五条",
+                        "check_business_activity",
+                        "BaseContract",
+                    ));
+                }
+            }
+            ActivityRegulation::General => {}
+        }
+    }
+
+    // 表述所列的行业均在经营范围之内的，为在经营范围之内
+    let within = |permits: &dyn Fn(&IndustryCode) -> bool| match resolution.codes() {
+        Some(codes) if codes.iter().all(permits) => ScopeAssessment::WithinScope,
+        _ => ScopeAssessment::BeyondScopeButValid,
+    };
+    if party.entity_type() == EntityType::LegalPersonInFormation {
+        return Ok(ScopeAssessment::NotApplicable);
+    }
+    Ok(match party.capacity_status() {
+        CapacityStatus::LegalPerson(scope) => within(&|c| scope.permits(c)),
+        CapacityStatus::UnincorporatedOrg(scope) => within(&|c| scope.permits(c)),
+        CapacityStatus::NaturalPerson(_) => ScopeAssessment::NotApplicable,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::intent::content::{BusinessActivity, SubjectMatter, SubjectMatterType};
+    use crate::core::entity::{CompanyType, LegalPerson, LegalPersonType, Licence};
+    use crate::core::money::Money;
+    use crate::testing::{fixed_clock, now, person};
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    /// 经营范围为软件开发的有限责任公司
+    fn software_company() -> LegalPerson {
+        let clock = fixed_clock();
+        let mut company = LegalPerson::with_clock(
+            LegalPersonType::Company(CompanyType::Limited),
+            Money::cny(Decimal::from(1_000_000)),
+            Uuid::new_v4(),
+            "北京市海淀区xxx街道".to_string(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+            clock,
+        );
+        company
+            .add_permitted_activity("软件开发".to_string())
+            .unwrap();
+        company
+    }
+
+    fn content(performer: Uuid, activity: &str) -> IntentContent {
+        IntentContent {
+            subject_matter: SubjectMatter::new(
+                Uuid::new_v4(),
+                SubjectMatterType::Service,
+                "测试服务".to_string(),
+                None,
+            ),
+            business_activity: Some(BusinessActivity::new(performer, activity.to_string())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_activity_within_and_beyond_scope() {
+        let company = software_company();
+        let assess =
+            |activity| check_business_activity(&company, &content(company.id(), activity), now());
+        assert_eq!(
+            assess("应用软件开发").unwrap(),
+            ScopeAssessment::WithinScope
+        );
+        // 超越经营范围的一般经营项目，合同不因此无效
+        assert_eq!(
+            assess("房地产经纪").unwrap(),
+            ScopeAssessment::BeyondScopeButValid
+        );
+        // 所列行业有一项超越经营范围的，即超越经营范围
+        assert_eq!(
+            assess("计算机软件开发及销售").unwrap(),
+            ScopeAssessment::BeyondScopeButValid
+        );
+        assert_eq!(
+            assess("软件开发培训").unwrap(),
+            ScopeAssessment::BeyondScopeButValid
+        );
+    }
+
+    #[test]
+    fn test_activity_of_other_party_not_applicable() {
+        let company = software_company();
+        let other = content(Uuid::new_v4(), "新闻采编");
+        assert_eq!(
+            check_business_activity(&company, &other, now()).unwrap(),
+            ScopeAssessment::NotApplicable
+        );
+
+        let person = person(1990);
+        assert_eq!(
+            check_business_activity(&person, &content(person.id(), "软件开发"), now()).unwrap(),
+            ScopeAssessment::NotApplicable
+        );
+    }
+
+    #[test]
+    fn test_prohibited_activity_is_void() {
+        let company = software_company();
+        let err = check_business_activity(&company, &content(company.id(), "新闻采编"), now())
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            FanError::ValidationError {
+                error_type: ValidationErrorType::ContractContentIllegal,
+                ..
+            }
+        ));
+        // 禁止经营的项目列于并列分项之中的，同样无效
+        assert!(check_business_activity(
+            &company,
+            &content(company.id(), "软件开发、新闻采编"),
+            now()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_licensed_activity_requires_valid_licence() {
+        let mut pharmacy = software_company();
+        let retail = content(pharmacy.id(), "药品零售");
+        assert!(check_business_activity(&pharmacy, &retail, now()).is_err());
+
+        pharmacy
+            .add_licence(
+                Licence::new(
+                    "药品经营许可证".to_string(),
+                    "北京市药品监督管理局".to_string(),
+                    "京AA0000000".to_string(),
+                    vec![IndustryCode::new("525").unwrap()],
+                    Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+                    Some(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()),
+                )
+                .unwrap(),
+            )
+            .unwrap();
+        assert_eq!(
+            check_business_activity(&pharmacy, &retail, now()).unwrap(),
+            ScopeAssessment::BeyondScopeButValid
+        );
+        // 许可证届满后从事的，合同无效
+        let expired = Utc.with_ymd_and_hms(2026, 6, 1, 0, 0, 0).unwrap();
+        assert!(check_business_activity(&pharmacy, &retail, expired).is_err());
+
+        // 许可证被吊销的，此后从事的合同无效，吊销之前从事的不受影响
+        let revoked = Utc.with_ymd_and_hms(2025, 7, 1, 0, 0, 0).unwrap();
+        assert!(pharmacy.revoke_licence("京AA0000001", revoked).is_err());
+        pharmacy.revoke_licence("京AA0000000", revoked).unwrap();
+        assert!(check_business_activity(&pharmacy, &retail, now()).is_ok());
+        assert!(check_business_activity(&pharmacy, &retail, revoked).is_err());
+        assert!(pharmacy.revoke_licence("京AA0000000", revoked).is_err());
+    }
+}
@@ -0,0 +1,335 @@
+//! 限制民事行为能力人实施民事法律行为的效力判断
+//! 对应《民法典》第十九条、第二十二条：限制民事行为能力人可以独立实施纯获利益的民事法律行为
+//! 或者与其年龄、智力、精神健康状况相适应的民事法律行为；实施其他民事法律行为由其法定代理人代理
+//! 或者经其法定代理人同意、追认（第一百四十五条）。无民事行为能力人实施的民事法律行为无效（第一百四十四条）。
+
+use crate::contract::intent::content::{IntentContent, SubjectMatterType};
+use crate::core::entity::NaturalCapacity;
+use crate::core::money::Currency;
+
+use rust_decimal::Decimal;
+
+/// 民事法律行为的效力
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActValidity {
+    /// 有效
+    Valid,
+    /// 效力待定，须经法定代理人追认
+    PendingRatification,
+    /// 无效
+    Void,
+}
+
+/// 表意人在交易中的地位
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartyRole {
+    /// 取得标的物或服务的一方，如买受人、受赠人
+    Acquirer,
+    /// 给付标的物或服务的一方，如出卖人、赠与人
+    Transferor,
+}
+
+/// 效力判断的结果及理由
+#[derive(Debug, Clone)]
+pub struct ActAssessment {
+    validity: ActValidity,
+    reason: String,
+}
+
+impl ActAssessment {
+    fn new(validity: ActValidity, reason: impl Into<String>) -> Self {
+        Self {
+            validity,
+            reason: reason.into(),
+        }
+    }
+
+    /// 获取效力
+    pub fn validity(&self) -> &ActValidity {
+        &self.validity
+    }
+
+    /// 获取判断理由
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+/// 判断行为是否与年龄、智力相适应的金额标准
+///
+/// 按年龄段设定限制民事行为能力的未成年人可独立支付的金额上限，
+/// 成年的限制民事行为能力人另设上限。金额以 `currency` 计，其他币种的价款不视为相适应。
+#[derive(Debug, Clone)]
+pub struct AppropriatenessThresholds {
+    /// 年龄段下限及对应的金额上限，按年龄升序排列
+    minor_limits: Vec<(u8, Decimal)>,
+    /// 成年的限制民事行为能力人可独立支付的金额上限
+    adult_limit: Decimal,
+    /// 金额上限的币种
+    currency: Currency,
+    /// 可独立交易的标的物类型，如日常消费品、日常服务
+    everyday_subjects: Vec<SubjectMatterType>,
+}
+
+impl AppropriatenessThresholds {
+    pub fn new(
+        minor_limits: Vec<(u8, Decimal)>,
+        adult_limit: Decimal,
+        currency: Currency,
+        everyday_subjects: Vec<SubjectMatterType>,
+    ) -> Self {
+        let mut minor_limits = minor_limits;
+        minor_limits.sort_by_key(|(age, _)| *age);
+        Self {
+            minor_limits,
+            adult_limit,
+            currency,
+            everyday_subjects,
+        }
+    }
+
+    /// 某一年龄可独立支付的金额上限，未知年龄（如非自然人）按成年人处理
+    pub fn limit_for(&self, age: Option<u8>) -> Decimal {
+        match age {
+            Some(age) if age < 18 => self
+                .minor_limits
+                .iter()
+                .rev()
+                .find(|(from, _)| *from <= age)
+                .map(|(_, limit)| *limit)
+                .unwrap_or(Decimal::ZERO),
+            _ => self.adult_limit,
+        }
+    }
+
+    /// 获取金额上限的币种
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// 获取可独立交易的标的物类型
+    pub fn everyday_subjects(&self) -> &[SubjectMatterType] {
+        &self.everyday_subjects
+    }
+}
+
+impl Default for AppropriatenessThresholds {
+    fn default() -> Self {
+        Self::new(
+            vec![
+                (8, Decimal::from(50)),
+                (12, Decimal::from(200)),
+                (16, Decimal::from(1000)),
+            ],
+            Decimal::from(1000),
+            Currency::CNY,
+            vec![SubjectMatterType::GenericGoods, SubjectMatterType::Service],
+        )
+    }
+}
+
+/// 限制民事行为能力人的行为效力分类器
+#[derive(Debug, Clone, Default)]
+pub struct CapacityClassifier {
+    thresholds: AppropriatenessThresholds,
+}
+
+impl CapacityClassifier {
+    pub fn new(thresholds: AppropriatenessThresholds) -> Self {
+        Self { thresholds }
+    }
+
+    /// 获取金额标准
+    pub fn thresholds(&self) -> &AppropriatenessThresholds {
+        &self.thresholds
+    }
+
+    /// 判断表意人独立实施该行为的效力
+    ///
+    /// - 完全民事行为能力人：有效
+    /// - 无民事行为能力人：无效（第一百四十四条）
+    /// - 限制民事行为能力人：纯获利益或与其年龄、智力相适应的，有效；其他的效力待定
+    pub fn classify(
+        &self,
+        capacity: &NaturalCapacity,
+        age: Option<u8>,
+        content: &IntentContent,
+        role: &PartyRole,
+    ) -> ActAssessment {
+        match capacity {
+            NaturalCapacity::Full => ActAssessment::new(
+                ActValidity::Valid,
+                "完全民事行为能力人可以独立实施民事法律行为",
+            ),
+            NaturalCapacity::None => ActAssessment::new(
+                ActValidity::Void,
+                "无民事行为能力人实施的民事法律行为无效（《民法典》第一百四十四条）",
+            ),
+            NaturalCapacity::Limited => self.classify_limited(age, content, role),
+        }
+    }
+
+    fn classify_limited(
+        &self,
+        age: Option<u8>,
+        content: &IntentContent,
+        role: &PartyRole,
+    ) -> ActAssessment {
+        if is_purely_beneficial(content, role) {
+            return ActAssessment::new(
+                ActValidity::Valid,
+                "纯获利益的民事法律行为（《民法典》第十九条、第二十二条）",
+            );
+        }
+
+        if !self
+            .thresholds
+            .everyday_subjects
+            .contains(content.subject_matter.subject_type())
+        {
+            return ActAssessment::new(
+                ActValidity::PendingRatification,
+                format!(
+                    "{}类标的不属于与其年龄、智力相适应的交易，须经法定代理人同意或追认（《民法典》第一百四十五条）",
+                    content.subject_matter.subject_type()
+                ),
+            );
+        }
+
+        let Some(ref price) = content.price else {
+            return ActAssessment::new(
+                ActValidity::PendingRatification,
+                "无偿给付财产不属于纯获利益的行为，须经法定代理人同意或追认（《民法典》第一百四十五条）",
+            );
+        };
+
+        let limit = self.thresholds.limit_for(age);
+        if price.currency() != self.thresholds.currency || price.amount() > limit {
+            return ActAssessment::new(
+                ActValidity::PendingRatification,
+                format!(
+                    "价款 {} {} 超出与其年龄、智力相适应的范围（{} {}），须经法定代理人同意或追认（《民法典》第一百四十五条）",
+                    price.amount(),
+                    price.currency(),
+                    limit,
+                    self.thresholds.currency
+                ),
+            );
+        }
+
+        ActAssessment::new(
+            ActValidity::Valid,
+            "与其年龄、智力相适应的民事法律行为（《民法典》第十九条、第二十二条）",
+        )
+    }
+}
+
+/// 是否为纯获利益的行为：无偿取得且不负担任何义务
+fn is_purely_beneficial(content: &IntentContent, role: &PartyRole) -> bool {
+    let gratuitous = content
+        .price
+        .as_ref()
+        .is_none_or(|price| price.amount().is_zero());
+    *role == PartyRole::Acquirer && gratuitous && content.additional_obligations.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::intent::content::{Price, SubjectMatter};
+    use crate::core::money::Money;
+    use uuid::Uuid;
+
+    fn content(subject_type: SubjectMatterType, price: Option<i64>) -> IntentContent {
+        IntentContent {
+            subject_matter: SubjectMatter::new(
+                Uuid::new_v4(),
+                subject_type,
+                "测试标的".to_string(),
+                None,
+            ),
+            price: price
+                .map(|amount| Price::new(Money::cny(Decimal::from(amount)), "现金".to_string())),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_classify_limited_capacity() {
+        let classifier = CapacityClassifier::default();
+        let limited = NaturalCapacity::Limited;
+        let snack = content(SubjectMatterType::GenericGoods, Some(10));
+        let phone = content(SubjectMatterType::GenericGoods, Some(5000));
+        let gift = content(SubjectMatterType::SpecificGoods, None);
+
+        let validity = |capacity: &NaturalCapacity, age, content, role| {
+            classifier
+                .classify(capacity, age, content, role)
+                .validity()
+                .clone()
+        };
+
+        // 八岁儿童买零食、接受赠与
+        assert_eq!(
+            validity(&limited, Some(8), &snack, &PartyRole::Acquirer),
+            ActValidity::Valid
+        );
+        assert_eq!(
+            validity(&limited, Some(8), &gift, &PartyRole::Acquirer),
+            ActValidity::Valid
+        );
+        // 赠与他人、购买高价商品须经追认
+        assert_eq!(
+            validity(&limited, Some(8), &gift, &PartyRole::Transferor),
+            ActValidity::PendingRatification
+        );
+        assert_eq!(
+            validity(&limited, Some(17), &phone, &PartyRole::Acquirer),
+            ActValidity::PendingRatification
+        );
+        // 附义务的赠与不是纯获利益
+        let mut burdened = gift.clone();
+        burdened.add_obligation("照顾赠与人的宠物".to_string());
+        assert_eq!(
+            validity(&limited, Some(15), &burdened, &PartyRole::Acquirer),
+            ActValidity::PendingRatification
+        );
+
+        assert_eq!(
+            validity(&NaturalCapacity::None, Some(6), &gift, &PartyRole::Acquirer),
+            ActValidity::Void
+        );
+        assert_eq!(
+            validity(
+                &NaturalCapacity::Full,
+                Some(30),
+                &phone,
+                &PartyRole::Acquirer
+            ),
+            ActValidity::Valid
+        );
+    }
+
+    #[test]
+    fn test_configurable_thresholds() {
+        let classifier = CapacityClassifier::new(AppropriatenessThresholds::new(
+            vec![(8, Decimal::from(5))],
+            Decimal::from(100),
+            Currency::CNY,
+            vec![SubjectMatterType::GenericGoods],
+        ));
+        let snack = content(SubjectMatterType::GenericGoods, Some(10));
+        assert_eq!(
+            classifier
+                .classify(
+                    &NaturalCapacity::Limited,
+                    Some(9),
+                    &snack,
+                    &PartyRole::Acquirer
+                )
+                .validity(),
+            &ActValidity::PendingRatification
+        );
+        assert_eq!(classifier.thresholds().limit_for(None), Decimal::from(100));
+    }
+}
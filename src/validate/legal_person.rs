@@ -0,0 +1,146 @@
+//! 法人类型对民事法律行为的限制
+//! 对应《民法典》第八十七条：非营利法人不得向出资人、设立人或者会员分配所取得利润；
+//! 第六百八十三条：机关法人和以公益为目的的非营利法人不得为保证人。
+
+use crate::contract::intent::content::{IntentContent, SubjectMatterType};
+use crate::core::entity::Entity;
+use crate::{FanError, FanResult, ValidationErrorType};
+
+/// 校验当事人的法人类型是否允许其实施该行为
+///
+/// 只检查内容中指向该当事人的保证和利润分配，非法人当事人不受限制。
+pub fn check_restricted_act(party: &dyn Entity, content: &IntentContent) -> FanResult<()> {
+    let Some(legal_form) = party.legal_form() else {
+        return Ok(());
+    };
+
+    match content.subject_matter.subject_type() {
+        SubjectMatterType::Guarantee { guarantor }
+            if *guarantor == party.id() && !legal_form.can_be_guarantor() =>
+        {
+            Err(FanError::validation_with_ref(
+                "机关法人、以公益为目的的非营利法人和基层群众性自治组织不得为保证人",
+                ValidationErrorType::ContractPartyUnqualified,
+                "《民法典》第六百八十三条",
+                "check_restricted_act",
+                "LegalPerson",
+            ))
+        }
+        SubjectMatterType::ProfitDistribution { distributor }
+            if *distributor == party.id() && !legal_form.may_distribute_profits() =>
+        {
+            Err(FanError::validation_with_ref(
+                "非营利法人、机关法人和基层群众性自治组织不得向出资人、设立人或者成员分配利润",
+                ValidationErrorType::ContractContentIllegal,
+                "《民法典》第八十七条",
+                "check_restricted_act",
+                "LegalPerson",
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::intent::content::SubjectMatter;
+    use crate::core::entity::{CompanyType, LegalPerson, LegalPersonType};
+    use crate::core::money::Money;
+    use crate::testing::{fixed_clock, person};
+    use chrono::{TimeZone, Utc};
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    fn legal_person(legal_person_type: LegalPersonType) -> LegalPerson {
+        LegalPerson::with_clock(
+            legal_person_type,
+            Money::cny(Decimal::from(1_000_000)),
+            Uuid::new_v4(),
+            "北京市东城区xxx街道".to_string(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+            fixed_clock(),
+        )
+    }
+
+    fn content(subject_type: SubjectMatterType) -> IntentContent {
+        IntentContent {
+            subject_matter: SubjectMatter::new(
+                Uuid::new_v4(),
+                subject_type,
+                "测试标的".to_string(),
+                None,
+            ),
+            ..Default::default()
+        }
+    }
+
+    fn guarantee(guarantor: Uuid) -> IntentContent {
+        content(SubjectMatterType::Guarantee { guarantor })
+    }
+
+    fn distribution(distributor: Uuid) -> IntentContent {
+        content(SubjectMatterType::ProfitDistribution { distributor })
+    }
+
+    #[test]
+    fn test_guarantor_must_be_qualified() {
+        let company = legal_person(LegalPersonType::Company(CompanyType::Limited));
+        assert!(check_restricted_act(&company, &guarantee(company.id())).is_ok());
+
+        // 机关法人、以公益为目的的非营利法人和基层群众性自治组织不得为保证人
+        for legal_person_type in [
+            LegalPersonType::StateOrgan,
+            LegalPersonType::Foundation,
+            LegalPersonType::SocialOrganization {
+                public_welfare: true,
+            },
+            LegalPersonType::GrassrootsAutonomousOrg,
+        ] {
+            let party = legal_person(legal_person_type);
+            let err = check_restricted_act(&party, &guarantee(party.id())).unwrap_err();
+            assert!(matches!(
+                err,
+                FanError::ValidationError {
+                    error_type: ValidationErrorType::ContractPartyUnqualified,
+                    ..
+                }
+            ));
+        }
+
+        // 非公益的社会团体可以为保证人
+        let association = legal_person(LegalPersonType::SocialOrganization {
+            public_welfare: false,
+        });
+        assert!(check_restricted_act(&association, &guarantee(association.id())).is_ok());
+    }
+
+    #[test]
+    fn test_non_profit_may_not_distribute_profits() {
+        let company = legal_person(LegalPersonType::Company(CompanyType::Limited));
+        assert!(check_restricted_act(&company, &distribution(company.id())).is_ok());
+
+        let foundation = legal_person(LegalPersonType::Foundation);
+        let err = check_restricted_act(&foundation, &distribution(foundation.id())).unwrap_err();
+        assert!(matches!(
+            err,
+            FanError::ValidationError {
+                error_type: ValidationErrorType::ContractContentIllegal,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_only_the_acting_legal_person_is_restricted() {
+        // 基金会作为债权人接受他人的保证、受领利润分配不受限制
+        let foundation = legal_person(LegalPersonType::Foundation);
+        assert!(check_restricted_act(&foundation, &guarantee(Uuid::new_v4())).is_ok());
+        assert!(check_restricted_act(&foundation, &distribution(Uuid::new_v4())).is_ok());
+
+        // 自然人不受法人类型的限制
+        let person = person(1990);
+        assert!(check_restricted_act(&person, &guarantee(person.id())).is_ok());
+        assert!(check_restricted_act(&person, &distribution(person.id())).is_ok());
+    }
+}
@@ -0,0 +1,6 @@
+//! 校验模块
+//! 提供跨主体与合同的效力判断规则
+
+pub mod business_scope;
+pub mod capacity;
+pub mod legal_person;